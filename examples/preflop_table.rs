@@ -0,0 +1,246 @@
+// Generates src/preflop_table.rs, the heads-up preflop equity of every pair of the 169
+// starting hand classes. Each entry averages the exact equity, over all C(48, 5) boards, of
+// every concrete matchup of the two classes. Takes a while; run it in release:
+//
+//     cargo run --release --example preflop_table > src/preflop_table.rs
+
+use holdemrmx::{Card, Hand, Rank, Suit};
+use std::collections::HashMap;
+
+// Per-rank weights whose sums tell apart every multiset of seven ranks
+const RANK_WEIGHTS: [u32; 13] = [
+    0, 1, 5, 22, 98, 453, 2031, 8698, 22854, 83661, 262349, 636345, 1479181,
+];
+
+struct Evaluator {
+    ranks: Vec<u16>,   // Strength by summed rank weights, hands without a flush
+    flushes: Vec<u16>, // Strength by rank bitmask of the flush suit
+}
+
+impl Evaluator {
+    fn new() -> Self {
+        let max_key = RANK_WEIGHTS[12] * 4 + RANK_WEIGHTS[11] * 3;
+        let mut ranks = vec![u16::MAX; max_key as usize + 1];
+        let mut counts = [0u8; 13];
+        Evaluator::fill_ranks(&mut ranks, &mut counts, 0, 7);
+
+        let suited = |mask: usize| -> Vec<Card> {
+            (0..13)
+                .filter(|rank| mask & 1 << rank != 0)
+                .map(|rank| Card::new(rank_of(rank as u8), Suit::Hearts))
+                .collect()
+        };
+        let flushes = (0..1 << 13)
+            .map(|mask: usize| match mask.count_ones() {
+                5..=7 => strength(&suited(mask)),
+                _ => 0,
+            })
+            .collect();
+
+        Evaluator { ranks, flushes }
+    }
+
+    // Every multiset of seven ranks, at most four of each, dealt over the suits so none
+    // holds five
+    fn fill_ranks(table: &mut [u16], counts: &mut [u8; 13], from: usize, left: u8) {
+        if left == 0 {
+            let mut cards = Vec::with_capacity(7);
+            for (rank, count) in counts.iter().enumerate() {
+                for _ in 0..*count {
+                    let suit = Suit::from_ordinal(cards.len() as u8 % 4).unwrap();
+                    cards.push(Card::new(rank_of(rank as u8), suit));
+                }
+            }
+
+            let key = counts
+                .iter()
+                .zip(RANK_WEIGHTS)
+                .map(|(count, weight)| *count as u32 * weight)
+                .sum::<u32>() as usize;
+            let value = strength(&cards);
+            assert!(
+                table[key] == u16::MAX || table[key] == value,
+                "rank weights collide"
+            );
+            table[key] = value;
+            return;
+        }
+
+        for rank in from..13 {
+            if counts[rank] < 4 {
+                counts[rank] += 1;
+                Evaluator::fill_ranks(table, counts, rank, left - 1);
+                counts[rank] -= 1;
+            }
+        }
+    }
+}
+
+fn rank_of(ordinal: u8) -> Rank {
+    Rank::from_ordinal(ordinal).unwrap()
+}
+
+fn strength(cards: &[Card]) -> u16 {
+    Hand::new(cards, &[]).best().strength().unwrap()
+}
+
+// Share of the pot the first hand wins over every board, ties split
+fn exact_equity(eval: &Evaluator, a: [u8; 2], b: [u8; 2]) -> f64 {
+    let live: Vec<u8> = (0..52)
+        .filter(|c| !a.contains(c) && !b.contains(c))
+        .collect();
+    let weight = |hole: [u8; 2]| {
+        hole.iter()
+            .map(|c| RANK_WEIGHTS[*c as usize / 4])
+            .sum::<u32>()
+    };
+    let suits = |hole: [u8; 2]| {
+        let mut masks = [0u16; 4];
+        for c in hole {
+            masks[c as usize % 4] |= 1 << (c / 4);
+        }
+        masks
+    };
+    let holes = [(weight(a), suits(a)), (weight(b), suits(b))];
+
+    let mut won = 0.0;
+    let mut boards = 0u32;
+    let mut idx = [0usize, 1, 2, 3, 4];
+    let n = live.len();
+
+    loop {
+        let mut key = 0u32;
+        let mut masks = [0u16; 4];
+        for i in idx {
+            let c = live[i];
+            key += RANK_WEIGHTS[c as usize / 4];
+            masks[c as usize % 4] |= 1 << (c / 4);
+        }
+
+        let flush_suit = (0..4).find(|s| masks[*s].count_ones() >= 3);
+        let [first, second] = holes.map(|(weight, hole_masks)| {
+            if let Some(s) = flush_suit {
+                let mask = masks[s] | hole_masks[s];
+                if mask.count_ones() >= 5 {
+                    return eval.flushes[mask as usize];
+                }
+            }
+            eval.ranks[(key + weight) as usize]
+        });
+
+        won += match first.cmp(&second) {
+            std::cmp::Ordering::Greater => 1.0,
+            std::cmp::Ordering::Equal => 0.5,
+            std::cmp::Ordering::Less => 0.0,
+        };
+        boards += 1;
+
+        // Next combination of five out of the live cards
+        let mut i = 5;
+        while i > 0 && idx[i - 1] == n - 5 + i - 1 {
+            i -= 1;
+        }
+        if i == 0 {
+            break;
+        }
+        idx[i - 1] += 1;
+        for j in i..5 {
+            idx[j] = idx[j - 1] + 1;
+        }
+    }
+
+    won / boards as f64
+}
+
+// Index of a starting hand class: pairs on the diagonal of a 13 x 13 grid of rank
+// ordinals, suited hands with the higher rank as the row, offsuit ones as the column
+fn class(hole: [u8; 2]) -> usize {
+    let (high, low) = (hole[0].max(hole[1]) as usize, hole[0].min(hole[1]) as usize);
+    let (high_rank, low_rank) = (high / 4, low / 4);
+
+    if high % 4 == low % 4 {
+        high_rank * 13 + low_rank
+    } else {
+        low_rank * 13 + high_rank
+    }
+}
+
+// The matchup with suits relabelled to its smallest form
+fn canonical(a: [u8; 2], b: [u8; 2], perms: &[[u8; 4]]) -> [u8; 4] {
+    perms
+        .iter()
+        .map(|perm| {
+            let relabel = |c: u8| c / 4 * 4 + perm[c as usize % 4];
+            let (mut x, mut y) = (a.map(relabel), b.map(relabel));
+            x.sort_unstable();
+            y.sort_unstable();
+            [x[0], x[1], y[0], y[1]]
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let eval = Evaluator::new();
+
+    let mut perms = Vec::with_capacity(24);
+    for p in 0..4u8 {
+        for q in (0..4).filter(|q| *q != p) {
+            for r in (0..4).filter(|r| *r != p && *r != q) {
+                perms.push([p, q, r, 6 - p - q - r]);
+            }
+        }
+    }
+
+    let holes: Vec<[u8; 2]> = (0..52u8)
+        .flat_map(|x| (x + 1..52).map(move |y| [x, y]))
+        .collect();
+
+    let mut memo: HashMap<[u8; 4], f64> = HashMap::new();
+    let mut totals = vec![(0.0f64, 0u32); 169 * 169];
+
+    for a in &holes {
+        for b in &holes {
+            if a.iter().any(|c| b.contains(c)) || class(*a) > class(*b) {
+                continue;
+            }
+
+            let key = canonical(*a, *b, &perms);
+            let equity = match memo.get(&key) {
+                Some(equity) => *equity,
+                None => {
+                    let swapped = canonical(*b, *a, &perms);
+                    let equity = match memo.get(&swapped) {
+                        Some(equity) => 1.0 - equity,
+                        None => exact_equity(&eval, *a, *b),
+                    };
+                    memo.insert(key, equity);
+                    equity
+                }
+            };
+
+            let total = &mut totals[class(*a) * 169 + class(*b)];
+            total.0 += equity;
+            total.1 += 1;
+        }
+    }
+
+    println!("// Generated by examples/preflop_table.rs, do not edit.");
+    println!("//");
+    println!("// Heads-up preflop equity of the first class against the second, averaged over");
+    println!("// every concrete matchup of the two. Classes are indexed as in preflop_class, and");
+    println!("// entry i * (339 - i) / 2 + (j - i) holds class i against class j >= i.");
+    println!("pub(crate) static PREFLOP_EQUITY: [f64; 14365] = [");
+    let entries: Vec<String> = (0..169)
+        .flat_map(|i| (i..169).map(move |j| (i, j)))
+        .map(|(i, j)| {
+            let (sum, count) = totals[i * 169 + j];
+            format!("{:.6},", sum / count as f64)
+        })
+        .collect();
+    // Nine to a line, the way rustfmt lays them out
+    for line in entries.chunks(9) {
+        println!("    {}", line.join(" "));
+    }
+    println!("];");
+}
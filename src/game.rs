@@ -0,0 +1,309 @@
+use crate::{PokerError, Pot, Street, Table};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Raise(u64), // Total bet for the street to raise to, not the amount added
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Transition {
+    NextToAct(usize),                           // Betting goes on with this seat
+    NewStreet(Street, usize),                   // Street dealt, seat first to act on it
+    Uncontested { seat: usize, pot: u64 },      // Everybody else folded
+    Showdown { winners: Vec<usize>, pot: u64 }, // Pot split between the best hands
+}
+
+// One no-limit hold'em hand with a single pot. Seat 0 posts the small blind and seat 1 the
+// big blind; after the flop the first seat still in acts first, except heads up where the
+// big blind does. Players who go all-in for
+// less than the others are not given side pots.
+#[derive(Debug)]
+pub struct GameState {
+    table: Table,
+    pot: Pot,
+    street: Street,
+    stacks: Vec<u64>,
+    bets: Vec<u64>, // Chips put in on the current street
+    folded: Vec<bool>,
+    pending: Vec<bool>, // Still to act before the street's betting closes
+    to_act: Option<usize>,
+    big_blind: u64,
+    raise_by: u64, // Largest raise increment on the current street, at least the big blind
+}
+
+impl GameState {
+    // Start a hand at the table: deal hole cards (a rigged table deals its rigged cards)
+    // and post the blinds
+    pub fn new(
+        mut table: Table,
+        stacks: Vec<u64>,
+        big_blind: u64,
+    ) -> Result<GameState, PokerError> {
+        let players = table.players();
+        if players < 2 || stacks.len() != players {
+            return Err(PokerError::InvalidAction(format!(
+                "{} stacks for {} players",
+                stacks.len(),
+                players
+            )));
+        }
+
+        table.deal_hole_cards();
+
+        let mut state = GameState {
+            table,
+            pot: Pot::new(big_blind),
+            street: Street::Preflop,
+            stacks,
+            bets: vec![0; players],
+            folded: vec![false; players],
+            pending: vec![false; players],
+            to_act: None,
+            big_blind,
+            raise_by: big_blind,
+        };
+
+        state.put_in(0, big_blind / 2);
+        state.put_in(1, big_blind);
+
+        // Preflop the seat after the big blind opens, which heads up is the small blind
+        let first = 2 % players;
+        state.pending = (0..players).map(|seat| state.stacks[seat] > 0).collect();
+        state.to_act = state.next_pending(first + players - 1);
+
+        Ok(state)
+    }
+
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    pub fn pot(&self) -> u64 {
+        self.pot.size()
+    }
+
+    pub fn stack(&self, seat: usize) -> u64 {
+        self.stacks[seat]
+    }
+
+    // Seat whose turn it is, None once the hand is over
+    pub fn to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+
+    pub fn step(&mut self, action: Action) -> Result<Transition, PokerError> {
+        let seat = self
+            .to_act
+            .ok_or_else(|| PokerError::InvalidAction(String::from("the hand is over")))?;
+        let current_bet = self.bets.iter().copied().max().unwrap_or(0);
+        let owed = current_bet - self.bets[seat];
+
+        match action {
+            Action::Fold => self.folded[seat] = true,
+            Action::Check if owed > 0 => {
+                return Err(PokerError::InvalidAction(format!("{} to call", owed)));
+            }
+            Action::Check => (),
+            Action::Call if owed == 0 => {
+                return Err(PokerError::InvalidAction(String::from("nothing to call")));
+            }
+            Action::Call => self.put_in(seat, owed),
+            Action::Raise(to) => {
+                let all_in = self.bets[seat] + self.stacks[seat];
+                if to > all_in {
+                    return Err(PokerError::InvalidAction(format!(
+                        "raise to {} with {} behind",
+                        to, all_in
+                    )));
+                }
+                // A raise must go up by at least the last raise on the street, though going
+                // all-in is allowed for less
+                let min_raise = current_bet + self.raise_by;
+                if to <= current_bet || (to < all_in && to < min_raise) {
+                    return Err(PokerError::InvalidAction(format!(
+                        "raise to {} is below the minimum of {}",
+                        to, min_raise
+                    )));
+                }
+
+                self.raise_by = self.raise_by.max(to - current_bet);
+                self.put_in(seat, to - self.bets[seat]);
+                for other in 0..self.players() {
+                    self.pending[other] = self.can_act(other);
+                }
+            }
+        }
+        self.pending[seat] = false;
+
+        let live: Vec<usize> = (0..self.players()).filter(|s| !self.folded[*s]).collect();
+        if let [seat] = live[..] {
+            let pot = self.award(&[seat]);
+            return Ok(Transition::Uncontested { seat, pot });
+        }
+
+        if let Some(next) = self.next_pending(seat) {
+            self.to_act = Some(next);
+            return Ok(Transition::NextToAct(next));
+        }
+
+        self.close_street()
+    }
+
+    fn players(&self) -> usize {
+        self.table.players()
+    }
+
+    fn can_act(&self, seat: usize) -> bool {
+        !self.folded[seat] && self.stacks[seat] > 0
+    }
+
+    // Move chips from the seat's stack into the pot, as many as it has left
+    fn put_in(&mut self, seat: usize, chips: u64) {
+        let chips = chips.min(self.stacks[seat]);
+
+        self.stacks[seat] -= chips;
+        self.bets[seat] += chips;
+        self.pot.add(chips);
+    }
+
+    // First seat after `after`, going round the table, that still has to act
+    fn next_pending(&self, after: usize) -> Option<usize> {
+        let players = self.players();
+
+        (1..=players)
+            .map(|offset| (after + offset) % players)
+            .find(|seat| self.pending[*seat])
+    }
+
+    // Betting is closed: deal the next street, or every street left when at most one player
+    // can still bet, then show down after the river
+    fn close_street(&mut self) -> Result<Transition, PokerError> {
+        self.bets.iter_mut().for_each(|bet| *bet = 0);
+        self.raise_by = self.big_blind;
+        let bettors = (0..self.players()).filter(|s| self.can_act(*s)).count();
+
+        while let Some(street) = self.street.next() {
+            self.street = street;
+            self.table.deal_street();
+
+            if bettors > 1 {
+                self.pending = (0..self.players()).map(|s| self.can_act(s)).collect();
+                // Heads up the small blind has the button and so acts last after the flop
+                let after = if self.players() == 2 {
+                    0
+                } else {
+                    self.players() - 1
+                };
+                let first = self.next_pending(after).expect("players to act");
+                self.to_act = Some(first);
+
+                return Ok(Transition::NewStreet(street, first));
+            }
+        }
+
+        let live: Vec<usize> = (0..self.players()).filter(|s| !self.folded[*s]).collect();
+        let best = live
+            .iter()
+            .map(|seat| self.table.hand(*seat).best())
+            .max()
+            .expect("players at showdown");
+        let winners: Vec<usize> = live
+            .into_iter()
+            .filter(|seat| self.table.hand(*seat).best() == best)
+            .collect();
+        let pot = self.award(&winners);
+
+        Ok(Transition::Showdown { winners, pot })
+    }
+
+    // Split the pot between the winners, odd chips going to the first of them, and end
+    // the hand. Returns the pot awarded.
+    fn award(&mut self, winners: &[usize]) -> u64 {
+        let pot = self.pot.size();
+        let share = pot / winners.len() as u64;
+
+        for seat in winners {
+            self.stacks[*seat] += share;
+        }
+        self.stacks[winners[0]] += pot % winners.len() as u64;
+        self.to_act = None;
+
+        pot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blinds_and_illegal_actions() {
+        let mut game = GameState::new(Table::new(3).unwrap(), vec![100, 100, 100], 10).unwrap();
+        assert_eq!(game.pot(), 15);
+        assert_eq!(game.stack(0), 95);
+        assert_eq!(game.to_act(), Some(2));
+
+        assert!(game.step(Action::Check).is_err());
+        assert!(game.step(Action::Raise(15)).is_err());
+        assert!(game.step(Action::Raise(101)).is_err());
+
+        assert_eq!(game.step(Action::Raise(30)), Ok(Transition::NextToAct(0)));
+        assert_eq!(game.step(Action::Fold), Ok(Transition::NextToAct(1)));
+        assert_eq!(
+            game.step(Action::Fold),
+            Ok(Transition::Uncontested { seat: 2, pot: 45 })
+        );
+        assert_eq!(game.stack(2), 115);
+        assert!(game.step(Action::Check).is_err());
+    }
+
+    #[test]
+    fn reraise_by_the_last_raise() {
+        let mut game = GameState::new(Table::new(3).unwrap(), vec![500, 500, 500], 10).unwrap();
+
+        // Raised by 20 to 30, so re-raising takes at least 50
+        assert_eq!(game.step(Action::Raise(30)), Ok(Transition::NextToAct(0)));
+        assert!(game.step(Action::Raise(49)).is_err());
+        assert_eq!(game.step(Action::Raise(50)), Ok(Transition::NextToAct(1)));
+        assert!(game.step(Action::Raise(69)).is_err());
+        assert_eq!(game.step(Action::Raise(100)), Ok(Transition::NextToAct(2)));
+        assert!(game.step(Action::Raise(149)).is_err());
+        assert_eq!(game.step(Action::Raise(150)), Ok(Transition::NextToAct(0)));
+    }
+
+    #[test]
+    fn heads_up_big_blind_acts_first_after_the_flop() {
+        let mut game = GameState::new(Table::new(2).unwrap(), vec![100, 100], 10).unwrap();
+        assert_eq!(game.to_act(), Some(0));
+
+        game.step(Action::Call).unwrap();
+        assert_eq!(
+            game.step(Action::Check),
+            Ok(Transition::NewStreet(Street::Flop, 1))
+        );
+        // A new street sets the minimum bet back to the big blind
+        assert!(game.step(Action::Raise(9)).is_err());
+        assert_eq!(game.step(Action::Raise(10)), Ok(Transition::NextToAct(0)));
+    }
+
+    #[test]
+    fn all_in_runs_the_board_out() {
+        let mut game = GameState::new(Table::new(2).unwrap(), vec![50, 50], 10).unwrap();
+        game.step(Action::Raise(50)).unwrap();
+
+        match game.step(Action::Call).unwrap() {
+            Transition::Showdown { pot, .. } => assert_eq!(pot, 100),
+            other => panic!("expected a showdown, got {:?}", other),
+        }
+        assert_eq!(game.street(), Street::River);
+        assert_eq!(game.table().board().len(), 5);
+        assert_eq!(game.stack(0) + game.stack(1), 100);
+    }
+}
@@ -1,11 +1,30 @@
+use rand::rngs::StdRng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
-use std::collections::HashMap;
+use rand::{thread_rng, Rng, RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+mod game;
+mod pot;
+mod preflop_table;
+mod simulation;
+mod table;
+
+pub use game::{Action, GameState, Transition};
+pub use pot::Pot;
+pub use simulation::Simulation;
+pub use table::Table;
+
+// Declared in bridge order, so ordinal() and the derived Ord both run from the Clubs (0)
+// up to the Spades (3)
 #[derive(Clone, Copy, Debug, EnumIter, Hash, Eq, Ord, PartialEq, PartialOrd)]
-enum Suit {
+pub enum Suit {
     Clubs,
     Diamonds,
     Hearts,
@@ -13,7 +32,7 @@ enum Suit {
 }
 
 #[derive(Clone, Copy, Debug, EnumIter, Hash, Eq, Ord, PartialEq, PartialOrd)]
-enum Rank {
+pub enum Rank {
     Two,
     Three,
     Four,
@@ -29,7 +48,167 @@ enum Rank {
     Ace,
 }
 
+// Every suit and rank, lowest first
+pub const ALL_SUITS: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+pub const ALL_RANKS: [Rank; 13] = [
+    Rank::Two,
+    Rank::Three,
+    Rank::Four,
+    Rank::Five,
+    Rank::Six,
+    Rank::Seven,
+    Rank::Eight,
+    Rank::Nine,
+    Rank::Ten,
+    Rank::Jack,
+    Rank::Queen,
+    Rank::King,
+    Rank::Ace,
+];
+
+impl Suit {
+    pub fn ordinal(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_ordinal(ordinal: u8) -> Option<Suit> {
+        match ordinal {
+            0 => Some(Suit::Clubs),
+            1 => Some(Suit::Diamonds),
+            2 => Some(Suit::Hearts),
+            3 => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+
+    // Position in bridge order, Spades highest at 3
+    pub fn bridge_order(&self) -> u8 {
+        SuitOrder::Bridge.position(*self)
+    }
+
+    // Suit from its letter in either case or its symbol
+    fn from_char(c: char) -> Option<Suit> {
+        match c.to_ascii_lowercase() {
+            'c' | '♣' => Some(Suit::Clubs),
+            'd' | '♦' => Some(Suit::Diamonds),
+            'h' | '♥' => Some(Suit::Hearts),
+            's' | '♠' => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+
+    // Lowercase initial as used in hand histories, e.g. the s of As
+    fn letter(self) -> char {
+        match self {
+            Suit::Clubs => 'c',
+            Suit::Diamonds => 'd',
+            Suit::Hearts => 'h',
+            Suit::Spades => 's',
+        }
+    }
+
+    fn symbol(self) -> char {
+        match self {
+            Suit::Clubs => '♣',
+            Suit::Diamonds => '♦',
+            Suit::Hearts => '♥',
+            Suit::Spades => '♠',
+        }
+    }
+}
+
+// Orders suits can be put in for display and canonical forms
+#[derive(Clone, Copy, Debug, Default, Hash, PartialEq, Eq)]
+pub enum SuitOrder {
+    #[default]
+    Bridge, // Clubs, Diamonds, Hearts, Spades from lowest to highest
+    Alternating, // Diamonds, Clubs, Hearts, Spades, so neighbouring suits differ in colour
+}
+
+impl SuitOrder {
+    const ALTERNATING: [Suit; 4] = [Suit::Diamonds, Suit::Clubs, Suit::Hearts, Suit::Spades];
+
+    // 0 for the lowest suit up to 3 for the highest
+    pub fn position(&self, suit: Suit) -> u8 {
+        match self {
+            SuitOrder::Bridge => suit.ordinal(),
+            SuitOrder::Alternating => SuitOrder::ALTERNATING
+                .iter()
+                .position(|s| *s == suit)
+                .expect("every suit is listed") as u8,
+        }
+    }
+
+    // The suit at a position, the inverse of position
+    pub fn suit_at(&self, position: u8) -> Suit {
+        match self {
+            SuitOrder::Bridge => Suit::from_ordinal(position),
+            SuitOrder::Alternating => SuitOrder::ALTERNATING.get(position as usize).copied(),
+        }
+        .expect("suit position below 4")
+    }
+}
+
 impl Rank {
+    // Zero based, Two is 0 and Ace is 12, unlike score which starts at 1
+    pub fn ordinal(&self) -> u8 {
+        *self as u8
+    }
+
+    pub fn from_ordinal(ordinal: u8) -> Option<Rank> {
+        match ordinal {
+            0..=12 => Some(Rank::id(ordinal + 1)),
+            _ => None,
+        }
+    }
+
+    // Inverse of from_char, T for the ten
+    fn to_char(self) -> char {
+        match self {
+            Rank::Ten => 'T',
+            rank => rank.symbol().chars().next().expect("rank symbol"),
+        }
+    }
+
+    // Rank from its usual one character form, T for the ten
+    fn from_char(c: char) -> Option<Rank> {
+        match c.to_ascii_uppercase() {
+            '2' => Some(Rank::Two),
+            '3' => Some(Rank::Three),
+            '4' => Some(Rank::Four),
+            '5' => Some(Rank::Five),
+            '6' => Some(Rank::Six),
+            '7' => Some(Rank::Seven),
+            '8' => Some(Rank::Eight),
+            '9' => Some(Rank::Nine),
+            'T' => Some(Rank::Ten),
+            'J' => Some(Rank::Jack),
+            'Q' => Some(Rank::Queen),
+            'K' => Some(Rank::King),
+            'A' => Some(Rank::Ace),
+            _ => None,
+        }
+    }
+
+    fn symbol(self) -> &'static str {
+        match self {
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "10",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+            Rank::Ace => "A",
+        }
+    }
+
     fn score(self) -> u8 {
         match self {
             Rank::Two => 1,
@@ -66,22 +245,188 @@ impl Rank {
             _ => panic!("No such card with score {}", score),
         }
     }
+
+    pub fn next(&self) -> Option<Rank> {
+        self.next_with(false)
+    }
+
+    pub fn prev(&self) -> Option<Rank> {
+        self.prev_with(false)
+    }
+
+    // With ace_low set the Ace also sits below the Two, so Ace -> Two
+    pub fn next_with(&self, ace_low: bool) -> Option<Rank> {
+        match self {
+            Rank::Ace if ace_low => Some(Rank::Two),
+            Rank::Ace => None,
+            _ => Some(Rank::id(self.score() + 1)),
+        }
+    }
+
+    // With ace_low set the Ace also sits below the Two, so Two -> Ace
+    pub fn prev_with(&self, ace_low: bool) -> Option<Rank> {
+        match self {
+            Rank::Two if ace_low => Some(Rank::Ace),
+            Rank::Two => None,
+            _ => Some(Rank::id(self.score() - 1)),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+// Variants compare by category first, then field by field from left to right.
+// A u16 holds the kickers as a bitmask with bit Rank::score() set per rank, so the
+// larger mask always has the better top kicker, then the better second, and so on.
+pub enum HandRanking {
+    HighCard(u16),             // Five high cards
+    OnePair(Rank, u16),        // Pair, then three kickers
+    TwoPair(Rank, Rank, Rank), // Top pair, bottom pair, kicker
+    Set(Rank, u16),            // Trips, then two kickers
+    Straight(Rank),            // High card, Five for the wheel
+    Flush(u16),                // Five flush cards
+    FullHouse(Rank, Rank),     // Trips, then pair
+    Quads(Rank, Rank),         // Quads, then kicker
+    StraightFlush(Rank),       // High card, Five for the steel wheel
+    RoyalFlush,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd)]
-enum HandRanking {
-    HighCard(u16),
-    OnePair(Rank, u16),
-    TwoPair(Rank, Rank, Rank),
-    Set(Rank, u16),
-    Straight(Rank),
-    Flush(u16),
-    FullHouse(Rank, Rank),
-    Quads(Rank, Rank),
-    StraightFlush(Rank),
+#[derive(Clone, Copy, Debug, EnumIter, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum HandCategory {
+    HighCard,
+    OnePair,
+    TwoPair,
+    Set,
+    Straight,
+    Flush,
+    FullHouse,
+    Quads,
+    StraightFlush,
     RoyalFlush,
 }
 
+impl fmt::Display for HandCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            HandCategory::HighCard => "High Card",
+            HandCategory::OnePair => "One Pair",
+            HandCategory::TwoPair => "Two Pair",
+            HandCategory::Set => "Set",
+            HandCategory::Straight => "Straight",
+            HandCategory::Flush => "Flush",
+            HandCategory::FullHouse => "Full House",
+            HandCategory::Quads => "Quads",
+            HandCategory::StraightFlush => "Straight Flush",
+            HandCategory::RoyalFlush => "Royal Flush",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+// Case and spacing are ignored, so "full house", "FullHouse" and "Full  House" all parse
+impl FromStr for HandCategory {
+    type Err = PokerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '_' && *c != '-')
+            .collect::<String>()
+            .to_lowercase();
+
+        match name.as_str() {
+            "highcard" => Ok(HandCategory::HighCard),
+            "onepair" | "pair" => Ok(HandCategory::OnePair),
+            "twopair" => Ok(HandCategory::TwoPair),
+            "set" | "trips" | "threeofakind" => Ok(HandCategory::Set),
+            "straight" => Ok(HandCategory::Straight),
+            "flush" => Ok(HandCategory::Flush),
+            "fullhouse" => Ok(HandCategory::FullHouse),
+            "quads" | "fourofakind" => Ok(HandCategory::Quads),
+            "straightflush" => Ok(HandCategory::StraightFlush),
+            "royalflush" => Ok(HandCategory::RoyalFlush),
+            _ => Err(PokerError::ParseError(format!(
+                "unknown hand category {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+impl HandRanking {
+    pub fn category(&self) -> HandCategory {
+        match self {
+            HandRanking::HighCard(_) => HandCategory::HighCard,
+            HandRanking::OnePair(..) => HandCategory::OnePair,
+            HandRanking::TwoPair(..) => HandCategory::TwoPair,
+            HandRanking::Set(..) => HandCategory::Set,
+            HandRanking::Straight(_) => HandCategory::Straight,
+            HandRanking::Flush(_) => HandCategory::Flush,
+            HandRanking::FullHouse(..) => HandCategory::FullHouse,
+            HandRanking::Quads(..) => HandCategory::Quads,
+            HandRanking::StraightFlush(_) => HandCategory::StraightFlush,
+            HandRanking::RoyalFlush => HandCategory::RoyalFlush,
+        }
+    }
+
+    pub fn beats(&self, other: &HandRanking) -> bool {
+        self > other
+    }
+
+    pub fn ties(&self, other: &HandRanking) -> bool {
+        self == other
+    }
+
+    // Whether the hand is in the given category or a better one, e.g. two pair or better
+    pub fn is_at_least(&self, category: HandCategory) -> bool {
+        self.category() >= category
+    }
+
+    // Break the ranking down into its compared parts, in the same order the derived Ord uses
+    fn components(&self) -> Vec<(&'static str, Rank)> {
+        let kickers = |name, mask| {
+            ranks_from_mask(mask)
+                .into_iter()
+                .map(move |rank| (name, rank))
+        };
+
+        match *self {
+            HandRanking::HighCard(mask) => kickers("high card", mask).collect(),
+            HandRanking::OnePair(pair, mask) => std::iter::once(("pair", pair))
+                .chain(kickers("kicker", mask))
+                .collect(),
+            HandRanking::TwoPair(high, low, kicker) => {
+                vec![("top pair", high), ("bottom pair", low), ("kicker", kicker)]
+            }
+            HandRanking::Set(set, mask) => std::iter::once(("set", set))
+                .chain(kickers("kicker", mask))
+                .collect(),
+            HandRanking::Straight(high) => vec![("straight", high)],
+            HandRanking::Flush(mask) => kickers("flush card", mask).collect(),
+            HandRanking::FullHouse(set, pair) => vec![("set", set), ("pair", pair)],
+            HandRanking::Quads(quads, kicker) => vec![("quads", quads), ("kicker", kicker)],
+            HandRanking::StraightFlush(high) => vec![("straight flush", high)],
+            HandRanking::RoyalFlush => Vec::new(),
+        }
+    }
+}
+
+// The category followed by the ranks it is compared on, e.g. "Full House (King, Two)"
+impl fmt::Display for HandRanking {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranks: Vec<String> = self
+            .components()
+            .iter()
+            .map(|(_, rank)| format!("{:?}", rank))
+            .collect();
+
+        match ranks.is_empty() {
+            true => write!(f, "{}", self.category()),
+            false => write!(f, "{} ({})", self.category(), ranks.join(", ")),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Card {
     rank: Rank,
@@ -89,7 +434,7 @@ pub struct Card {
 }
 
 impl Card {
-    fn new(rank: Rank, suit: Suit) -> Self {
+    pub fn new(rank: Rank, suit: Suit) -> Self {
         Card { rank, suit }
     }
 
@@ -97,43 +442,419 @@ impl Card {
         self.rank.score()
     }
 
+    // Position of the card in a fresh deck, 0..52
+    pub fn to_index(&self) -> u8 {
+        self.rank.ordinal() * 4 + self.suit.ordinal()
+    }
+
+    pub fn from_index(index: u8) -> Option<Card> {
+        Some(Card::new(
+            Rank::from_ordinal(index / 4)?,
+            Suit::from_ordinal(index % 4)?,
+        ))
+    }
+
     pub fn display(&self) {
         println!("Drew card -> {:?}", self);
     }
+
+    // A five column wide card: rank in the top left corner, suit in the middle
+    pub fn render_ascii(&self) -> [String; 3] {
+        [
+            format!("┌{:─<3}┐", self.rank.symbol()),
+            format!("│ {} │", self.suit.symbol()),
+            String::from("└───┘"),
+        ]
+    }
 }
 
-#[derive(Debug)]
+// Two character hand history form such as As or Th
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.rank.to_char(), self.suit.letter())
+    }
+}
+
+// One card such as "As", "Th" or "10h"
+impl FromStr for Card {
+    type Err = PokerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_cards(s)?.as_slice() {
+            [card] => Ok(*card),
+            _ => Err(PokerError::ParseError(format!(
+                "expected one card in {:?}",
+                s
+            ))),
+        }
+    }
+}
+
+// Cards written back to back or separated by spaces or commas, e.g. "AsKs", "Ts 9c" or
+// "10h,9c". The ten is either T or 10; "10" is looked for first so it is never read as a 1.
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, PokerError> {
+    let invalid = |at: &str| PokerError::ParseError(format!("invalid card at {:?} in {:?}", at, s));
+    let mut cards = Vec::new();
+    let mut rest = s.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+
+    while !rest.is_empty() {
+        let (rank, after) = match rest.strip_prefix("10") {
+            Some(after) => (Some(Rank::Ten), after),
+            None => {
+                let mut chars = rest.chars();
+                (chars.next().and_then(Rank::from_char), chars.as_str())
+            }
+        };
+
+        let mut chars = after.chars();
+        let suit = chars.next().and_then(Suit::from_char);
+        match (rank, suit) {
+            (Some(rank), Some(suit)) => cards.push(Card::new(rank, suit)),
+            _ => return Err(invalid(rest)),
+        }
+
+        rest = chars
+            .as_str()
+            .trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+
+    Ok(cards)
+}
+
+#[derive(Clone, Copy, Debug, EnumIter, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+}
+
+impl Street {
+    // Community cards showing once the street is reached
+    pub fn board_cards(&self) -> usize {
+        match self {
+            Street::Preflop => 0,
+            Street::Flop => 3,
+            Street::Turn => 4,
+            Street::River => 5,
+        }
+    }
+
+    pub fn next(&self) -> Option<Street> {
+        match self {
+            Street::Preflop => Some(Street::Flop),
+            Street::Flop => Some(Street::Turn),
+            Street::Turn => Some(Street::River),
+            Street::River => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PokerError {
+    DeckEmpty,
+    DuplicateCard(Card),
+    TooManyCards(usize),
+    TooFewCards(usize),
+    ParseError(String),
+    CorruptDeck(String),
+    InvalidAction(String),
+}
+
+impl fmt::Display for PokerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PokerError::DeckEmpty => write!(f, "the deck is empty"),
+            PokerError::DuplicateCard(card) => write!(f, "duplicate card {}", card),
+            PokerError::TooManyCards(n) => write!(f, "too many cards ({})", n),
+            PokerError::TooFewCards(n) => write!(f, "too few cards ({})", n),
+            PokerError::ParseError(reason) => write!(f, "parse error: {}", reason),
+            PokerError::CorruptDeck(reason) => write!(f, "corrupt deck: {}", reason),
+            PokerError::InvalidAction(reason) => write!(f, "invalid action: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for PokerError {}
+
+#[derive(Clone, Debug)]
 pub struct Deck {
     cards: Vec<Card>,
     dealt: Vec<Card>,
+    jokers: Vec<usize>, // Undealt jokers, each by the number of cards beneath it
+    jokers_dealt: usize,
+}
+
+// Stands for a joker where cards are written as Card::to_index bytes
+const JOKER_INDEX: u8 = 52;
+
+// A card dealt from a deck that may hold jokers
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeckCard {
+    Standard(Card),
+    Joker,
+}
+
+// A dealt card and whether the other players can see it, as with stud's up-cards
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DealtCard {
+    pub card: Card,
+    pub face_up: bool,
+}
+
+// Every card dealt from a deck so far, in the order it was dealt
+#[derive(Clone, Debug, PartialEq)]
+pub struct DealLog {
+    cards: Vec<Card>,
+}
+
+impl DealLog {
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    // A fresh deck stacked so it deals the logged cards again in the same order
+    pub fn replay(&self) -> Result<Deck, PokerError> {
+        let mut deck = Deck::new_shuffled();
+        deck.stack(&self.cards)?;
+
+        Ok(deck)
+    }
+}
+
+// Saved deck state for exploring a branch and coming back to it
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeckSnapshot {
+    cards: Vec<Card>,
+    dealt: Vec<Card>,
+    jokers: Vec<usize>,
+    jokers_dealt: usize,
 }
 
 impl Deck {
     pub fn new() -> Self {
-        let mut cards = Vec::with_capacity(52);
+        Deck::multi(1)
+    }
+
+    // The order a new deck comes out of the box, drawn in this sequence:
+    // Spades A, 2 .. K, then Diamonds A, 2 .. K, then Clubs K, Q .. 2, A, then Hearts K, Q .. 2, A
+    pub fn new_deck_order() -> Self {
+        let ace_to_king: Vec<Rank> = std::iter::once(Rank::Ace)
+            .chain(Rank::iter().take(12))
+            .collect();
+        let king_to_ace: Vec<Rank> = ace_to_king.iter().rev().copied().collect();
+
+        let mut order = Vec::with_capacity(52);
+        for (suit, ranks) in [
+            (Suit::Spades, &ace_to_king),
+            (Suit::Diamonds, &ace_to_king),
+            (Suit::Clubs, &king_to_ace),
+            (Suit::Hearts, &king_to_ace),
+        ] {
+            order.extend(ranks.iter().map(|rank| Card::new(*rank, suit)));
+        }
+
+        // Cards are drawn from the back
+        order.reverse();
+
+        Deck {
+            cards: order,
+            dealt: Vec::with_capacity(25),
+            jokers: Vec::new(),
+            jokers_dealt: 0,
+        }
+    }
+
+    // A shoe of n standard decks shuffled together, for dealing games like blackjack.
+    // Cards repeat across copies, so evaluating poker hands dealt from it isn't meaningful.
+    pub fn multi(n: usize) -> Self {
+        let mut cards = Vec::with_capacity(52 * n);
         let dealt = Vec::with_capacity(25);
 
-        for rank in Rank::iter() {
-            for suit in Suit::iter() {
-                cards.push(Card::new(rank, suit));
+        for _ in 0..n {
+            for rank in Rank::iter() {
+                for suit in Suit::iter() {
+                    cards.push(Card::new(rank, suit));
+                }
             }
         }
 
-        Deck { cards, dealt }
+        Deck {
+            cards,
+            dealt,
+            jokers: Vec::new(),
+            jokers_dealt: 0,
+        }
+    }
+
+    // A standard deck with n jokers on top, to be shuffled in
+    pub fn with_jokers(n: usize) -> Self {
+        let mut deck = Deck::new();
+        deck.jokers = vec![deck.cards.len(); n];
+
+        deck
     }
 
     pub fn display(&self) {
         println!("{:?}", self);
     }
 
+    pub fn new_shuffled() -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle();
+
+        deck
+    }
+
+    // Same seed, same order, for reproducible games and tests
+    pub fn new_shuffled_seeded(seed: u64) -> Self {
+        let mut deck = Deck::new();
+        deck.shuffle_with(&mut StdRng::seed_from_u64(seed));
+
+        deck
+    }
+
+    // Deal one card per entry in the pattern, face up where the entry is true
+    pub fn deal_with_visibility(&mut self, pattern: &[bool]) -> Vec<DealtCard> {
+        pattern
+            .iter()
+            .flat_map(|face_up| {
+                self.draw(1).into_iter().map(|card| DealtCard {
+                    card,
+                    face_up: *face_up,
+                })
+            })
+            .collect()
+    }
+
+    // Draw n cards along with the log of everything dealt since the deck was last reset
+    pub fn deal_recorded(&mut self, n: usize) -> (Vec<Card>, DealLog) {
+        let cards = self.draw(n);
+        let log = DealLog {
+            cards: self.dealt.clone(),
+        };
+
+        (cards, log)
+    }
+
     pub fn shuffle(&mut self) {
-        self.cards.shuffle(&mut thread_rng());
+        self.shuffle_with(&mut thread_rng());
+    }
+
+    pub fn shuffle_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.cards.shuffle(rng);
+
+        // Pick the jokers' places among all undealt cards, then count the cards under each
+        if !self.jokers.is_empty() {
+            let total = self.cards.len() + self.jokers.len();
+            let mut places = rand::seq::index::sample(rng, total, self.jokers.len()).into_vec();
+            places.sort_unstable();
+            self.jokers = places
+                .iter()
+                .enumerate()
+                .map(|(below, place)| place - below)
+                .collect();
+        }
+    }
+
+    // Provably fair shuffle that anyone holding the seed can replay:
+    //  1. The seed bytes are folded into a 32 byte key, byte i XORed into key[i % 32].
+    //  2. The key seeds a ChaCha20 stream (rand_chacha's ChaCha20Rng, stream 0).
+    //  3. A Fisher-Yates pass runs from the last undealt card i down to 1, swapping it with
+    //     card j = x % (i + 1), where x is the next u32 of the stream, rejecting any
+    //     x >= 2^32 - 2^32 % (i + 1) so every j is equally likely.
+    // Undealt cards are indexed from the bottom of the deck, jokers counting as cards.
+    pub fn shuffle_from_seed_bytes(&mut self, seed: &[u8]) {
+        let mut key = [0u8; 32];
+        for (i, byte) in seed.iter().enumerate() {
+            key[i % 32] ^= byte;
+        }
+
+        let mut rng = ChaCha20Rng::from_seed(key);
+        let mut undealt = self.undealt();
+
+        for i in (1..undealt.len()).rev() {
+            let bound = i as u64 + 1;
+            let limit = (1u64 << 32) - (1u64 << 32) % bound;
+
+            let x = loop {
+                let x = rng.next_u32() as u64;
+                if x < limit {
+                    break x;
+                }
+            };
+
+            undealt.swap(i, (x % bound) as usize);
+        }
+
+        self.set_undealt(undealt);
+    }
+
+    // The undealt cards from the bottom of the deck up, jokers in their places
+    fn undealt(&self) -> Vec<DeckCard> {
+        let mut jokers = self.jokers.clone();
+        jokers.sort_unstable();
+        let mut jokers = jokers.into_iter().peekable();
+
+        let mut undealt = Vec::with_capacity(self.remaining());
+        for (below, card) in self.cards.iter().enumerate() {
+            while jokers.next_if(|under| *under <= below).is_some() {
+                undealt.push(DeckCard::Joker);
+            }
+            undealt.push(DeckCard::Standard(*card));
+        }
+        undealt.extend(jokers.map(|_| DeckCard::Joker));
+
+        undealt
+    }
+
+    fn set_undealt(&mut self, undealt: Vec<DeckCard>) {
+        self.cards.clear();
+        self.jokers.clear();
+
+        for card in undealt {
+            match card {
+                DeckCard::Standard(card) => self.cards.push(card),
+                DeckCard::Joker => self.jokers.push(self.cards.len()),
+            }
+        }
+    }
+
+    fn joker_on_top(&self) -> bool {
+        self.jokers.iter().any(|under| *under >= self.cards.len())
+    }
+
+    // Deal the joker on top of the deck, if there is one
+    fn take_joker(&mut self) -> bool {
+        let top = self.cards.len();
+        match self.jokers.iter().position(|under| *under >= top) {
+            Some(i) => {
+                self.jokers.swap_remove(i);
+                self.jokers_dealt += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Indices address the undealt cards, which are drawn from the highest index down
+    pub fn shuffle_range<R: Rng + ?Sized>(&mut self, range: std::ops::Range<usize>, rng: &mut R) {
+        self.cards[range].shuffle(rng);
+    }
+
+    pub fn swap(&mut self, i: usize, j: usize) {
+        self.cards.swap(i, j);
     }
 
-    pub fn draw(&mut self, nr: u8) -> Vec<Card> {
-        let mut cards = Vec::new();
+    // Deal nr standard cards off the top. Jokers met on the way are dealt as well, so more
+    // than nr cards may leave the deck; draw_with_jokers returns them instead.
+    pub fn draw(&mut self, nr: usize) -> Vec<Card> {
+        let mut cards = Vec::with_capacity(nr);
 
-        for _ in 0..nr {
+        while cards.len() < nr {
+            if self.take_joker() {
+                continue;
+            }
             if let Some(card) = self.cards.pop() {
                 self.dealt.push(card);
                 cards.push(card);
@@ -145,58 +866,453 @@ impl Deck {
         cards
     }
 
-    pub fn reset(&mut self) {
-        self.cards.append(&mut self.dealt);
-    }
-}
+    // Draw n cards, jokers included
+    pub fn draw_with_jokers(&mut self, n: usize) -> Vec<DeckCard> {
+        let mut cards = Vec::with_capacity(n);
 
-impl Default for Deck {
-    fn default() -> Self {
-        Deck::new()
+        for _ in 0..n {
+            if self.take_joker() {
+                cards.push(DeckCard::Joker);
+            } else if let Some(card) = self.cards.pop() {
+                self.dealt.push(card);
+                cards.push(DeckCard::Standard(card));
+            } else {
+                panic!("Deck is empty!");
+            }
+        }
+
+        cards
     }
-}
 
-#[derive(Debug)]
-pub struct Hand {
-    cards: Vec<Card>,
-    bitmask: u16,
-    suit_map: HashMap<Suit, u8>,
-    rank_map: HashMap<Rank, u8>,
-}
+    // Deal the bottom card, the one draw would reach last. Jokers at the very bottom are
+    // passed over and stay there.
+    pub fn deal_bottom(&mut self) -> Option<Card> {
+        if self.cards.is_empty() {
+            return None;
+        }
 
-impl Hand {
-    pub fn new(hole_cards: &[Card], board_cards: &[Card]) -> Hand {
-        let mut cards: Vec<Card> = Vec::with_capacity(7);
-        let mut suit_map = HashMap::with_capacity(4);
-        let mut rank_map = HashMap::with_capacity(7);
-        let mut bitmask: u16 = 0x00;
+        let card = self.cards.remove(0);
+        self.dealt.push(card);
+        for under in self.jokers.iter_mut().filter(|under| **under > 0) {
+            *under -= 1;
+        }
 
-        cards.extend(hole_cards);
-        cards.extend(board_cards);
+        Some(card)
+    }
 
-        for card in &cards {
-            let count = suit_map.entry(card.suit).or_insert(0);
-            *count += 1;
+    // Check the cards dealt so far against what a new deck put through
+    // shuffle_from_seed_bytes with the same seed deals, so anyone can rerun the audit.
+    // Returns the position of the first card dealt out of turn, None if they all match.
+    pub fn audit_seeded_deal(&self, seed: &[u8]) -> Option<usize> {
+        let mut expected = Deck::new();
+        expected.shuffle_from_seed_bytes(seed);
 
-            let count = rank_map.entry(card.rank).or_insert(0);
-            *count += 1;
+        self.dealt
+            .iter()
+            .position(|card| expected.cards.pop() != Some(*card))
+    }
 
-            bitmask |= 1 << card.score();
-            if card.score() == 13 {
-                // If Ace also set bit 1
-                bitmask |= 0x01;
-            }
-        }
+    // Burn a card and deal the board cards that take the hand from current to the next street.
+    // The burn is the top card, joker or not; the board cards are standard ones.
+    pub fn deal_to_street(&mut self, current: Street) -> Result<Vec<Card>, PokerError> {
+        let next = current
+            .next()
+            .ok_or(PokerError::TooManyCards(current.board_cards() + 1))?;
+        let count = next.board_cards() - current.board_cards();
 
-        Hand {
-            cards,
-            bitmask,
-            suit_map,
-            rank_map,
+        let burn = usize::from(!self.joker_on_top());
+        if self.remaining() < count + 1 || self.cards.len() < count + burn {
+            return Err(PokerError::DeckEmpty);
         }
+
+        self.draw_with_jokers(1);
+
+        Ok(self.draw(count))
+    }
+
+    pub fn reset(&mut self) {
+        self.cards.append(&mut self.dealt);
+        self.jokers = vec![self.cards.len(); self.jokers.len() + self.jokers_dealt];
+        self.jokers_dealt = 0;
+    }
+
+    pub fn snapshot(&self) -> DeckSnapshot {
+        DeckSnapshot {
+            cards: self.cards.clone(),
+            dealt: self.dealt.clone(),
+            jokers: self.jokers.clone(),
+            jokers_dealt: self.jokers_dealt,
+        }
+    }
+
+    pub fn restore(&mut self, snap: &DeckSnapshot) {
+        self.cards.clone_from(&snap.cards);
+        self.dealt.clone_from(&snap.dealt);
+        self.jokers.clone_from(&snap.jokers);
+        self.jokers_dealt = snap.jokers_dealt;
+    }
+
+    // Move the given cards to the top so they are drawn next, in the order given.
+    // The other undealt cards keep their relative order underneath.
+    pub fn stack(&mut self, order: &[Card]) -> Result<(), PokerError> {
+        for (i, card) in order.iter().enumerate() {
+            if order[..i].contains(card) || !self.cards.contains(card) {
+                return Err(PokerError::DuplicateCard(*card));
+            }
+        }
+
+        let mut undealt = self.undealt();
+        undealt.retain(|card| !matches!(card, DeckCard::Standard(card) if order.contains(card)));
+        undealt.extend(order.iter().rev().map(|card| DeckCard::Standard(*card)));
+        self.set_undealt(undealt);
+
+        Ok(())
+    }
+
+    // Every k-card combination of the undealt cards, produced lazily
+    pub fn combinations(&self, k: usize) -> impl Iterator<Item = Vec<Card>> {
+        Combinations {
+            cards: self.cards.clone(),
+            indices: (0..k).collect(),
+            done: k > self.cards.len(),
+        }
+    }
+
+    // Take a specific card out of the undealt cards, as if it had been dealt
+    pub fn remove(&mut self, card: Card) -> Result<(), PokerError> {
+        let index = self
+            .cards
+            .iter()
+            .position(|c| *c == card)
+            .ok_or(PokerError::DuplicateCard(card))?;
+
+        self.dealt.push(self.cards.remove(index));
+        for under in self.jokers.iter_mut().filter(|under| **under > index) {
+            *under -= 1;
+        }
+
+        Ok(())
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.cards.len() + self.jokers.len()
+    }
+
+    // Undealt cards of each suit, suits with none left included
+    pub fn suit_counts(&self) -> HashMap<Suit, usize> {
+        let mut counts: HashMap<Suit, usize> = Suit::iter().map(|suit| (suit, 0)).collect();
+
+        for card in &self.cards {
+            *counts.entry(card.suit).or_insert(0) += 1;
+        }
+
+        counts
+    }
+
+    // Check that the undealt and dealt piles together still hold every card exactly once
+    // (or, for a Deck::multi shoe, every card the same number of times)
+    pub fn assert_integrity(&self) -> Result<(), PokerError> {
+        let total = self.cards.len() + self.dealt.len();
+        if total == 0 || !total.is_multiple_of(52) {
+            return Err(PokerError::CorruptDeck(format!(
+                "{} cards is not a whole number of decks",
+                total
+            )));
+        }
+
+        let mut counts = [0usize; 52];
+        for card in self.cards.iter().chain(&self.dealt) {
+            counts[card.to_index() as usize] += 1;
+        }
+
+        let copies = total / 52;
+        for (index, count) in counts.iter().enumerate() {
+            if *count != copies {
+                return Err(PokerError::CorruptDeck(format!(
+                    "{:?} appears {} times, expected {}",
+                    Card::from_index(index as u8).unwrap(),
+                    count,
+                    copies
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    // Each pile is written as a u16 little-endian length followed by one card index per byte,
+    // the undealt pile from the bottom up. A joker is written as index 52, in its place in
+    // the undealt pile and after the cards in the dealt one.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(4 + self.remaining() + self.dealt.len());
+        let index = |card: &DeckCard| match card {
+            DeckCard::Standard(card) => card.to_index(),
+            DeckCard::Joker => JOKER_INDEX,
+        };
+
+        let undealt = self.undealt();
+        bytes.extend((undealt.len() as u16).to_le_bytes());
+        bytes.extend(undealt.iter().map(index));
+
+        bytes.extend(((self.dealt.len() + self.jokers_dealt) as u16).to_le_bytes());
+        bytes.extend(self.dealt.iter().map(Card::to_index));
+        bytes.extend(std::iter::repeat_n(JOKER_INDEX, self.jokers_dealt));
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Deck, PokerError> {
+        let mut rest = bytes;
+        let mut piles = Vec::with_capacity(2);
+
+        for _ in 0..2 {
+            if rest.len() < 2 {
+                return Err(PokerError::ParseError(String::from(
+                    "truncated pile length",
+                )));
+            }
+
+            let len = u16::from_le_bytes([rest[0], rest[1]]) as usize;
+            rest = &rest[2..];
+
+            if rest.len() < len {
+                return Err(PokerError::ParseError(format!(
+                    "expected {} cards, found {}",
+                    len,
+                    rest.len()
+                )));
+            }
+
+            let pile = rest[..len]
+                .iter()
+                .map(|&index| match index {
+                    JOKER_INDEX => Ok(DeckCard::Joker),
+                    index => Card::from_index(index)
+                        .map(DeckCard::Standard)
+                        .ok_or_else(|| {
+                            PokerError::ParseError(format!("invalid card index {}", index))
+                        }),
+                })
+                .collect::<Result<Vec<DeckCard>, PokerError>>()?;
+
+            piles.push(pile);
+            rest = &rest[len..];
+        }
+
+        if !rest.is_empty() {
+            return Err(PokerError::ParseError(format!(
+                "{} trailing bytes",
+                rest.len()
+            )));
+        }
+
+        let dealt = piles.pop().unwrap_or_default();
+        let mut deck = Deck {
+            cards: Vec::new(),
+            dealt: Vec::with_capacity(dealt.len()),
+            jokers: Vec::new(),
+            jokers_dealt: 0,
+        };
+        deck.set_undealt(piles.pop().unwrap_or_default());
+
+        for card in dealt {
+            match card {
+                DeckCard::Standard(card) => deck.dealt.push(card),
+                DeckCard::Joker => deck.jokers_dealt += 1,
+            }
+        }
+
+        Ok(deck)
+    }
+}
+
+struct Combinations {
+    cards: Vec<Card>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl Iterator for Combinations {
+    type Item = Vec<Card>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let combination = self.indices.iter().map(|i| self.cards[*i]).collect();
+        self.done = !next_combination(&mut self.indices, self.cards.len());
+
+        Some(combination)
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Deck::new()
+    }
+}
+
+// A deck that owns its RNG, so every shuffle follows from the seed it was built with and
+// cloning it branches deck and RNG together. ChaCha20 keeps the stream the same across
+// rand releases, unlike StdRng.
+#[derive(Clone, Debug)]
+pub struct SeededDeck {
+    deck: Deck,
+    rng: ChaCha20Rng,
+}
+
+impl SeededDeck {
+    // A full deck, already given its first shuffle
+    pub fn new(seed: u64) -> Self {
+        let mut deck = SeededDeck {
+            deck: Deck::new(),
+            rng: ChaCha20Rng::seed_from_u64(seed),
+        };
+        deck.shuffle();
+
+        deck
+    }
+
+    pub fn deck(&self) -> &Deck {
+        &self.deck
+    }
+
+    // Gather every card and shuffle with the deck's own RNG
+    pub fn shuffle(&mut self) {
+        self.deck.reset();
+        self.deck.shuffle_with(&mut self.rng);
+    }
+
+    pub fn draw(&mut self, nr: usize) -> Vec<Card> {
+        self.deck.draw(nr)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.deck.remaining()
+    }
+}
+
+#[derive(Debug)]
+pub struct Hand {
+    cards: Vec<Card>,
+    hole_len: usize, // The first hole_len cards are the hole cards, the rest the board
+    bitmask: u16,
+    suit_map: HashMap<Suit, u8>,
+    rank_map: HashMap<Rank, u8>,
+}
+
+// Builds a Hand one card at a time, e.g. as each street is dealt
+#[derive(Debug)]
+pub struct HandBuilder {
+    hand: Hand,
+}
+
+impl HandBuilder {
+    pub fn new() -> Self {
+        HandBuilder {
+            hand: Hand {
+                cards: Vec::with_capacity(7),
+                hole_len: 0,
+                bitmask: 0x00,
+                suit_map: HashMap::with_capacity(4),
+                rank_map: HashMap::with_capacity(7),
+            },
+        }
+    }
+
+    pub fn add_card(&mut self, card: Card) {
+        let hand = &mut self.hand;
+        hand.cards.push(card);
+
+        let count = hand.suit_map.entry(card.suit).or_insert(0);
+        *count += 1;
+
+        let count = hand.rank_map.entry(card.rank).or_insert(0);
+        *count += 1;
+
+        hand.bitmask |= 1 << card.score();
+        if card.score() == 13 {
+            // If Ace also set bit 1
+            hand.bitmask |= 0x01;
+        }
+    }
+
+    // The hand so far, for evaluating before every card is in
+    pub fn hand(&self) -> &Hand {
+        &self.hand
+    }
+
+    pub fn build(self) -> Hand {
+        self.hand
+    }
+}
+
+impl Default for HandBuilder {
+    fn default() -> Self {
+        HandBuilder::new()
+    }
+}
+
+impl Hand {
+    pub fn new(hole_cards: &[Card], board_cards: &[Card]) -> Hand {
+        let mut builder = HandBuilder::new();
+
+        for card in hole_cards.iter().chain(board_cards) {
+            builder.add_card(*card);
+        }
+
+        let mut hand = builder.build();
+        hand.hole_len = hole_cards.len();
+
+        hand
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    // The hole cards as passed to Hand::new, none for a hand put together by HandBuilder
+    pub fn hole(&self) -> &[Card] {
+        &self.cards[..self.hole_len]
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.cards[self.hole_len..]
+    }
+
+    // Held cards of one suit, highest rank first
+    pub fn suit_cards(&self, suit: Suit) -> Vec<Card> {
+        let mut cards: Vec<Card> = self
+            .cards
+            .iter()
+            .filter(|c| c.suit == suit)
+            .copied()
+            .collect();
+        cards.sort_by_key(|card| std::cmp::Reverse(card.rank));
+
+        cards
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    // One bit per rank held: bit 1 is the Two up to bit 13 for the Ace, and an Ace also sets
+    // bit 0 so A-2-3-4-5 reads as five adjacent bits. Bits 14 and 15 are always clear.
+    pub fn rank_bitmask(&self) -> u16 {
+        self.bitmask
     }
 
     fn check_flush(&self) -> Option<HandRanking> {
+        self.check_flush_with(true)
+    }
+
+    fn check_flush_with(&self, wheel: bool) -> Option<HandRanking> {
         let mut flush_suit: Option<Suit> = None;
 
         for (suit, count) in &self.suit_map {
@@ -210,198 +1326,4557 @@ impl Hand {
             // For flush cards
             let mut bitmask = 0x00;
 
-            for card in &self.cards {
-                if card.suit == suit {
-                    bitmask |= 1 << card.score();
+            for card in &self.cards {
+                if card.suit == suit {
+                    bitmask |= 1 << card.score();
+
+                    // Also set bit 1 if Ace
+                    if card.score() == 13 && wheel {
+                        bitmask |= 0x01;
+                    }
+                }
+            }
+
+            if let Some(card) = self.best_straight(bitmask) {
+                if card == Rank::Ace {
+                    Some(HandRanking::RoyalFlush)
+                } else {
+                    Some(HandRanking::StraightFlush(card))
+                }
+            } else {
+                bitmask = self.highcards(bitmask, 5);
+
+                Some(HandRanking::Flush(bitmask))
+            }
+        } else {
+            None
+        }
+    }
+
+    // Returns the high card rank of the best possible straight, None if no straight found
+    fn best_straight(&self, bitmask: u16) -> Option<Rank> {
+        self.best_straight_wheel(bitmask).map(|(card, _)| card)
+    }
+
+    // Same as best_straight, also flagging whether the straight used the ace-low bit
+    fn best_straight_wheel(&self, bitmask: u16) -> Option<(Rank, bool)> {
+        let mut card: Option<(Rank, bool)> = None;
+
+        // There are ten possible straights, check from highest to lowest. The straight in
+        // bits i..=i + 4 tops out at bit i + 4, which is the score of its high card
+        for i in (0..10).rev() {
+            if bitmask & 0x1F << i == 0x1F << i {
+                card = Some((Rank::id(i + 4), i == 0));
+                break;
+            }
+        }
+
+        card
+    }
+
+    // Best straight in the hand and whether it is the A-2-3-4-5 wheel
+    pub fn straight(&self) -> Option<(Rank, bool)> {
+        self.best_straight_wheel(self.bitmask)
+    }
+
+    // The five cards making up the best hand, ordered the way it reads: the biggest group
+    // of a rank first, higher ranks first within a size, and the Ace last in a wheel
+    pub fn best_five(&self) -> Vec<Card> {
+        let mut five = five_card_subsets(&self.cards)
+            .into_iter()
+            .max_by_key(eval5)
+            .map(Vec::from)
+            .unwrap_or_else(|| self.cards.clone());
+
+        let wheel = matches!(
+            Hand::new(&five, &[]).best(),
+            HandRanking::Straight(Rank::Five) | HandRanking::StraightFlush(Rank::Five)
+        );
+        let counts = Hand::new(&five, &[]).rank_map;
+        five.sort_by_key(|card| {
+            let low_ace = wheel && card.rank == Rank::Ace;
+            std::cmp::Reverse((counts[&card.rank], !low_ace, card.rank, card.suit))
+        });
+
+        five
+    }
+
+    // All held cards, the best five first in the order best_five reads them, then the rest
+    // from highest to lowest
+    pub fn ordered_for_display(&self) -> Vec<Card> {
+        let mut cards = self.best_five();
+        let mut unused: Vec<Card> = self
+            .cards
+            .iter()
+            .filter(|card| !cards.contains(card))
+            .copied()
+            .collect();
+        unused.sort_by(|a, b| b.cmp(a));
+
+        cards.extend(unused);
+        cards
+    }
+
+    // Get bitmask representation of the high cards to be used in HandRanking
+    fn highcards(&self, mut bitmask: u16, bits_needed: u8) -> u16 {
+        // Make sure bit 1 is unset
+        bitmask &= !0x01;
+
+        let mut bits_set = self.bits_set(bitmask);
+
+        while bits_set > bits_needed {
+            bitmask &= bitmask - 1; // Unset lsb
+            bits_set -= 1;
+        }
+
+        bitmask
+    }
+
+    // Count number of bits that are set in bitmask
+    fn bits_set(&self, mut bitmask: u16) -> u8 {
+        let mut count = 0;
+
+        while bitmask != 0 {
+            bitmask &= bitmask - 1;
+            count += 1;
+        }
+
+        count
+    }
+
+    pub fn best(&self) -> HandRanking {
+        self.evaluate(true)
+    }
+
+    // Evaluate for variants where A-2-3-4-5 is not a straight and the Ace only plays high
+    pub fn best_no_wheel(&self) -> HandRanking {
+        self.evaluate(false)
+    }
+
+    fn evaluate(&self, wheel: bool) -> HandRanking {
+        let mut pair: Vec<Rank> = Vec::with_capacity(3);
+        let mut set: Vec<Rank> = Vec::with_capacity(2);
+
+        for (card, count) in &self.rank_map {
+            match count {
+                2 => pair.push(*card),
+                3 => set.push(*card),
+
+                // Check for Quads
+                4 => {
+                    let bitmask = self.bitmask ^ 1 << card.score(); // Unset quads bit
+
+                    // Without a fifth card the quads rank stands in for the kicker
+                    let kicker = ranks_from_mask(bitmask).first().copied().unwrap_or(*card);
+
+                    return HandRanking::Quads(*card, kicker);
+                }
+                _ => (),
+            }
+        }
+
+        // Check for FullHouse, a second set can fill in for the pair
+        set.sort();
+        set.reverse();
+
+        if let Some((top, rest)) = set.split_first() {
+            if let Some(filler) = rest.iter().chain(&pair).max() {
+                return HandRanking::FullHouse(*top, *filler);
+            }
+        }
+
+        // Check for Flush and StraightFlush
+        let flush = if wheel {
+            self.check_flush()
+        } else {
+            self.check_flush_with(false)
+        };
+        if let Some(flush) = flush {
+            return flush;
+        }
+
+        // Check for Straight
+        // Bit 0 is the Ace playing low
+        let straights = if wheel {
+            self.bitmask
+        } else {
+            self.bitmask & !0x01
+        };
+        if let Some(card) = self.best_straight(straights) {
+            return HandRanking::Straight(card);
+        }
+
+        // Clear bit 0, the ace-low copy is only needed for straights. Kickers and the
+        // paired or tripped rank are then taken from bits 1..=13 alone.
+        let mut bitmask = self.bitmask & !0x01;
+
+        // Check for Set
+        if set.len() == 1 {
+            bitmask ^= 1 << set[0].score(); // Unset set1 bit
+
+            bitmask = self.highcards(bitmask, 2); // Two high cards
+
+            return HandRanking::Set(set[0], bitmask);
+        }
+
+        // Check for TwoPair
+        if pair.len() > 1 {
+            pair.sort();
+            pair.reverse();
+
+            bitmask ^= 1 << pair[0].score(); // Unset pair1 bit
+            bitmask ^= 1 << pair[1].score(); // Unset pair2 bit
+
+            // Without a fifth card the second pair stands in for the kicker
+            let kicker = ranks_from_mask(bitmask).first().copied().unwrap_or(pair[1]);
+
+            return HandRanking::TwoPair(pair[0], pair[1], kicker);
+        }
+
+        // Check for OnePair
+        if !pair.is_empty() {
+            bitmask ^= 1 << pair[0].score(); // Unset pair1 bit
+
+            bitmask = self.highcards(bitmask, 3); // Three high cards
+
+            return HandRanking::OnePair(pair[0], bitmask);
+        }
+
+        // None of the above means HighCard
+        bitmask = self.highcards(bitmask, 5); // Five high cards
+
+        HandRanking::HighCard(bitmask)
+    }
+
+    pub fn display(&self) {
+        println!("Hand is {:?}", self.cards)
+    }
+}
+
+pub fn group_by_suit(cards: &[Card]) -> HashMap<Suit, Vec<Card>> {
+    let mut groups: HashMap<Suit, Vec<Card>> = HashMap::with_capacity(4);
+
+    for card in cards {
+        groups.entry(card.suit).or_default().push(*card);
+    }
+
+    groups
+}
+
+pub fn group_by_rank(cards: &[Card]) -> HashMap<Rank, Vec<Card>> {
+    let mut groups: HashMap<Rank, Vec<Card>> = HashMap::with_capacity(cards.len());
+
+    for card in cards {
+        groups.entry(card.rank).or_default().push(*card);
+    }
+
+    groups
+}
+
+// Unseen cards that would lift the hand into a better category as the next card
+pub fn outs(hole: &[Card; 2], board: &[Card]) -> Vec<Card> {
+    let current = Hand::new(hole, board).best().category();
+    let mut cards = board.to_vec();
+
+    stub_cards(&[*hole], board, &[])
+        .into_iter()
+        .filter(|card| {
+            cards.truncate(board.len());
+            cards.push(*card);
+
+            Hand::new(hole, &cards).best().category() > current
+        })
+        .collect()
+}
+
+pub fn count_outs(hole: &[Card; 2], board: &[Card]) -> usize {
+    outs(hole, board).len()
+}
+
+// Best category on the flop, turn and river as the board comes out
+pub fn category_at_each_street(hole: &[Card; 2], full_board: &[Card; 5]) -> [HandCategory; 3] {
+    [3, 4, 5].map(|shown| Hand::new(hole, &full_board[..shown]).best().category())
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Showdown {
+    pub winners: Vec<usize>, // Seats sharing the pot, more than one on a chop
+    pub rankings: Vec<HandRanking>, // Every seat's best hand, in seat order
+}
+
+// Rank every seat's hand on a flop, turn or river board and find who takes the pot
+pub fn resolve_showdown(holes: &[[Card; 2]], board: &[Card]) -> Result<Showdown, PokerError> {
+    if board.len() < 3 {
+        return Err(PokerError::TooFewCards(board.len()));
+    }
+    if board.len() > 5 {
+        return Err(PokerError::TooManyCards(board.len()));
+    }
+
+    let mut seen: Vec<Card> = Vec::with_capacity(holes.len() * 2 + board.len());
+    for card in holes.iter().flatten().chain(board) {
+        if seen.contains(card) {
+            return Err(PokerError::DuplicateCard(*card));
+        }
+        seen.push(*card);
+    }
+
+    let rankings: Vec<HandRanking> = holes
+        .iter()
+        .map(|hole| Hand::new(hole, board).best())
+        .collect();
+    let best = rankings.iter().max().copied();
+    let winners = (0..holes.len())
+        .filter(|seat| Some(rankings[*seat]) == best)
+        .collect();
+
+    Ok(Showdown { winners, rankings })
+}
+
+// Winning seats, the winning ranking and the five cards it is made of (those of the first
+// winner when the pot is chopped)
+pub fn showdown_with_cards(
+    holes: &[[Card; 2]],
+    board: &[Card; 5],
+) -> (Vec<usize>, HandRanking, [Card; 5]) {
+    let hands: Vec<Hand> = holes.iter().map(|hole| Hand::new(hole, board)).collect();
+    let rankings: Vec<HandRanking> = hands.iter().map(Hand::best).collect();
+    let best = *rankings.iter().max().expect("no players at showdown");
+
+    let winners: Vec<usize> = (0..holes.len())
+        .filter(|seat| rankings[*seat] == best)
+        .collect();
+    let five = hands[winners[0]].best_five();
+
+    (winners, best, [five[0], five[1], five[2], five[3], five[4]])
+}
+
+// What the five board cards make on their own, the hand every player can play
+pub fn board_plays(board: &[Card; 5]) -> HandRanking {
+    eval5(board)
+}
+
+// Boards already making a straight or better, where hole cards rarely change the result
+// and chopped pots are common
+pub fn board_counterfeits(board: &[Card; 5]) -> bool {
+    board_plays(board).category() >= HandCategory::Straight
+}
+
+// Whether both hole cards are needed for the hand, i.e. it beats what either card makes alone
+fn plays_both_cards(hole: &[Card; 2], board: &[Card]) -> bool {
+    let best = Hand::new(hole, board).best();
+
+    hole.iter()
+        .all(|card| Hand::new(&[*card], board).best() < best)
+}
+
+// Whether a hand that needed both hole cards on the flop, such as two pair with both cards
+// paired, is still in the same category on the river but one hole card no longer plays
+// because the board paired over it
+pub fn counterfeited(hole: &[Card; 2], flop_board: &[Card], river_board: &[Card; 5]) -> bool {
+    let flop = Hand::new(hole, flop_board).best();
+    let river = Hand::new(hole, river_board).best();
+
+    flop.category() == river.category()
+        && plays_both_cards(hole, flop_board)
+        && !plays_both_cards(hole, river_board)
+}
+
+// Every two-card holding left once the board and dead cards are out of the deck
+fn hole_combos(board: &[Card], dead: &[Card]) -> Vec<[Card; 2]> {
+    let live: Vec<Card> = Deck::new()
+        .cards
+        .into_iter()
+        .filter(|card| !board.contains(card) && !dead.contains(card))
+        .collect();
+
+    let mut combos = Vec::with_capacity(live.len() * live.len() / 2);
+    for (i, first) in live.iter().enumerate() {
+        for second in &live[i + 1..] {
+            combos.push([*first, *second]);
+        }
+    }
+
+    combos
+}
+
+// All combos of one hand class such as "QQ", "AKs", "AKo" or "AK" (suited and offsuit)
+fn class_combos(high: Rank, low: Rank, suited: Option<bool>) -> Vec<[Card; 2]> {
+    let mut combos = Vec::new();
+
+    for first in Suit::iter() {
+        for second in Suit::iter() {
+            let keep = if high == low {
+                first < second
+            } else {
+                suited.is_none_or(|suited| suited == (first == second))
+            };
+
+            if keep {
+                combos.push([Card::new(high, first), Card::new(low, second)]);
+            }
+        }
+    }
+
+    combos
+}
+
+// Expand a comma separated range like "QQ+, AKs, ATo+, 76s" into concrete combos.
+// A trailing + raises pairs up to aces, and otherwise the kicker up to one below the top card.
+pub fn parse_range(range: &str) -> Result<Vec<[Card; 2]>, PokerError> {
+    let mut combos = Vec::new();
+
+    for class in range.split(',').map(str::trim).filter(|c| !c.is_empty()) {
+        let invalid = || PokerError::ParseError(format!("invalid hand class {:?}", class));
+        let (body, plus) = match class.strip_suffix('+') {
+            Some(body) => (body, true),
+            None => (class, false),
+        };
+
+        let chars: Vec<char> = body.chars().collect();
+        let (high, low, suited) = match chars.as_slice() {
+            [a, b] => (Rank::from_char(*a), Rank::from_char(*b), None),
+            [a, b, 's' | 'S'] => (Rank::from_char(*a), Rank::from_char(*b), Some(true)),
+            [a, b, 'o' | 'O'] => (Rank::from_char(*a), Rank::from_char(*b), Some(false)),
+            _ => return Err(invalid()),
+        };
+        let (high, low) = match (high, low) {
+            (Some(a), Some(b)) => (a.max(b), a.min(b)),
+            _ => return Err(invalid()),
+        };
+        if high == low && suited.is_some() {
+            return Err(invalid());
+        }
+
+        if high == low {
+            let mut pair = Some(high);
+            while let Some(rank) = pair {
+                combos.extend(class_combos(rank, rank, None));
+                pair = rank.next().filter(|_| plus);
+            }
+            continue;
+        }
+
+        let mut next = Some(low);
+        while let Some(low) = next {
+            combos.extend(class_combos(high, low, suited));
+            next = low.next().filter(|next| plus && *next < high);
+        }
+    }
+
+    Ok(combos)
+}
+
+// Combos of a hand class still possible with the board and dead cards out
+pub fn remaining_combos(class: &str, board: &[Card], dead: &[Card]) -> Result<usize, PokerError> {
+    Ok(parse_range(class)?
+        .iter()
+        .filter(|combo| combo_is_possible(combo, board, dead))
+        .count())
+}
+
+// Combos of a hand class or range still possible on the board that the hero's hole cards
+// take out, e.g. the flush-suit Ace removing nut flush combos
+pub fn blocker_count(
+    hero: &[Card; 2],
+    target_class: &str,
+    board: &[Card],
+) -> Result<usize, PokerError> {
+    Ok(parse_range(target_class)?
+        .iter()
+        .filter(|combo| combo_is_possible(combo, board, &[]))
+        .filter(|combo| combo.iter().any(|card| hero.contains(card)))
+        .count())
+}
+
+// Whether a player can still hold the combo, i.e. neither card is on the board or dead
+pub fn combo_is_possible(combo: &[Card; 2], board: &[Card], dead: &[Card]) -> bool {
+    combo[0] != combo[1]
+        && combo
+            .iter()
+            .all(|card| !board.contains(card) && !dead.contains(card))
+}
+
+// Strongest hand any two live hole cards can make on the board
+pub fn nut_hand(board: &[Card], dead: &[Card]) -> HandRanking {
+    hole_combos(board, dead)
+        .iter()
+        .map(|hole| Hand::new(hole, board).best())
+        .max()
+        .expect("no live hole cards")
+}
+
+// The live turn card that lifts the nuts the most from where they stand on the flop. Ties go
+// to the first card in deck order.
+pub fn scariest_turn_card(flop: &[Card; 3], dead: &[Card]) -> Card {
+    // A flop and two hole cards always make five, so every nut hand has a strength
+    let strength = |board: &[Card]| nut_hand(board, dead).strength().expect("five-card hand");
+    let flop_nuts = strength(flop);
+    let mut scariest: Option<(u16, Card)> = None;
+
+    for turn in Deck::new().cards {
+        if flop.contains(&turn) || dead.contains(&turn) {
+            continue;
+        }
+        let board = [flop[0], flop[1], flop[2], turn];
+        let jump = strength(&board) - flop_nuts;
+        if scariest.is_none_or(|(best, _)| jump > best) {
+            scariest = Some((jump, turn));
+        }
+    }
+
+    scariest.expect("no live turn cards").1
+}
+
+// Live hole cards that beat the hero on the river, zero meaning the hero has the nuts or
+// chops them. The hero's own hole cards belong in dead.
+pub fn hands_beating_count(hero: &HandRanking, board: &[Card; 5], dead: &[Card]) -> usize {
+    hole_combos(board, dead)
+        .iter()
+        .filter(|hole| Hand::new(*hole, board).best() > *hero)
+        .count()
+}
+
+// The n strongest distinct rankings two live hole cards can make on the board, best first
+pub fn top_rankings(board: &[Card], dead: &[Card], n: usize) -> Vec<HandRanking> {
+    let mut rankings: Vec<HandRanking> = hole_combos(board, dead)
+        .iter()
+        .map(|hole| Hand::new(hole, board).best())
+        .collect();
+    rankings.sort_unstable_by(|a, b| b.cmp(a));
+    rankings.dedup();
+    rankings.truncate(n);
+
+    rankings
+}
+
+// Share of the opponent holdings left in the deck the hand beats right now, ties counting
+// half. Exact, by evaluating every one of them on the board as it stands.
+pub fn hand_strength(hole: &[Card; 2], board: &[Card]) -> f64 {
+    let ours = Hand::new(hole, board).best();
+    let combos = hole_combos(board, hole);
+
+    let won: f64 = combos
+        .iter()
+        .map(
+            |villain| match ours.cmp(&Hand::new(villain, board).best()) {
+                std::cmp::Ordering::Greater => 1.0,
+                std::cmp::Ordering::Equal => 0.5,
+                std::cmp::Ordering::Less => 0.0,
+            },
+        )
+        .sum();
+
+    won / combos.len() as f64
+}
+
+// Best ranking the known cards can reach once `wilds` more cards are picked freely from
+// the rest of the deck, e.g. the best hand possible with two cards to come
+pub fn best_with_wilds(known: &[Card], wilds: usize) -> HandRanking {
+    let mut deck = Deck::new();
+    deck.cards.retain(|card| !known.contains(card));
+
+    deck.combinations(wilds)
+        .map(|extra| Hand::new(known, &extra).best())
+        .max()
+        .unwrap_or_else(|| Hand::new(known, &[]).best())
+}
+
+// Best hand with each joker played as whichever card helps most
+pub fn best_with_jokers(cards: &[DeckCard]) -> HandRanking {
+    let known: Vec<Card> = cards
+        .iter()
+        .filter_map(|card| match card {
+            DeckCard::Standard(card) => Some(*card),
+            DeckCard::Joker => None,
+        })
+        .collect();
+
+    best_with_wilds(&known, cards.len() - known.len())
+}
+
+// Fewest cards still to come that can lift the hand to the target category, at most enough
+// to reach seven cards. None when no such run of cards is left outside the dead cards.
+pub fn cards_to_guarantee(current: &[Card], target: HandCategory, dead: &[Card]) -> Option<usize> {
+    let mut deck = Deck::new();
+    deck.cards
+        .retain(|card| !current.contains(card) && !dead.contains(card));
+
+    (0..=7usize.saturating_sub(current.len())).find(|&count| {
+        deck.combinations(count)
+            .any(|extra| Hand::new(current, &extra).best().is_at_least(target))
+    })
+}
+
+// Weakest hand any two live hole cards can make on the board
+pub fn worst_hand(board: &[Card], dead: &[Card]) -> HandRanking {
+    hole_combos(board, dead)
+        .iter()
+        .map(|hole| Hand::new(hole, board).best())
+        .min()
+        .expect("no live hole cards")
+}
+
+// The k strongest live hole cards on the board, strongest first. A min-heap of size k keeps
+// the best seen so far, its weakest at the top ready to be pushed out by a better combo.
+pub fn top_k_combos(board: &[Card], dead: &[Card], k: usize) -> Vec<([Card; 2], HandRanking)> {
+    let mut heap = BinaryHeap::with_capacity(k + 1);
+
+    for hole in hole_combos(board, dead) {
+        heap.push(Reverse((Hand::new(&hole, board).best(), hole)));
+
+        if heap.len() > k {
+            heap.pop();
+        }
+    }
+
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((ranking, hole))| (hole, ranking))
+        .collect()
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct DrawInfo {
+    pub flush_draw: bool,
+    pub open_ended_straight_draw: bool,
+    pub gutshot: bool,
+    pub backdoor_flush: bool,
+}
+
+// Differences between consecutive distinct rank scores, lowest first. A gap of 1 means
+// adjacent ranks, so 5-6-8-9 gives [1, 2, 1] with the missing Seven as the 2.
+pub fn rank_gaps(cards: &[Card]) -> Vec<u8> {
+    let mut scores: Vec<u8> = cards.iter().map(Card::score).collect();
+    scores.sort_unstable();
+    scores.dedup();
+
+    scores.windows(2).map(|pair| pair[1] - pair[0]).collect()
+}
+
+// Draws present in the cards seen so far (hole and board together). A straight draw is
+// open-ended when two or more ranks complete it, a gutshot when only one does.
+pub fn draws(cards: &[Card]) -> DrawInfo {
+    let hand = Hand::new(cards, &[]);
+    let mut info = DrawInfo::default();
+
+    let max_suited = hand.suit_map.values().copied().max().unwrap_or(0);
+    info.flush_draw = max_suited == 4;
+    info.backdoor_flush = max_suited == 3 && cards.len() <= 5;
+
+    if hand.best_straight(hand.bitmask).is_none() {
+        let completing = Rank::iter()
+            .filter(|rank| hand.bitmask & 1 << rank.score() == 0)
+            .filter(|rank| {
+                let mut bitmask = hand.bitmask | 1 << rank.score();
+                if *rank == Rank::Ace {
+                    bitmask |= 0x01;
+                }
+
+                hand.best_straight(bitmask).is_some()
+            })
+            .count();
+
+        info.open_ended_straight_draw = completing >= 2;
+        info.gutshot = completing == 1;
+    }
+
+    info
+}
+
+// Names of the draws in the cards for teaching tools, e.g. ["nut flush draw", "gutshot",
+// "combo draw"]. A flush draw is the nut one when the Ace of its suit is among the cards.
+pub fn draw_description(cards: &[Card]) -> Vec<String> {
+    let info = draws(cards);
+    let mut names = Vec::new();
+
+    if info.flush_draw {
+        let hand = Hand::new(cards, &[]);
+        let nut = hand
+            .suit_map
+            .iter()
+            .filter(|(_, count)| **count == 4)
+            .any(|(suit, _)| cards.contains(&Card::new(Rank::Ace, *suit)));
+
+        names.push(if nut { "nut flush draw" } else { "flush draw" });
+    }
+    if info.backdoor_flush {
+        names.push("backdoor flush draw");
+    }
+    if info.open_ended_straight_draw {
+        names.push("open-ended straight draw");
+    }
+    if info.gutshot {
+        names.push("gutshot");
+    }
+    if info.flush_draw && (info.open_ended_straight_draw || info.gutshot) {
+        names.push("combo draw");
+    }
+
+    names.into_iter().map(String::from).collect()
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct BoardTexture {
+    pub is_monotone: bool,
+    pub is_two_tone: bool,
+    pub is_rainbow: bool,
+    pub is_paired: bool,
+    pub straight_possible: bool,
+}
+
+// Summary of the board for strategy heuristics. A straight is possible when three
+// distinct ranks fit inside one five-rank window, counting the Ace as low too.
+pub fn board_texture(board: &[Card]) -> BoardTexture {
+    let hand = Hand::new(board, &[]);
+    let suits = hand.suit_map.len();
+
+    BoardTexture {
+        is_monotone: suits == 1,
+        is_two_tone: suits == 2,
+        is_rainbow: !board.is_empty() && suits == board.len(),
+        is_paired: hand.rank_map.values().any(|count| *count >= 2),
+        straight_possible: (0..10).any(|i| hand.bits_set(hand.bitmask & 0x1F << i) >= 3),
+    }
+}
+
+// High cards of the straights in a rank bitmask that two more cards can complete, that is
+// the five-rank windows holding at least three of its ranks, highest first
+fn completable_straights(bitmask: u16) -> Vec<Rank> {
+    (0..10)
+        .rev()
+        .filter(|i| (bitmask & 0x1F << i).count_ones() >= 3)
+        .map(|i| Rank::id(i + 4))
+        .collect()
+}
+
+// Every straight a player can make with two hole cards on this board, by its high card
+pub fn possible_straights(board: &[Card]) -> Vec<Rank> {
+    completable_straights(Hand::new(board, &[]).bitmask)
+}
+
+// As possible_straights, for straight flushes: three cards of one suit in the window
+pub fn possible_straight_flushes(board: &[Card]) -> Vec<Rank> {
+    let mut highs: Vec<Rank> = Suit::iter()
+        .flat_map(|suit| {
+            let suited: Vec<Card> = board.iter().filter(|c| c.suit == suit).copied().collect();
+            completable_straights(Hand::new(&suited, &[]).bitmask)
+        })
+        .collect();
+    highs.sort_unstable_by(|a, b| b.cmp(a));
+    highs.dedup();
+
+    highs
+}
+
+// Heuristic 0-10 danger score for a board, the sum of:
+//  - flush potential: 4 with three or more cards of a suit, 2 with two
+//  - straight potential: one less than the most distinct ranks in any five-rank window, at most 4
+//  - pairing: 2 if any rank is paired, for the full houses and quads it allows
+pub fn board_wetness(board: &[Card]) -> u8 {
+    let hand = Hand::new(board, &[]);
+
+    let flush = match hand.suit_map.values().max() {
+        Some(count) if *count >= 3 => 4,
+        Some(2) => 2,
+        _ => 0,
+    };
+    let window = (0..10)
+        .map(|i| hand.bits_set(hand.bitmask & 0x1F << i))
+        .max()
+        .unwrap_or(0);
+    let straight = window.saturating_sub(1).min(4);
+    let paired = if hand.rank_map.values().any(|count| *count >= 2) {
+        2
+    } else {
+        0
+    };
+
+    (flush + straight + paired).min(10)
+}
+
+// All 24 ways of relabelling the four suits
+fn suit_permutations() -> Vec<[u8; 4]> {
+    let mut perms = Vec::with_capacity(24);
+
+    for a in 0..4 {
+        for b in (0..4).filter(|&b| b != a) {
+            for c in (0..4).filter(|&c| c != a && c != b) {
+                perms.push([a, b, c, 6 - a - b - c]);
+            }
+        }
+    }
+
+    perms
+}
+
+// Key that is identical for card sets differing only by a relabelling of suits.
+// Each card sets its to_index bit and the smallest key over all suit permutations wins.
+pub fn canonicalize(cards: &[Card]) -> u64 {
+    suit_permutations()
+        .iter()
+        .map(|perm| {
+            cards.iter().fold(0u64, |key, card| {
+                let index = (card.score() - 1) * 4 + perm[card.suit as usize];
+                key | 1 << index
+            })
+        })
+        .min()
+        .unwrap_or(0)
+}
+
+// Representative of the cards' suit isomorphism class, highest card first. The suits are
+// those of the canonicalize key laid onto the order, so the result only depends on the order
+// and not on which suits came in.
+pub fn canonical_form(cards: &[Card], order: SuitOrder) -> Vec<Card> {
+    let key = canonicalize(cards);
+
+    (0..52u8)
+        .rev()
+        .filter(|bit| key & 1 << bit != 0)
+        .map(|bit| {
+            let rank = Rank::from_ordinal(bit / 4).expect("rank below 13");
+            Card::new(rank, order.suit_at(bit % 4))
+        })
+        .collect()
+}
+
+// Sort cards for display, highest rank first and the higher suit first within a rank
+pub fn sort_cards(cards: &mut [Card], order: SuitOrder) {
+    cards.sort_by_key(|card| std::cmp::Reverse((card.rank, order.position(card.suit))));
+}
+
+// Shuffle a fresh deck, deal one player and a full board with burns, and evaluate
+pub fn random_holdem<R: Rng + ?Sized>(rng: &mut R) -> ([Card; 2], [Card; 5], HandRanking) {
+    let mut deck = Deck::new();
+    deck.shuffle_with(rng);
+
+    let hole = deck.draw(2);
+    let mut board = Vec::with_capacity(5);
+    let mut street = Street::Preflop;
+
+    while let Ok(cards) = deck.deal_to_street(street) {
+        board.extend(cards);
+        street = street.next().unwrap();
+    }
+
+    let hole = [hole[0], hole[1]];
+    let board = [board[0], board[1], board[2], board[3], board[4]];
+    let ranking = Hand::new(&hole, &board).best();
+
+    (hole, board, ranking)
+}
+
+// One line per hand for hand history tools, e.g.
+// "P0:AsKs P1:QhQd | board:Ts7c2d 9h 4s | winner:P0", with chopped pots listing every winner
+// separated by commas
+pub fn format_hand_history(holes: &[[Card; 2]], board: &[Card; 5], winners: &[usize]) -> String {
+    let players: Vec<String> = holes
+        .iter()
+        .enumerate()
+        .map(|(seat, hole)| format!("P{}:{}{}", seat, hole[0], hole[1]))
+        .collect();
+    let winners: Vec<String> = winners.iter().map(|seat| format!("P{}", seat)).collect();
+
+    format!(
+        "{} | board:{}{}{} {} {} | winner:{}",
+        players.join(" "),
+        board[0],
+        board[1],
+        board[2],
+        board[3],
+        board[4],
+        winners.join(",")
+    )
+}
+
+// Lay out rendered cards side by side, one space apart
+pub fn render_row(cards: &[Card]) -> String {
+    let rendered: Vec<[String; 3]> = cards.iter().map(Card::render_ascii).collect();
+
+    (0..3)
+        .map(|line| {
+            rendered
+                .iter()
+                .map(|card| card[line].as_str())
+                .collect::<Vec<&str>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Cards still in the deck once the hands, board and dead cards are taken out
+fn stub_cards(hands: &[[Card; 2]], board: &[Card], dead: &[Card]) -> Vec<Card> {
+    let known: Vec<Card> = hands
+        .iter()
+        .flatten()
+        .chain(board)
+        .chain(dead)
+        .copied()
+        .collect();
+
+    Deck::new()
+        .cards
+        .into_iter()
+        .filter(|card| !known.contains(card))
+        .collect()
+}
+
+// Split one pot between the best hands on a complete board
+fn award_shares(hands: &[[Card; 2]], board: &[Card], shares: &mut [f64]) {
+    let rankings: Vec<HandRanking> = hands
+        .iter()
+        .map(|hole| Hand::new(hole, board).best())
+        .collect();
+    let best = rankings.iter().max().copied();
+    let winners = rankings.iter().filter(|r| Some(**r) == best).count();
+
+    for (share, ranking) in shares.iter_mut().zip(&rankings) {
+        if Some(*ranking) == best {
+            *share += 1.0 / winners as f64;
+        }
+    }
+}
+
+// Sum of pot shares won by each hand over random runouts of the board
+fn equity_shares<R: Rng + ?Sized>(
+    hands: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+    iterations: usize,
+    rng: &mut R,
+) -> Vec<f64> {
+    let stub = stub_cards(hands, board, dead);
+    let mut shares = vec![0.0; hands.len()];
+    let mut runout = board.to_vec();
+
+    for _ in 0..iterations {
+        runout.truncate(board.len());
+        runout.extend(stub.choose_multiple(rng, 5 - board.len()));
+
+        award_shares(hands, &runout, &mut shares);
+    }
+
+    shares
+}
+
+// Estimate each hand's share of the pot by dealing random runouts of the board
+pub fn equity_monte_carlo<R: Rng + ?Sized>(
+    hands: &[[Card; 2]],
+    board: &[Card],
+    iterations: usize,
+    rng: &mut R,
+) -> Vec<f64> {
+    equity_monte_carlo_dead(hands, board, &[], iterations, rng)
+}
+
+// Same as equity_monte_carlo, with dead cards (folded hands, burns seen) kept out of the runouts
+pub fn equity_monte_carlo_dead<R: Rng + ?Sized>(
+    hands: &[[Card; 2]],
+    board: &[Card],
+    dead: &[Card],
+    iterations: usize,
+    rng: &mut R,
+) -> Vec<f64> {
+    equity_shares(hands, board, dead, iterations, rng)
+        .into_iter()
+        .map(|share| share / iterations as f64)
+        .collect()
+}
+
+// Advance indices to the next k-combination of 0..n in lexicographic order,
+// returning false once the last one has been passed
+fn next_combination(indices: &mut [usize], n: usize) -> bool {
+    let k = indices.len();
+
+    for i in (0..k).rev() {
+        if indices[i] < n - k + i {
+            indices[i] += 1;
+            for j in i + 1..k {
+                indices[j] = indices[j - 1] + 1;
+            }
+            return true;
+        }
+    }
+
+    false
+}
+
+// Every way of picking five of the cards
+fn five_card_subsets(cards: &[Card]) -> Vec<[Card; 5]> {
+    let mut subsets = Vec::new();
+    if cards.len() < 5 {
+        return subsets;
+    }
+
+    let mut indices = [0, 1, 2, 3, 4];
+    loop {
+        subsets.push(indices.map(|i| cards[i]));
+
+        if !next_combination(&mut indices, cards.len()) {
+            break;
+        }
+    }
+
+    subsets
+}
+
+// Whether the ranking can be made from exactly `required` hole cards and the rest from the
+// board, as Omaha demands (two hole cards, three board cards)
+pub fn uses_n_hole_cards(
+    hole: &[Card],
+    board: &[Card],
+    ranking: &HandRanking,
+    required: usize,
+) -> bool {
+    let mut cards = hole.to_vec();
+    cards.extend(board);
+
+    five_card_subsets(&cards).iter().any(|five| {
+        let from_hole = five.iter().filter(|card| hole.contains(card)).count();
+
+        from_hole == required && eval5(five) == *ranking
+    })
+}
+
+// Call f with every complete five card board that can be dealt from the stub,
+// returning how many there were
+fn for_each_runout<F: FnMut(&[Card])>(board: &[Card], stub: &[Card], mut f: F) -> usize {
+    let mut runout = board.to_vec();
+    let mut indices: Vec<usize> = (0..5 - board.len()).collect();
+    let mut runouts = 0;
+
+    loop {
+        runout.truncate(board.len());
+        runout.extend(indices.iter().map(|i| stub[*i]));
+
+        f(&runout);
+        runouts += 1;
+
+        if !next_combination(&mut indices, stub.len()) {
+            return runouts;
+        }
+    }
+}
+
+// Each hand's exact share of the pot over every possible runout, leaving out dead cards
+pub fn equity_exact(hands: &[[Card; 2]], board: &[Card], dead: &[Card]) -> Vec<f64> {
+    let stub = stub_cards(hands, board, dead);
+    let mut shares = vec![0.0; hands.len()];
+
+    let runouts = for_each_runout(board, &stub, |runout| {
+        award_shares(hands, runout, &mut shares);
+    });
+
+    shares
+        .into_iter()
+        .map(|share| share / runouts as f64)
+        .collect()
+}
+
+// Index of a starting hand's class in a 13 x 13 grid of rank ordinals: pairs on the
+// diagonal, suited hands with the higher rank as the row and offsuit ones as the column
+fn preflop_class(hand: &[Card; 2]) -> usize {
+    let (high, low) = (hand[0].max(hand[1]), hand[0].min(hand[1]));
+    let (high_rank, low_rank) = (high.rank.ordinal() as usize, low.rank.ordinal() as usize);
+
+    if high.suit == low.suit {
+        high_rank * 13 + low_rank
+    } else {
+        low_rank * 13 + high_rank
+    }
+}
+
+// All-in preflop equity of two hands, ties split, looked up in the table of the 169
+// starting hand classes (see examples/preflop_table.rs). Each class pair holds the exact
+// equity averaged over the suit combinations the classes allow, so the result can be a
+// point or so off for hands sharing a suit, e.g. AsKs against QsJs.
+pub fn preflop_equity_2way(a: &[Card; 2], b: &[Card; 2]) -> (f64, f64) {
+    let (i, j) = (preflop_class(a), preflop_class(b));
+    let (low, high) = (i.min(j), i.max(j));
+    let equity = preflop_table::PREFLOP_EQUITY[low * (339 - low) / 2 + (high - low)];
+
+    if i <= j {
+        (equity, 1.0 - equity)
+    } else {
+        (1.0 - equity, equity)
+    }
+}
+
+// Live equity of hands as the board comes out. Once the flop is out every runout is
+// evaluated a single time and kept with each hand's share, and each later reveal only
+// drops the runouts that didn't bring that card. Before the flop there are too many
+// runouts to keep, so the equity is enumerated afresh, or heads up looked up through
+// preflop_equity_2way.
+#[derive(Clone, Debug)]
+pub struct EquityTracker {
+    hands: Vec<[Card; 2]>,
+    board: Vec<Card>,
+    runouts: Vec<(Vec<Card>, Vec<f64>)>, // Cards still to come and each hand's share
+    equity: Option<Vec<f64>>,
+}
+
+impl EquityTracker {
+    pub fn new(hands: &[[Card; 2]]) -> Self {
+        EquityTracker {
+            hands: hands.to_vec(),
+            board: Vec::with_capacity(5),
+            runouts: Vec::new(),
+            equity: None,
+        }
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    pub fn reveal(&mut self, card: Card) -> Result<(), PokerError> {
+        if self.board.len() == 5 {
+            return Err(PokerError::TooManyCards(6));
+        }
+        if self.board.contains(&card) || self.hands.iter().flatten().any(|c| *c == card) {
+            return Err(PokerError::DuplicateCard(card));
+        }
+
+        self.board.push(card);
+        self.equity = None;
+        self.runouts.retain_mut(
+            |(to_come, _)| match to_come.iter().position(|c| *c == card) {
+                Some(i) => {
+                    to_come.swap_remove(i);
+                    true
+                }
+                None => false,
+            },
+        );
+
+        Ok(())
+    }
+
+    // Each hand's share of the pot, exact once the river is out
+    pub fn equity(&mut self) -> &[f64] {
+        if self.board.len() >= 3 && self.runouts.is_empty() {
+            self.runouts = self.enumerate_runouts();
+        }
+        let (hands, board, runouts) = (&self.hands, &self.board, &self.runouts);
+
+        self.equity
+            .get_or_insert_with(|| match (&hands[..], board.len()) {
+                ([a, b], 0) => {
+                    let (a, b) = preflop_equity_2way(a, b);
+                    vec![a, b]
+                }
+                (_, 0..=2) => equity_exact(hands, board, &[]),
+                _ => {
+                    let mut totals = vec![0.0; hands.len()];
+                    for (_, shares) in runouts {
+                        for (total, share) in totals.iter_mut().zip(shares) {
+                            *total += share;
+                        }
+                    }
+                    totals
+                        .into_iter()
+                        .map(|total| total / runouts.len() as f64)
+                        .collect()
+                }
+            })
+    }
+
+    fn enumerate_runouts(&self) -> Vec<(Vec<Card>, Vec<f64>)> {
+        let stub = stub_cards(&self.hands, &self.board, &[]);
+        let mut runouts = Vec::new();
+
+        for_each_runout(&self.board, &stub, |runout| {
+            let mut shares = vec![0.0; self.hands.len()];
+            award_shares(&self.hands, runout, &mut shares);
+            runouts.push((runout[self.board.len()..].to_vec(), shares));
+        });
+
+        runouts
+    }
+}
+
+// Sampled hand strength and potentials against one random opponent: how often the hand is
+// ahead now (ties half), how often it goes from behind to ahead by the river (PPot) and how
+// often from ahead to behind (NPot), as in Billings et al.
+fn strength_and_potential<R: Rng + ?Sized>(
+    hole: &[Card; 2],
+    board: &[Card],
+    rng: &mut R,
+    iters: usize,
+) -> (f64, f64, f64) {
+    let stub = stub_cards(&[*hole], board, &[]);
+    let to_come = 5 - board.len();
+
+    // Indexed [now][at the river] with 0 ahead, 1 tied and 2 behind
+    let mut counts = [[0.0f64; 3]; 3];
+    let position = |ours: HandRanking, theirs: HandRanking| match ours.cmp(&theirs) {
+        std::cmp::Ordering::Greater => 0,
+        std::cmp::Ordering::Equal => 1,
+        std::cmp::Ordering::Less => 2,
+    };
+
+    for _ in 0..iters {
+        let drawn: Vec<Card> = stub.choose_multiple(rng, 2 + to_come).copied().collect();
+        let villain = [drawn[0], drawn[1]];
+        let river: Vec<Card> = board.iter().chain(&drawn[2..]).copied().collect();
+
+        let now = position(
+            Hand::new(hole, board).best(),
+            Hand::new(&villain, board).best(),
+        );
+        let later = position(
+            Hand::new(hole, &river).best(),
+            Hand::new(&villain, &river).best(),
+        );
+        counts[now][later] += 1.0;
+    }
+
+    let total = |now: usize| counts[now].iter().sum::<f64>();
+    let (ahead, tied, behind) = (total(0), total(1), total(2));
+    let ratio = |part: f64, whole: f64| if whole > 0.0 { part / whole } else { 0.0 };
+
+    let strength = ratio(ahead + tied / 2.0, iters as f64);
+    let positive = ratio(
+        counts[2][0] + counts[2][1] / 2.0 + counts[1][0] / 2.0,
+        behind + tied / 2.0,
+    );
+    let negative = ratio(
+        counts[0][2] + counts[0][1] / 2.0 + counts[1][2] / 2.0,
+        ahead + tied / 2.0,
+    );
+
+    (strength, positive, negative)
+}
+
+// Effective hand strength against one random hand: EHS = HS * (1 - NPot) + (1 - HS) * PPot,
+// the current strength adjusted by the chances of being outdrawn or drawing out. From iters
+// sampled opponent hands and runouts; on the river it is the plain hand strength.
+pub fn effective_hand_strength<R: Rng + ?Sized>(
+    hole: &[Card; 2],
+    board: &[Card],
+    rng: &mut R,
+    iters: usize,
+) -> f64 {
+    let (strength, positive, negative) = strength_and_potential(hole, board, rng, iters);
+
+    strength * (1.0 - negative) + (1.0 - strength) * positive
+}
+
+// Chance of the hand finishing in each category, over every runout of the board.
+// Meant for the flop onwards, where there are at most C(47, 2) runouts.
+pub fn category_distribution(hole: &[Card; 2], board: &[Card]) -> HashMap<HandCategory, f64> {
+    let stub = stub_cards(&[*hole], board, &[]);
+    let mut counts: HashMap<HandCategory, usize> = HashMap::new();
+
+    let runouts = for_each_runout(board, &stub, |runout| {
+        let category = Hand::new(hole, runout).best().category();
+        *counts.entry(category).or_insert(0) += 1;
+    });
+
+    counts
+        .into_iter()
+        .map(|(category, count)| (category, count as f64 / runouts as f64))
+        .collect()
+}
+
+// Number of the C(52, 7) = 133,784,560 seven-card deals whose best hand is each category
+const SEVEN_CARD_CATEGORY_COUNTS: [(HandCategory, u64); 10] = [
+    (HandCategory::HighCard, 23_294_460),
+    (HandCategory::OnePair, 58_627_800),
+    (HandCategory::TwoPair, 31_433_400),
+    (HandCategory::Set, 6_461_620),
+    (HandCategory::Straight, 6_180_020),
+    (HandCategory::Flush, 4_047_644),
+    (HandCategory::FullHouse, 3_473_184),
+    (HandCategory::Quads, 224_848),
+    (HandCategory::StraightFlush, 37_260),
+    (HandCategory::RoyalFlush, 4_324),
+];
+
+// Exact chance of each category in a random seven-card deal, e.g. a flush 3.03% of the time
+pub fn category_frequencies() -> HashMap<HandCategory, f64> {
+    let deals = choose(52, 7);
+
+    SEVEN_CARD_CATEGORY_COUNTS
+        .iter()
+        .map(|(category, count)| (*category, *count as f64 / deals))
+        .collect()
+}
+
+// Monte Carlo estimate of category_frequencies over iters random seven-card deals
+pub fn estimate_category_frequencies<R: Rng + ?Sized>(
+    iters: usize,
+    rng: &mut R,
+) -> HashMap<HandCategory, f64> {
+    let mut counts: HashMap<HandCategory, usize> = HashMap::new();
+
+    for _ in 0..iters {
+        let mut deck = Deck::new();
+        deck.shuffle_with(rng);
+
+        let category = Hand::new(&deck.draw(7), &[]).best().category();
+        *counts.entry(category).or_insert(0) += 1;
+    }
+
+    counts
+        .into_iter()
+        .map(|(category, count)| (category, count as f64 / iters as f64))
+        .collect()
+}
+
+// Same as equity_monte_carlo but split across threads. The iterations go in fixed chunks,
+// each with its own RNG seeded from the master seed, so results only depend on the seed and
+// not on how many threads the machine runs them on.
+#[cfg(feature = "parallel")]
+pub fn equity_monte_carlo_par(
+    hands: &[[Card; 2]],
+    board: &[Card],
+    iterations: usize,
+    seed: u64,
+) -> Vec<f64> {
+    const CHUNK: usize = 1024;
+
+    let chunks = iterations.div_ceil(CHUNK);
+    let threads = std::thread::available_parallelism()
+        .map_or(1, |threads| threads.get())
+        .min(chunks)
+        .max(1);
+
+    // Thread t runs chunks t, t + threads, ... and hands back each chunk's shares
+    let mut results: Vec<(usize, Vec<f64>)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|thread| {
+                scope.spawn(move || {
+                    (thread..chunks)
+                        .step_by(threads)
+                        .map(|chunk| {
+                            let count = CHUNK.min(iterations - chunk * CHUNK);
+                            let mut rng = StdRng::seed_from_u64(seed.wrapping_add(chunk as u64));
+                            (chunk, equity_shares(hands, board, &[], count, &mut rng))
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("equity worker panicked"))
+            .collect()
+    });
+
+    // Add up in chunk order so the floating point sums don't depend on the thread count
+    results.sort_by_key(|(chunk, _)| *chunk);
+    let totals = results
+        .into_iter()
+        .fold(vec![0.0; hands.len()], |mut totals, (_, shares)| {
+            for (total, share) in totals.iter_mut().zip(shares) {
+                *total += share;
+            }
+            totals
+        });
+
+    totals
+        .into_iter()
+        .map(|share| share / iterations as f64)
+        .collect()
+}
+
+// Ranking of exactly four cards, as in stud before the last cards are out. Kept apart from
+// HandRanking since no five cards make these, so they have no strength on its scale.
+// Straights and flushes need five cards, which makes quads the best; the derived Ord
+// compares the category first, then the fields left to right.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FourCardRanking {
+    HighCard(u16),       // Four high cards, as a HandRanking kicker mask
+    OnePair(Rank, u16),  // Pair, then two kickers
+    TwoPair(Rank, Rank), // Top pair, bottom pair
+    Set(Rank, Rank),     // Trips, then the kicker
+    Quads(Rank),
+}
+
+impl FourCardRanking {
+    pub fn category(&self) -> HandCategory {
+        match self {
+            FourCardRanking::HighCard(_) => HandCategory::HighCard,
+            FourCardRanking::OnePair(..) => HandCategory::OnePair,
+            FourCardRanking::TwoPair(..) => HandCategory::TwoPair,
+            FourCardRanking::Set(..) => HandCategory::Set,
+            FourCardRanking::Quads(_) => HandCategory::Quads,
+        }
+    }
+}
+
+pub fn eval4(cards: &[Card; 4]) -> FourCardRanking {
+    match Hand::new(cards, &[]).best() {
+        HandRanking::HighCard(mask) => FourCardRanking::HighCard(mask),
+        HandRanking::OnePair(pair, mask) => FourCardRanking::OnePair(pair, mask),
+        HandRanking::TwoPair(high, low, _) => FourCardRanking::TwoPair(high, low),
+        HandRanking::Set(set, mask) => FourCardRanking::Set(set, ranks_from_mask(mask)[0]),
+        HandRanking::Quads(quads, _) => FourCardRanking::Quads(quads),
+        ranking => unreachable!("four cards can't make {:?}", ranking),
+    }
+}
+
+// Whether a rank bitmask (ace also on bit 0) holds five ranks in a row, and the top one
+fn straight_high(bitmask: u16) -> Option<u8> {
+    (0..10u8)
+        .rev()
+        .find(|i| bitmask & 0x1F << i == 0x1F << i)
+        .map(|i| i + 4)
+}
+
+// Category of the best hand from rank and suit counts alone, skipping the kicker work
+pub fn quick_category(cards: &[Card]) -> HandCategory {
+    let mut rank_counts = [0u8; 14];
+    let mut suit_counts = [0u8; 4];
+    let mut suit_masks = [0u16; 4];
+    let mut bitmask = 0u16;
+
+    for card in cards {
+        let bits = 1 << card.score() | u16::from(card.rank == Rank::Ace);
+        rank_counts[card.score() as usize] += 1;
+        suit_counts[card.suit as usize] += 1;
+        suit_masks[card.suit as usize] |= bits;
+        bitmask |= bits;
+    }
+
+    if let Some(suit) = suit_counts.iter().position(|count| *count >= 5) {
+        match straight_high(suit_masks[suit]) {
+            Some(13) => return HandCategory::RoyalFlush,
+            Some(_) => return HandCategory::StraightFlush,
+            None => (),
+        }
+    }
+
+    let count = |n: u8| rank_counts.iter().filter(|c| **c == n).count();
+    let (quads, sets, pairs) = (count(4), count(3), count(2));
+
+    if quads > 0 {
+        HandCategory::Quads
+    } else if sets >= 2 || sets == 1 && pairs > 0 {
+        HandCategory::FullHouse
+    } else if suit_counts.iter().any(|count| *count >= 5) {
+        HandCategory::Flush
+    } else if straight_high(bitmask).is_some() {
+        HandCategory::Straight
+    } else if sets == 1 {
+        HandCategory::Set
+    } else if pairs >= 2 {
+        HandCategory::TwoPair
+    } else if pairs == 1 {
+        HandCategory::OnePair
+    } else {
+        HandCategory::HighCard
+    }
+}
+
+// Evaluate exactly five cards as they are, without choosing a best subset
+pub fn eval5(cards: &[Card; 5]) -> HandRanking {
+    let hand = Hand::new(cards, &[]);
+    let flush = hand.suit_map.len() == 1;
+    let straight = hand.best_straight(hand.bitmask);
+
+    match (flush, straight) {
+        (true, Some(Rank::Ace)) => return HandRanking::RoyalFlush,
+        (true, Some(rank)) => return HandRanking::StraightFlush(rank),
+        _ => (),
+    }
+
+    // Group ranks by count, bigger groups first and higher ranks first within a size
+    let mut groups: Vec<(u8, Rank)> = hand.rank_map.iter().map(|(r, c)| (*c, *r)).collect();
+    groups.sort_unstable_by(|a, b| b.cmp(a));
+
+    let counts: Vec<u8> = groups.iter().map(|(count, _)| *count).collect();
+    let ranks: Vec<Rank> = groups.iter().map(|(_, rank)| *rank).collect();
+    let kickers = |from: usize| {
+        ranks[from..]
+            .iter()
+            .fold(0u16, |mask, rank| mask | 1 << rank.score())
+    };
+
+    match counts.as_slice() {
+        [4, 1] => HandRanking::Quads(ranks[0], ranks[1]),
+        [3, 2] => HandRanking::FullHouse(ranks[0], ranks[1]),
+        _ if flush => HandRanking::Flush(kickers(0)),
+        _ if straight.is_some() => HandRanking::Straight(straight.unwrap()),
+        [3, 1, 1] => HandRanking::Set(ranks[0], kickers(1)),
+        [2, 2, 1] => HandRanking::TwoPair(ranks[0], ranks[1], ranks[2]),
+        [2, 1, 1, 1] => HandRanking::OnePair(ranks[0], kickers(1)),
+        _ => HandRanking::HighCard(kickers(0)),
+    }
+}
+
+static EVAL_TABLE: OnceLock<HashMap<u32, HandRanking>> = OnceLock::new();
+
+// Five cards reduce to their ranks and whether they share a suit. Ranks are packed
+// in descending order four bits apiece, with bit 20 flagging a flush.
+fn eval_key(cards: &[Card; 5]) -> u32 {
+    let mut scores = cards.map(|card| card.score() as u32);
+    scores.sort_unstable_by(|a, b| b.cmp(a));
+
+    let flush = cards.iter().all(|card| card.suit == cards[0].suit);
+    let key = scores.iter().fold(0, |key, score| key << 4 | score);
+
+    key | u32::from(flush) << 20
+}
+
+pub const DISTINCT_HAND_RANKS: usize = 7462;
+
+static RANKINGS: OnceLock<Vec<HandRanking>> = OnceLock::new();
+
+// One five-card hand for every distinct class: each rank multiset without a flush,
+// plus a suited copy whenever all five ranks differ
+fn five_card_classes() -> Vec<[Card; 5]> {
+    let mut classes = Vec::with_capacity(DISTINCT_HAND_RANKS);
+    let suits: Vec<Suit> = Suit::iter().collect();
+
+    for a in 1..=13u8 {
+        for b in 1..=a {
+            for c in 1..=b {
+                for d in 1..=c {
+                    for e in 1..=d {
+                        if a == e {
+                            continue; // Five of a kind
+                        }
+
+                        let ranks = [a, b, c, d, e].map(Rank::id);
+
+                        // Spread suits so no flush is formed
+                        let mut i = 0;
+                        classes.push(ranks.map(|rank| {
+                            i += 1;
+                            Card::new(rank, suits[i % 4])
+                        }));
+
+                        if a > b && b > c && c > d && d > e {
+                            classes.push(ranks.map(|rank| Card::new(rank, Suit::Spades)));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    classes
+}
+
+// Build the lookup table used by fast_eval, one entry per distinct five-card class
+pub fn init_tables() {
+    EVAL_TABLE.get_or_init(|| {
+        five_card_classes()
+            .iter()
+            .map(|cards| (eval_key(cards), eval5(cards)))
+            .collect()
+    });
+}
+
+// One ranking per distinct hand strength, weakest first
+pub fn enumerate_distinct_rankings() -> Vec<HandRanking> {
+    let mut rankings: Vec<HandRanking> = five_card_classes().iter().map(eval5).collect();
+    rankings.sort_unstable();
+    rankings.dedup();
+
+    rankings
+}
+
+impl HandRanking {
+    // Position among all distinct hand strengths, 0 for 7-5-4-3-2 up to 7461 for a royal flush.
+    // None for rankings no five cards make, e.g. those of fewer cards.
+    pub fn strength(&self) -> Option<u16> {
+        let rankings = RANKINGS.get_or_init(enumerate_distinct_rankings);
+
+        rankings
+            .binary_search(self)
+            .ok()
+            .map(|strength| strength as u16)
+    }
+}
+
+// The top n ranks of a rank bitmask as a kicker mask, bit 0 (the low Ace) dropped
+fn top_bits(mut mask: u16, n: u32) -> u16 {
+    mask &= !0x01;
+
+    while mask.count_ones() > n {
+        mask &= mask - 1;
+    }
+
+    mask
+}
+
+// Same result as Hand::best for five to seven cards, worked out on fixed size count
+// arrays so nothing is allocated
+fn eval_cards(cards: &[Card]) -> HandRanking {
+    let mut rank_counts = [0u8; 14];
+    let mut suit_counts = [0u8; 4];
+    let mut suit_masks = [0u16; 4];
+    let mut bitmask = 0u16;
+
+    for card in cards {
+        let bits = 1 << card.score() | u16::from(card.rank == Rank::Ace);
+        rank_counts[card.score() as usize] += 1;
+        suit_counts[card.suit as usize] += 1;
+        suit_masks[card.suit as usize] |= bits;
+        bitmask |= bits;
+    }
+
+    // Highest rank held exactly n times, skipping the one given
+    let highest = |n: u8, skip: Option<u8>| {
+        (1..=13u8)
+            .rev()
+            .find(|score| rank_counts[*score as usize] == n && Some(*score) != skip)
+    };
+    let without = |score: u8| bitmask & !(1 << score);
+    let top_rank = |mask: u16| match mask & !0x01 {
+        0 => None,
+        mask => Some(Rank::id(15 - mask.leading_zeros() as u8)),
+    };
+
+    if let Some(quads) = highest(4, None) {
+        let kicker = top_rank(without(quads)).unwrap_or(Rank::id(quads));
+        return HandRanking::Quads(Rank::id(quads), kicker);
+    }
+
+    let set = highest(3, None);
+    if let Some(set) = set {
+        let filler = highest(3, Some(set)).max(highest(2, None));
+        if let Some(filler) = filler {
+            return HandRanking::FullHouse(Rank::id(set), Rank::id(filler));
+        }
+    }
+
+    if let Some(suit) = suit_counts.iter().position(|count| *count >= 5) {
+        return match straight_high(suit_masks[suit]) {
+            Some(13) => HandRanking::RoyalFlush,
+            Some(high) => HandRanking::StraightFlush(Rank::id(high)),
+            None => HandRanking::Flush(top_bits(suit_masks[suit], 5)),
+        };
+    }
+
+    if let Some(high) = straight_high(bitmask) {
+        return HandRanking::Straight(Rank::id(high));
+    }
+
+    if let Some(set) = set {
+        return HandRanking::Set(Rank::id(set), top_bits(without(set), 2));
+    }
+
+    match highest(2, None) {
+        Some(high) => match highest(2, Some(high)) {
+            Some(low) => {
+                let kicker = top_rank(without(high) & !(1 << low)).unwrap_or(Rank::id(low));
+                HandRanking::TwoPair(Rank::id(high), Rank::id(low), kicker)
+            }
+            None => HandRanking::OnePair(Rank::id(high), top_bits(without(high), 3)),
+        },
+        None => HandRanking::HighCard(top_bits(bitmask, 5)),
+    }
+}
+
+// Strength (as HandRanking::strength, 7461 for a royal flush) of seven cards given by their
+// Card::to_index values. Panics on an index of 52 or more.
+pub fn eval7_indices(cards: &[u8; 7]) -> u32 {
+    let cards = cards.map(|index| Card::from_index(index).expect("card index below 52"));
+
+    eval_cards(&cards)
+        .strength()
+        .expect("seven cards make a five-card hand") as u32
+}
+
+// Evaluate five to seven cards packed into a mask with bit Card::to_index set per card.
+// Panics on any other card count or on a bit from 52 up.
+pub fn eval_mask(mask: u64) -> HandRanking {
+    let count = mask.count_ones();
+    assert!(
+        (5..=7).contains(&count),
+        "card mask holds {} cards, not 5 to 7",
+        count
+    );
+    assert!(mask >> 52 == 0, "card mask sets a bit above 51");
+
+    let cards: Vec<Card> = (0..52)
+        .filter(|index| mask & 1 << index != 0)
+        .filter_map(Card::from_index)
+        .collect();
+
+    eval_cards(&cards)
+}
+
+// First strength() of each category on the Two Plus Two scale, which folds the royal flush
+// into the straight flushes: high card, pair, two pair, trips, straight, flush, full house,
+// quads, straight flush
+const TPTT_CATEGORY_STARTS: [u16; 9] = [0, 1277, 4137, 4995, 5853, 5863, 7140, 7296, 7452];
+
+// Strength on the Two Plus Two evaluator scale: the category from 1 (high card) to 9
+// (straight flush, royal included) in bits 12 and up, and the 1-based position within the
+// category below. 7-5-4-3-2 is 4097 and a royal flush 36874; higher is better. None where
+// strength is.
+pub fn to_tptt_strength(ranking: &HandRanking) -> Option<u16> {
+    let strength = ranking.strength()?;
+    let category = TPTT_CATEGORY_STARTS
+        .iter()
+        .rposition(|start| *start <= strength)?;
+
+    Some((category as u16 + 1) << 12 | (strength - TPTT_CATEGORY_STARTS[category] + 1))
+}
+
+// Evaluate exactly five cards, using the lookup table if init_tables has been called
+pub fn fast_eval(cards: &[Card; 5]) -> HandRanking {
+    match EVAL_TABLE.get() {
+        Some(table) => table[&eval_key(cards)],
+        None => eval5(cards),
+    }
+}
+
+// Memoized evaluation keyed by canonicalize, so suit-isomorphic hands share one entry
+#[derive(Debug, Default)]
+pub struct EvalCache {
+    rankings: HashMap<u64, HandRanking>,
+    hits: usize,
+    misses: usize,
+}
+
+impl EvalCache {
+    pub fn new() -> Self {
+        EvalCache::default()
+    }
+
+    pub fn get_or_eval(&mut self, cards: &[Card]) -> HandRanking {
+        let key = canonicalize(cards);
+
+        if let Some(ranking) = self.rankings.get(&key) {
+            self.hits += 1;
+            return *ranking;
+        }
+
+        self.misses += 1;
+        let ranking = Hand::new(cards, &[]).best();
+        self.rankings.insert(key, ranking);
+
+        ranking
+    }
+
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+// Number of ways to choose k items out of n
+fn choose(n: usize, k: usize) -> f64 {
+    if k > n {
+        return 0.0;
+    }
+
+    (0..k).fold(1.0, |acc, i| acc * (n - i) as f64 / (i + 1) as f64)
+}
+
+// Chance that at least one of the outs lands in the next cards_to_come cards
+pub fn draw_probability(outs: usize, cards_to_come: usize, unseen: usize) -> f64 {
+    let blanks = unseen.saturating_sub(outs);
+
+    1.0 - choose(blanks, cards_to_come) / choose(unseen, cards_to_come)
+}
+
+// Distance between two hands in distinct strength classes, positive when a is stronger.
+// None unless both are five-card rankings.
+pub fn strength_margin(a: &HandRanking, b: &HandRanking) -> Option<i64> {
+    Some(a.strength()? as i64 - b.strength()? as i64)
+}
+
+// Compare two hands of five to seven cards each, by the best five cards each one holds
+pub fn compare_partial(a: &[Card], b: &[Card]) -> std::cmp::Ordering {
+    Hand::new(a, &[]).best().cmp(&Hand::new(b, &[]).best())
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum RankScheme {
+    HighStandard, // Regular hold'em rules
+    ShortDeck,    // Sixes and up, A-6-7-8-9 is a straight and a flush beats a full house
+    AceToFiveLow, // Lowest hand wins, straights and flushes do not count and the Ace is low
+}
+
+impl RankScheme {
+    // Order two rankings produced by evaluate_with under this scheme, Greater when a wins
+    pub fn compare(&self, a: &HandRanking, b: &HandRanking) -> std::cmp::Ordering {
+        match self {
+            RankScheme::HighStandard => a.cmp(b),
+            RankScheme::ShortDeck => {
+                // Flushes are rarer than full houses with only 36 cards, so they swap places
+                let order = |ranking: &HandRanking| match ranking.category() {
+                    HandCategory::Flush => HandCategory::FullHouse,
+                    HandCategory::FullHouse => HandCategory::Flush,
+                    category => category,
+                };
+
+                order(a).cmp(&order(b)).then_with(|| a.cmp(b))
+            }
+            RankScheme::AceToFiveLow => low_key(b).cmp(&low_key(a)),
+        }
+    }
+}
+
+// Evaluate the cards under the given scheme; compare the results with RankScheme::compare,
+// as the derived Ord of HandRanking only holds for HighStandard
+pub fn evaluate_with(cards: &[Card], scheme: RankScheme) -> HandRanking {
+    match scheme {
+        RankScheme::HighStandard => Hand::new(cards, &[]).best(),
+        RankScheme::ShortDeck => short_deck_best(cards),
+        RankScheme::AceToFiveLow => five_card_subsets(cards)
+            .iter()
+            .map(|five| low_ranking(five))
+            .min_by_key(low_key)
+            .unwrap_or_else(|| low_ranking(cards)),
+    }
+}
+
+// The Ace plays below the Six instead of the Two, anything else is a regular high hand
+fn short_deck_best(cards: &[Card]) -> HandRanking {
+    let best = Hand::new(cards, &[]).best_no_wheel();
+    let low_straight = [Rank::Ace, Rank::Six, Rank::Seven, Rank::Eight, Rank::Nine];
+    let holds = |suit: Option<Suit>| {
+        low_straight.iter().all(|rank| {
+            cards
+                .iter()
+                .any(|card| card.rank == *rank && suit.is_none_or(|suit| card.suit == suit))
+        })
+    };
+
+    if best < HandRanking::StraightFlush(Rank::Nine) && Suit::iter().any(|suit| holds(Some(suit))) {
+        HandRanking::StraightFlush(Rank::Nine)
+    } else if best < HandRanking::Straight(Rank::Nine) && holds(None) {
+        HandRanking::Straight(Rank::Nine)
+    } else {
+        best
+    }
+}
+
+// Value of a rank when the Ace plays low: the Ace is 0, the rest keep their score
+fn low_value(rank: Rank) -> u8 {
+    match rank {
+        Rank::Ace => 0,
+        rank => rank.score(),
+    }
+}
+
+// Ace-to-five ranking of up to five cards. Only pairings count and kicker masks set bit
+// low_value, so the Ace sits in bit 0; two pair lists the pair higher in low terms first
+fn low_ranking(cards: &[Card]) -> HandRanking {
+    let mut groups: Vec<(usize, Rank)> = group_by_rank(cards)
+        .into_iter()
+        .map(|(rank, cards)| (cards.len(), rank))
+        .collect();
+    groups.sort_by_key(|(count, rank)| std::cmp::Reverse((*count, low_value(*rank))));
+
+    let kickers = |skip: usize| {
+        groups[skip..]
+            .iter()
+            .fold(0u16, |mask, (_, rank)| mask | 1 << low_value(*rank))
+    };
+
+    match groups.as_slice() {
+        [] => HandRanking::HighCard(0),
+        [(4, quads), rest @ ..] => HandRanking::Quads(*quads, rest.first().map_or(*quads, |g| g.1)),
+        [(3, set), (2, pair), ..] => HandRanking::FullHouse(*set, *pair),
+        [(3, set), ..] => HandRanking::Set(*set, kickers(1)),
+        [(2, high), (2, low), rest @ ..] => {
+            HandRanking::TwoPair(*high, *low, rest.first().map_or(*low, |g| g.1))
+        }
+        [(2, pair), ..] => HandRanking::OnePair(*pair, kickers(1)),
+        _ => HandRanking::HighCard(kickers(0)),
+    }
+}
+
+// Sort key for low rankings, smaller is the better low
+fn low_key(ranking: &HandRanking) -> (HandCategory, [u8; 2], u16) {
+    let category = ranking.category();
+
+    match *ranking {
+        HandRanking::OnePair(pair, mask) => (category, [low_value(pair), 0], mask),
+        HandRanking::TwoPair(high, low, kicker) => (
+            category,
+            [low_value(high), low_value(low)],
+            1 << low_value(kicker),
+        ),
+        HandRanking::Set(set, mask) => (category, [low_value(set), 0], mask),
+        HandRanking::FullHouse(set, pair) => (category, [low_value(set), low_value(pair)], 0),
+        HandRanking::Quads(quads, kicker) => (category, [low_value(quads), low_value(kicker)], 0),
+        HandRanking::HighCard(mask) => (category, [0, 0], mask),
+        _ => (category, [0, 0], 0),
+    }
+}
+
+// A lowball hand, ordered so the better low compares greater. It wraps the HandRanking of
+// the five cards: ace-to-five lows set the Ace in bit 0 of their kicker masks as
+// evaluate_with does, while deuce-to-seven lows are plain high rankings with the Ace high.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct LowRanking {
+    ranking: HandRanking,
+    ace_low: bool,
+}
+
+impl LowRanking {
+    pub fn ranking(&self) -> HandRanking {
+        self.ranking
+    }
+
+    // Five distinct ranks from the Eight down, the usual hi-lo qualifier
+    pub fn is_eight_or_better(&self) -> bool {
+        match self.ranking {
+            HandRanking::HighCard(mask) if self.ace_low => mask < 1 << 8,
+            _ => false,
+        }
+    }
+}
+
+impl Ord for LowRanking {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.ace_low.cmp(&other.ace_low).then_with(|| {
+            if self.ace_low {
+                low_key(&other.ranking).cmp(&low_key(&self.ranking))
+            } else {
+                other.ranking.cmp(&self.ranking)
+            }
+        })
+    }
+}
+
+impl PartialOrd for LowRanking {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Best eight-or-better low any two live hole cards make with three of the board, as
+// Omaha hi-lo plays it. None when no low is possible, e.g. fewer than three low board cards.
+pub fn nut_low(board: &[Card], dead: &[Card]) -> Option<LowRanking> {
+    let mut boards = Vec::new();
+    let mut indices = [0, 1, 2];
+    while board.len() >= 3 {
+        boards.push(indices.map(|i| board[i]));
+
+        if !next_combination(&mut indices, board.len()) {
+            break;
+        }
+    }
+
+    hole_combos(board, dead)
+        .iter()
+        .flat_map(|hole| {
+            boards.iter().map(move |three| LowRanking {
+                ranking: low_ranking(&[hole[0], hole[1], three[0], three[1], three[2]]),
+                ace_low: true,
+            })
+        })
+        .filter(LowRanking::is_eight_or_better)
+        .max()
+}
+
+// Deuce-to-seven low of the best five cards: the Ace only plays high and straights and
+// flushes count against the hand, so 7-5-4-3-2 offsuit is the nuts
+pub fn best_27_low(cards: &[Card]) -> LowRanking {
+    let ranking = five_card_subsets(cards)
+        .iter()
+        .map(|five| Hand::new(five, &[]).best_no_wheel())
+        .min()
+        .unwrap_or_else(|| Hand::new(cards, &[]).best_no_wheel());
+
+    LowRanking {
+        ranking,
+        ace_low: false,
+    }
+}
+
+// Lazily evaluate a stream of seven-card hands, one ranking per hand as it is pulled
+pub fn evaluate_all<I: IntoIterator<Item = [Card; 7]>>(
+    hands: I,
+) -> impl Iterator<Item = HandRanking> {
+    hands.into_iter().map(|cards| Hand::new(&cards, &[]).best())
+}
+
+// Ranks set in a rank bitmask (see Hand::rank_bitmask), from highest to lowest. Bit 0 is
+// only the Ace's low copy and is ignored, the Two is bit 1
+pub fn ranks_from_mask(mask: u16) -> Vec<Rank> {
+    (1..=13)
+        .rev()
+        .filter(|score| mask & 1 << score != 0)
+        .map(Rank::id)
+        .collect()
+}
+
+// Describe the component that decides between two rankings, e.g. "higher pair (Ace over King)"
+pub fn explain_comparison(a: &HandRanking, b: &HandRanking) -> String {
+    let (winner, loser) = match a.cmp(b) {
+        std::cmp::Ordering::Equal => return String::from("hands are tied"),
+        std::cmp::Ordering::Greater => (a, b),
+        std::cmp::Ordering::Less => (b, a),
+    };
+
+    if winner.category() != loser.category() {
+        return format!(
+            "higher category ({} over {})",
+            winner.category(),
+            loser.category()
+        );
+    }
+
+    let ordinals = ["", "second ", "third ", "fourth ", "fifth "];
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+
+    let (highs, lows) = (winner.components(), loser.components());
+
+    // Kicker lists may differ in length below five cards, the missing component losing
+    for (i, (name, high)) in highs.into_iter().enumerate() {
+        let index = seen.entry(name).or_insert(0);
+        let ordinal = if *index > 0 { ordinals[*index] } else { "" };
+        match lows.get(i) {
+            Some((_, low)) if *low == high => *index += 1,
+            Some((_, low)) => {
+                return format!("higher {}{} ({:?} over {:?})", ordinal, name, high, low)
+            }
+            None => return format!("higher {}{} ({:?} over none)", ordinal, name, high),
+        }
+    }
+
+    unreachable!("rankings differ but no component does")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_ranks_and_suits() {
+        assert_eq!(ALL_RANKS.to_vec(), Rank::iter().collect::<Vec<_>>());
+        assert_eq!(ALL_SUITS.to_vec(), Suit::iter().collect::<Vec<_>>());
+        assert!(ALL_RANKS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(ALL_SUITS.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn ordinals() {
+        for (ordinal, rank) in Rank::iter().enumerate() {
+            assert_eq!(rank.ordinal() as usize, ordinal);
+            assert_eq!(Rank::from_ordinal(rank.ordinal()), Some(rank));
+        }
+        for (ordinal, suit) in Suit::iter().enumerate() {
+            assert_eq!(suit.ordinal() as usize, ordinal);
+            assert_eq!(Suit::from_ordinal(suit.ordinal()), Some(suit));
+        }
+
+        assert_eq!(Rank::Two.ordinal(), Rank::Two.score() - 1);
+        assert_eq!(Rank::from_ordinal(13), None);
+        assert_eq!(Suit::from_ordinal(4), None);
+    }
+
+    #[test]
+    fn suit_orders() {
+        assert_eq!(Suit::Spades.bridge_order(), 3);
+        assert!(Suit::iter().all(|suit| suit.bridge_order() <= Suit::Spades.bridge_order()));
+        assert_eq!(SuitOrder::Alternating.position(Suit::Diamonds), 0);
+        for order in [SuitOrder::Bridge, SuitOrder::Alternating] {
+            assert!(Suit::iter().all(|suit| order.suit_at(order.position(suit)) == suit));
+        }
+
+        let mut cards = [
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Diamonds),
+            Card::new(Rank::Ace, Suit::Clubs),
+        ];
+        sort_cards(&mut cards, SuitOrder::Alternating);
+        assert_eq!(cards[1], Card::new(Rank::Nine, Suit::Clubs));
+        sort_cards(&mut cards, SuitOrder::Bridge);
+        assert_eq!(cards[1], Card::new(Rank::Nine, Suit::Diamonds));
+
+        // Isomorphic hands share one canonical form
+        let hearts = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::King, Suit::Clubs),
+        ];
+        let spades = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let form = canonical_form(&hearts, SuitOrder::Bridge);
+        assert_eq!(form, canonical_form(&spades, SuitOrder::Bridge));
+        assert_eq!(canonicalize(&form), canonicalize(&hearts));
+    }
+
+    #[test]
+    fn rank_steps() {
+        assert_eq!(Rank::King.next(), Some(Rank::Ace));
+        assert_eq!(Rank::Ace.next(), None);
+        assert_eq!(Rank::Three.prev(), Some(Rank::Two));
+        assert_eq!(Rank::Two.prev(), None);
+
+        assert_eq!(Rank::Ace.next_with(true), Some(Rank::Two));
+        assert_eq!(Rank::Two.prev_with(true), Some(Rank::Ace));
+        assert_eq!(Rank::Ten.next_with(true), Some(Rank::Jack));
+    }
+
+    #[test]
+    fn hand_ranking() {
+        assert!(
+            Some(HandRanking::Flush(0b01_0011_0001_0010))
+                > Some(HandRanking::Flush(0b01_0001_1101_0010))
+        );
+        assert!(HandRanking::RoyalFlush > HandRanking::StraightFlush(Rank::King));
+        assert!(HandRanking::Straight(Rank::Queen) > HandRanking::Straight(Rank::Jack));
+        assert!(
+            HandRanking::Quads(Rank::Queen, Rank::Jack)
+                < HandRanking::Quads(Rank::Queen, Rank::King)
+        );
+        assert!(
+            HandRanking::FullHouse(Rank::King, Rank::Two)
+                > HandRanking::FullHouse(Rank::Two, Rank::Seven)
+        );
+        assert!(
+            HandRanking::Set(Rank::Two, 0b10_0001_1010_0010)
+                < HandRanking::Set(Rank::Three, 0b01_0001_1010_0010)
+        );
+        assert!(
+            HandRanking::TwoPair(Rank::Ace, Rank::Eight, Rank::Six)
+                > HandRanking::TwoPair(Rank::Ace, Rank::Eight, Rank::Five)
+        );
+        assert!(
+            HandRanking::OnePair(Rank::Ace, 0b10_0001_1010_1100)
+                < HandRanking::OnePair(Rank::Ace, 0b10_0010_1010_1100)
+        );
+        assert!(
+            HandRanking::HighCard(0b10_0001_1010_1100) < HandRanking::HighCard(0b10_0010_1010_1100)
+        );
+    }
+
+    #[test]
+    fn best_never_panics() {
+        let mut rng = StdRng::seed_from_u64(415);
+        for i in 0..30_000 {
+            let mut deck = Deck::new();
+            deck.shuffle_with(&mut rng);
+            let cards = deck.draw(2 + i % 6);
+
+            let ranking = Hand::new(&cards, &[]).best();
+            assert!(cards.len() >= 5 || ranking.category() <= HandCategory::Quads);
+            Hand::new(&cards, &[]).best_no_wheel();
+        }
+
+        // Hands built around repeated ranks, where kickers run out
+        let of_rank = |rank, n: usize| -> Vec<Card> {
+            Suit::iter()
+                .take(n)
+                .map(|suit| Card::new(rank, suit))
+                .collect()
+        };
+        let edge_cases = [
+            Vec::new(),
+            of_rank(Rank::Ace, 2),
+            of_rank(Rank::Ace, 3),
+            of_rank(Rank::Ace, 4),
+            [of_rank(Rank::Two, 4), of_rank(Rank::Ace, 3)].concat(),
+            [of_rank(Rank::Two, 4), of_rank(Rank::Three, 2)].concat(),
+            [of_rank(Rank::Ace, 3), of_rank(Rank::King, 3)].concat(),
+            [
+                of_rank(Rank::Two, 2),
+                of_rank(Rank::Three, 2),
+                of_rank(Rank::Ace, 2),
+            ]
+            .concat(),
+            [of_rank(Rank::Five, 2), of_rank(Rank::Four, 2)].concat(),
+        ];
+        for cards in &edge_cases {
+            Hand::new(cards, &[]).best();
+            Hand::new(cards, &[]).best_no_wheel();
+        }
+
+        assert_eq!(
+            Hand::new(&edge_cases[4], &[]).best(),
+            HandRanking::Quads(Rank::Two, Rank::Ace)
+        );
+        assert_eq!(
+            Hand::new(&edge_cases[3], &[]).best(),
+            HandRanking::Quads(Rank::Ace, Rank::Ace)
+        );
+        assert_eq!(
+            Hand::new(&edge_cases[0], &[]).best(),
+            HandRanking::HighCard(0)
+        );
+    }
+
+    #[test]
+    fn hand_ranking_categories() {
+        let ascending = [
+            HandRanking::HighCard(0b11_1100_1000_0000),
+            HandRanking::OnePair(Rank::Two, 0b00_0000_0001_1100),
+            HandRanking::TwoPair(Rank::Three, Rank::Two, Rank::Four),
+            HandRanking::Set(Rank::Two, 0b00_0000_0001_1000),
+            HandRanking::Straight(Rank::Five),
+            HandRanking::Flush(0b00_0000_0101_1110),
+            HandRanking::FullHouse(Rank::Two, Rank::Three),
+            HandRanking::Quads(Rank::Two, Rank::Three),
+            HandRanking::StraightFlush(Rank::Five),
+            HandRanking::RoyalFlush,
+        ];
+
+        // The weakest hand of each category beats the best of the one below
+        for (i, low) in ascending.iter().enumerate() {
+            for high in &ascending[i + 1..] {
+                assert!(high > low, "{:?} should beat {:?}", high, low);
+            }
+        }
+        assert!(
+            HandRanking::OnePair(Rank::Two, 0b00_0000_0001_1100) > HandRanking::HighCard(0x3E00)
+        );
+        assert!(HandRanking::Straight(Rank::Five) > HandRanking::Set(Rank::Ace, 0x1800));
+        assert!(HandRanking::Flush(0x5E) > HandRanking::Straight(Rank::Ace));
+    }
+
+    #[test]
+    fn rank_bitmask_bits() {
+        let hand = Hand::new(
+            &[
+                Card::new(Rank::Ace, Suit::Spades),
+                Card::new(Rank::Two, Suit::Hearts),
+            ],
+            &[Card::new(Rank::Two, Suit::Clubs)],
+        );
+
+        assert_eq!(hand.rank_bitmask(), 0b10_0000_0000_0011);
+        assert_eq!(
+            ranks_from_mask(hand.rank_bitmask()),
+            vec![Rank::Ace, Rank::Two]
+        );
+    }
+
+    #[test]
+    fn hole_and_board_split() {
+        let hole = [
+            Card::new(Rank::Jack, Suit::Clubs),
+            Card::new(Rank::Ten, Suit::Clubs),
+        ];
+        let board = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Two, Suit::Diamonds),
+        ];
+
+        let hand = Hand::new(&hole, &board);
+        assert_eq!(hand.hole(), &hole);
+        assert_eq!(hand.board(), &board);
+    }
+
+    #[test]
+    fn cards_of_a_suit() {
+        let hand = Hand::new(
+            &[
+                Card::new(Rank::Four, Suit::Hearts),
+                Card::new(Rank::Jack, Suit::Hearts),
+            ],
+            &[
+                Card::new(Rank::Ace, Suit::Clubs),
+                Card::new(Rank::Nine, Suit::Hearts),
+                Card::new(Rank::King, Suit::Hearts),
+            ],
+        );
+
+        assert_eq!(
+            hand.suit_cards(Suit::Hearts),
+            vec![
+                Card::new(Rank::King, Suit::Hearts),
+                Card::new(Rank::Jack, Suit::Hearts),
+                Card::new(Rank::Nine, Suit::Hearts),
+                Card::new(Rank::Four, Suit::Hearts),
+            ]
+        );
+        assert!(hand.suit_cards(Suit::Spades).is_empty());
+    }
+
+    #[test]
+    fn hand_ranking_kickers() {
+        // Same trips, kickers A-3 against K-Q: the top kicker decides
+        assert!(
+            HandRanking::Set(Rank::Nine, 0b10_0000_0000_0100)
+                > HandRanking::Set(Rank::Nine, 0b01_1000_0000_0000)
+        );
+        // Same trips and top kicker, second kicker decides
+        assert!(
+            HandRanking::Set(Rank::Nine, 0b10_0000_0001_0000)
+                > HandRanking::Set(Rank::Nine, 0b10_0000_0000_1000)
+        );
+        assert!(
+            HandRanking::TwoPair(Rank::King, Rank::Two, Rank::Ace)
+                > HandRanking::TwoPair(Rank::King, Rank::Two, Rank::Queen)
+        );
+        assert!(
+            HandRanking::TwoPair(Rank::King, Rank::Three, Rank::Two)
+                > HandRanking::TwoPair(Rank::King, Rank::Two, Rank::Ace)
+        );
+        assert!(
+            HandRanking::OnePair(Rank::Five, 0b10_0000_0000_1110)
+                > HandRanking::OnePair(Rank::Five, 0b01_1100_0000_0000)
+        );
+        assert!(
+            HandRanking::Quads(Rank::Five, Rank::Ace) > HandRanking::Quads(Rank::Five, Rank::King)
+        );
+        assert_eq!(
+            HandRanking::Set(Rank::Nine, 0b10_0000_0000_0100),
+            HandRanking::Set(Rank::Nine, 0b10_0000_0000_0100)
+        );
+    }
+
+    #[test]
+    fn ace_kickers_leave_bit_zero_clear() {
+        let ranking = |cards: [(Rank, Suit); 5]| Hand::new(&five(cards), &[]).best();
+
+        let set_ace_kicker = ranking([
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Nine, Suit::Spades),
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Ace, Suit::Diamonds),
+            (Rank::Three, Suit::Spades),
+        ]);
+        assert_eq!(
+            set_ace_kicker,
+            HandRanking::Set(Rank::Nine, 0b10_0000_0000_0100)
+        );
+
+        let set_of_aces = ranking([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Ace, Suit::Spades),
+            (Rank::Ace, Suit::Clubs),
+            (Rank::King, Suit::Diamonds),
+            (Rank::Three, Suit::Spades),
+        ]);
+        assert_eq!(
+            set_of_aces,
+            HandRanking::Set(Rank::Ace, 0b01_0000_0000_0100)
+        );
+
+        let pair_ace_kicker = ranking([
+            (Rank::Five, Suit::Hearts),
+            (Rank::Five, Suit::Spades),
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Four, Suit::Diamonds),
+            (Rank::Two, Suit::Spades),
+        ]);
+        assert_eq!(
+            pair_ace_kicker,
+            HandRanking::OnePair(Rank::Five, 0b10_0000_0000_1010)
+        );
+
+        let pair_of_aces = ranking([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Ace, Suit::Spades),
+            (Rank::Four, Suit::Clubs),
+            (Rank::Three, Suit::Diamonds),
+            (Rank::Two, Suit::Spades),
+        ]);
+        assert_eq!(pair_of_aces, HandRanking::OnePair(Rank::Ace, 0b1110));
+        assert!(pair_ace_kicker < pair_of_aces);
+    }
+
+    #[test]
+    fn parse_category() {
+        assert_eq!("full house".parse(), Ok(HandCategory::FullHouse));
+        assert_eq!("  Two   PAIR ".parse(), Ok(HandCategory::TwoPair));
+        assert!("super flush".parse::<HandCategory>().is_err());
+
+        for category in HandCategory::iter() {
+            assert_eq!(category.to_string().parse(), Ok(category));
+        }
+    }
+
+    #[test]
+    fn beats_and_ties() {
+        let flush = HandRanking::Flush(0b00_0110_1010_0010);
+        let straight = HandRanking::Straight(Rank::Ace);
+
+        assert!(flush.beats(&straight));
+        assert!(!straight.beats(&flush));
+        assert!(!flush.ties(&straight));
+
+        assert!(straight.ties(&HandRanking::Straight(Rank::Ace)));
+        assert!(!straight.beats(&HandRanking::Straight(Rank::Ace)));
+    }
+
+    #[test]
+    fn category_thresholds() {
+        let flush = HandRanking::Flush(0b00_0110_1010_0010);
+        assert!(flush.is_at_least(HandCategory::TwoPair));
+        assert!(flush.is_at_least(HandCategory::Flush));
+        assert!(!flush.is_at_least(HandCategory::FullHouse));
+
+        let high_card = HandRanking::HighCard(0b11_1100_1000_0000);
+        assert!(!high_card.is_at_least(HandCategory::TwoPair));
+        assert!(high_card.is_at_least(HandCategory::HighCard));
+    }
+
+    #[test]
+    fn partial_comparison() {
+        let six = [
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Diamonds),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+        let seven = [
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Diamonds),
+        ];
+
+        assert_eq!(compare_partial(&six, &seven), std::cmp::Ordering::Greater);
+        assert_eq!(compare_partial(&seven, &six), std::cmp::Ordering::Less);
+        assert_eq!(compare_partial(&six[..5], &six), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn rank_schemes() {
+        let flush = five([
+            (Rank::King, Suit::Hearts),
+            (Rank::Ten, Suit::Hearts),
+            (Rank::Eight, Suit::Hearts),
+            (Rank::Seven, Suit::Hearts),
+            (Rank::Six, Suit::Hearts),
+        ]);
+        let full_house = five([
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Nine, Suit::Spades),
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Six, Suit::Diamonds),
+            (Rank::Six, Suit::Spades),
+        ]);
+
+        let high = RankScheme::HighStandard;
+        let (a, b) = (
+            evaluate_with(&flush, high),
+            evaluate_with(&full_house, high),
+        );
+        assert_eq!(high.compare(&a, &b), std::cmp::Ordering::Less);
+
+        let short = RankScheme::ShortDeck;
+        let (a, b) = (
+            evaluate_with(&flush, short),
+            evaluate_with(&full_house, short),
+        );
+        assert_eq!(short.compare(&a, &b), std::cmp::Ordering::Greater);
+
+        let low_straight = five([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Six, Suit::Spades),
+            (Rank::Seven, Suit::Clubs),
+            (Rank::Eight, Suit::Diamonds),
+            (Rank::Nine, Suit::Spades),
+        ]);
+        assert_eq!(
+            evaluate_with(&low_straight, short),
+            HandRanking::Straight(Rank::Nine)
+        );
+        assert_eq!(
+            evaluate_with(&low_straight, high),
+            HandRanking::HighCard(0b10_0001_1110_0000)
+        );
+    }
+
+    #[test]
+    fn ace_to_five_low() {
+        let low = RankScheme::AceToFiveLow;
+        let wheel = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::King, Suit::Clubs),
+        ];
+        let six_low = five([
+            (Rank::Six, Suit::Hearts),
+            (Rank::Four, Suit::Spades),
+            (Rank::Three, Suit::Clubs),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::Ace, Suit::Spades),
+        ]);
+        let paired = five([
+            (Rank::Two, Suit::Hearts),
+            (Rank::Two, Suit::Spades),
+            (Rank::Three, Suit::Clubs),
+            (Rank::Four, Suit::Diamonds),
+            (Rank::Five, Suit::Spades),
+        ]);
+
+        // The steel wheel is the best low, its straight flush does not count
+        let best = evaluate_with(&wheel, low);
+        assert_eq!(best, HandRanking::HighCard(0b1_1111));
+
+        let six = evaluate_with(&six_low, low);
+        let pair = evaluate_with(&paired, low);
+        assert_eq!(low.compare(&best, &six), std::cmp::Ordering::Greater);
+        assert_eq!(low.compare(&six, &pair), std::cmp::Ordering::Greater);
+    }
+
+    #[test]
+    fn nut_lows() {
+        let board = [
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Hearts),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Queen, Suit::Spades),
+        ];
+
+        // A-3 in the hand makes 8-5-3-2-A
+        let nut = nut_low(&board, &[]).unwrap();
+        assert_eq!(nut.ranking(), HandRanking::HighCard(0b1001_0111));
+        assert!(nut.is_eight_or_better());
+
+        // With every Three gone the best left is A-4 for 8-5-4-2-A
+        let threes: Vec<Card> = Suit::iter()
+            .map(|suit| Card::new(Rank::Three, suit))
+            .collect();
+        let second = nut_low(&board, &threes).unwrap();
+        assert_eq!(second.ranking(), HandRanking::HighCard(0b1001_1011));
+        assert!(nut > second);
+
+        let two_low = [board[0], board[1], board[3], board[4]];
+        assert_eq!(nut_low(&two_low, &[]), None);
+    }
+
+    #[test]
+    fn deuce_to_seven_low() {
+        let low = |cards: &str| best_27_low(&parse_cards(cards).unwrap());
+        let nuts = low("7h 5d 4c 3s 2h");
+        assert_eq!(nuts.ranking(), HandRanking::HighCard(0b101_1110));
+
+        // The Ace plays high, so 6-4-3-2-A is just an Ace-high hand
+        assert!(nuts > low("6h 4d 3c 2s Ah"));
+        for worse in [
+            "7h 6d 4c 3s 2h",
+            "8h 5d 4c 3s 2h",
+            "6h 5d 4c 3s 2h",
+            "7h 5h 4h 3h 2h",
+            "7h 7d 4c 3s 2h",
+        ] {
+            assert!(nuts > low(worse), "{} should lose to 7-5-4-3-2", worse);
+        }
+
+        // The best five of seven, leaving out the pair
+        assert_eq!(low("Kc 7h 5d 4c Kd 3s 2h"), nuts);
+        assert!(!nuts.is_eight_or_better());
+    }
+
+    #[test]
+    fn evaluate_stream() {
+        let seven = |cards: [(Rank, Suit); 7]| cards.map(|(rank, suit)| Card::new(rank, suit));
+        let hands = vec![
+            seven([
+                (Rank::Ace, Suit::Spades),
+                (Rank::King, Suit::Spades),
+                (Rank::Queen, Suit::Spades),
+                (Rank::Jack, Suit::Spades),
+                (Rank::Ten, Suit::Spades),
+                (Rank::Two, Suit::Hearts),
+                (Rank::Three, Suit::Clubs),
+            ]),
+            seven([
+                (Rank::Nine, Suit::Hearts),
+                (Rank::Nine, Suit::Clubs),
+                (Rank::Four, Suit::Spades),
+                (Rank::Four, Suit::Diamonds),
+                (Rank::King, Suit::Hearts),
+                (Rank::Two, Suit::Clubs),
+                (Rank::Seven, Suit::Diamonds),
+            ]),
+            seven([
+                (Rank::Ace, Suit::Hearts),
+                (Rank::Jack, Suit::Clubs),
+                (Rank::Nine, Suit::Spades),
+                (Rank::Seven, Suit::Diamonds),
+                (Rank::Five, Suit::Hearts),
+                (Rank::Three, Suit::Clubs),
+                (Rank::Two, Suit::Diamonds),
+            ]),
+        ];
+
+        let categories: Vec<HandCategory> = evaluate_all(hands)
+            .map(|ranking| ranking.category())
+            .collect();
+        assert_eq!(
+            categories,
+            vec![
+                HandCategory::RoyalFlush,
+                HandCategory::TwoPair,
+                HandCategory::HighCard
+            ]
+        );
+    }
+
+    #[test]
+    fn displayed_ranking() {
+        let full_house = HandRanking::FullHouse(Rank::King, Rank::Two);
+        assert_eq!(full_house.to_string(), "Full House (King, Two)");
+
+        let pair = Hand::new(&parse_cards("Ah Ad Kc 9s 4h").unwrap(), &[]).best();
+        assert_eq!(pair.to_string(), "One Pair (Ace, King, Nine, Four)");
+        assert_eq!(HandRanking::RoyalFlush.to_string(), "Royal Flush");
+    }
+
+    #[test]
+    fn explain_comparison_flush_kicker() {
+        // A K J 8 4 against A K J 8 3
+        let a = HandRanking::Flush(0b11_0100_1000_1000);
+        let b = HandRanking::Flush(0b11_0100_1000_0100);
+        assert_eq!(
+            explain_comparison(&a, &b),
+            "higher fifth flush card (Four over Three)"
+        );
+        assert_eq!(explain_comparison(&b, &a), explain_comparison(&a, &b));
+    }
+
+    #[test]
+    fn explain_comparison_pair() {
+        let a = HandRanking::OnePair(Rank::Queen, 0b10_0000_0100_0100);
+        let b = HandRanking::OnePair(Rank::Jack, 0b11_0000_0000_0100);
+        assert_eq!(explain_comparison(&a, &b), "higher pair (Queen over Jack)");
+        assert_eq!(
+            explain_comparison(&HandRanking::Flush(0x3E), &b),
+            "higher category (Flush over One Pair)"
+        );
+        assert_eq!(explain_comparison(&a, &a), "hands are tied");
+    }
+
+    #[test]
+    fn explain_comparison_missing_kicker() {
+        // A K Q J 9 against the four cards A K Q J
+        let five = HandRanking::HighCard(0b11_1101_0000_0000);
+        let four = HandRanking::HighCard(0b11_1100_0000_0000);
+        assert_eq!(
+            explain_comparison(&four, &five),
+            "higher fifth high card (Nine over none)"
+        );
+    }
+
+    #[test]
+    fn error_messages() {
+        let errors = [
+            (PokerError::DeckEmpty, "the deck is empty"),
+            (
+                PokerError::DuplicateCard(Card::new(Rank::Ten, Suit::Hearts)),
+                "duplicate card Th",
+            ),
+            (PokerError::TooManyCards(53), "too many cards (53)"),
+            (PokerError::TooFewCards(4), "too few cards (4)"),
+            (
+                PokerError::ParseError(String::from("bad rank 'X'")),
+                "parse error: bad rank 'X'",
+            ),
+            (
+                PokerError::CorruptDeck(String::from("52 cards expected")),
+                "corrupt deck: 52 cards expected",
+            ),
+            (
+                PokerError::InvalidAction(String::from("nothing to call")),
+                "invalid action: nothing to call",
+            ),
+        ];
+
+        for (error, message) in errors {
+            assert_eq!(error.to_string(), message);
+
+            let boxed: Box<dyn std::error::Error> = Box::new(error);
+            assert_eq!(boxed.to_string(), message);
+        }
+    }
+
+    #[test]
+    fn card_notation() {
+        let ten = Card::new(Rank::Ten, Suit::Hearts);
+        assert_eq!("10h".parse(), Ok(ten));
+        assert_eq!("Th".parse(), Ok(ten));
+        assert_eq!("tH".parse(), Ok(ten));
+        assert_eq!(ten.to_string().parse(), Ok(ten));
+
+        assert!("1h".parse::<Card>().is_err());
+        assert!("0h".parse::<Card>().is_err());
+        assert!("10".parse::<Card>().is_err());
+        assert!("AsKs".parse::<Card>().is_err());
+
+        assert_eq!(
+            parse_cards("10hAs, 9c  Td"),
+            Ok(vec![
+                ten,
+                Card::new(Rank::Ace, Suit::Spades),
+                Card::new(Rank::Nine, Suit::Clubs),
+                Card::new(Rank::Ten, Suit::Diamonds),
+            ])
+        );
+        assert_eq!(parse_cards(""), Ok(Vec::new()));
+        assert!(parse_cards("As 1h").is_err());
+    }
+
+    #[test]
+    fn hand_history_line() {
+        let holes = [
+            [
+                Card::new(Rank::Ace, Suit::Spades),
+                Card::new(Rank::King, Suit::Spades),
+            ],
+            [
+                Card::new(Rank::Queen, Suit::Hearts),
+                Card::new(Rank::Queen, Suit::Diamonds),
+            ],
+        ];
+        let board = five([
+            (Rank::Ten, Suit::Spades),
+            (Rank::Seven, Suit::Clubs),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Four, Suit::Spades),
+        ]);
+
+        let line = format_hand_history(&holes, &board, &[1]);
+        assert_eq!(line, "P0:AsKs P1:QhQd | board:Ts7c2d 9h 4s | winner:P1");
+
+        let sections: Vec<&str> = line.split(" | ").collect();
+        assert_eq!(sections.len(), 3);
+        assert!(format_hand_history(&holes, &board, &[0, 1]).ends_with("winner:P0,P1"));
+    }
+
+    #[test]
+    fn seed_bytes_shuffle() {
+        let mut deck1 = Deck::new();
+        let mut deck2 = Deck::new();
+        deck1.shuffle_from_seed_bytes(b"server seed 42");
+        deck2.shuffle_from_seed_bytes(b"server seed 42");
+        assert_eq!(deck1.cards, deck2.cards);
+        assert_ne!(deck1.cards, Deck::new().cards);
+
+        let mut deck3 = Deck::new();
+        deck3.shuffle_from_seed_bytes(b"server seed 43");
+        assert_ne!(deck1.cards, deck3.cards);
+        assert_eq!(deck3.assert_integrity(), Ok(()));
+    }
+
+    #[test]
+    fn seed_bytes_shuffle_known_answer() {
+        // Worked out independently of rand_chacha from the steps documented on
+        // shuffle_from_seed_bytes, starting from Deck::new() order. Listed by position,
+        // index 0 first, so the last card is the first one drawn.
+        let expected = parse_cards(
+            "6h 2s Jh Ks Jc As 8c Kd 5d Tc Ad 5s 4c Js 2h Qs 6d 7s 3s Qc 7c Ac 9s 5c Kc Ah \
+             2d 7d 9d 3d 8h Qd 9h Ts 8d 8s 9c 4h Jd 4d Qh 7h 2c Td 3h 6c 5h 3c 4s Th 6s Kh",
+        )
+        .unwrap();
+
+        let mut deck = Deck::new();
+        deck.shuffle_from_seed_bytes(b"server seed 42");
+        assert_eq!(deck.cards, expected);
+        assert_eq!(deck.draw(1), vec![Card::new(Rank::King, Suit::Hearts)]);
+    }
+
+    #[test]
+    fn partial_shuffle() {
+        let mut deck = Deck::new();
+        let fresh = deck.cards.clone();
+
+        deck.shuffle_range(10..40, &mut StdRng::seed_from_u64(5));
+        assert_eq!(deck.cards[..10], fresh[..10]);
+        assert_eq!(deck.cards[40..], fresh[40..]);
+        assert_ne!(deck.cards[10..40], fresh[10..40]);
+
+        deck.swap(0, 51);
+        assert_eq!(deck.draw(1), vec![fresh[0]]);
+        assert_eq!(deck.cards[0], fresh[51]);
+    }
+
+    #[test]
+    fn deal_streets() {
+        let mut deck = Deck::new_shuffled();
+        deck.draw(4);
+
+        assert_eq!(deck.deal_to_street(Street::Preflop).unwrap().len(), 3);
+        assert_eq!(deck.remaining(), 44);
+
+        let turn = deck.deal_to_street(Street::Flop).unwrap();
+        assert_eq!(turn.len(), 1);
+        assert_eq!(deck.remaining(), 42);
+        assert_eq!(deck.dealt.last(), turn.last());
+
+        assert_eq!(deck.deal_to_street(Street::Turn).unwrap().len(), 1);
+        assert!(deck.deal_to_street(Street::River).is_err());
+
+        deck.draw(40);
+        assert_eq!(
+            deck.deal_to_street(Street::Turn),
+            Err(PokerError::DeckEmpty)
+        );
+    }
+
+    #[test]
+    fn clone_deck() {
+        let mut deck = Deck::new_shuffled();
+        deck.draw(7);
+
+        let mut copy = deck.clone();
+        let drawn = copy.draw(10);
+        assert_eq!(copy.remaining(), 35);
+        assert_eq!(deck.remaining(), 45);
+        assert_eq!(deck.draw(10), drawn);
+    }
+
+    #[test]
+    fn seeded_deck_sequences() {
+        let mut a = SeededDeck::new(420);
+        let mut b = SeededDeck::new(420);
+
+        for _ in 0..5 {
+            assert_eq!(a.draw(9), b.draw(9));
+            a.shuffle();
+            b.shuffle();
+            assert_eq!(a.remaining(), 52);
+        }
+
+        // A clone carries the RNG along, so the branches keep dealing alike
+        let mut branch = a.clone();
+        branch.shuffle();
+        a.shuffle();
+        assert_eq!(a.draw(52), branch.draw(52));
+
+        assert_ne!(SeededDeck::new(421).draw(9), b.draw(9));
+    }
+
+    #[test]
+    fn bottom_dealing() {
+        let mut top = Deck::new_shuffled_seeded(11);
+        let mut bottom = top.clone();
+        assert_ne!(top.draw(1)[0], bottom.deal_bottom().unwrap());
+        assert_eq!(bottom.remaining(), 51);
+
+        let mut deck = Deck::new();
+        deck.shuffle_from_seed_bytes(b"hand 11");
+        deck.draw(3);
+        assert_eq!(deck.audit_seeded_deal(b"hand 11"), None);
+        deck.deal_bottom();
+        assert_eq!(deck.audit_seeded_deal(b"hand 11"), Some(3));
+        assert_eq!(deck.audit_seeded_deal(b"hand 12"), Some(0));
+
+        let mut empty = Deck::new();
+        empty.draw(52);
+        assert_eq!(empty.deal_bottom(), None);
+    }
+
+    #[test]
+    fn stacked_deck() {
+        let mut deck = Deck::new_shuffled();
+        let order = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Hearts),
+        ];
+
+        deck.stack(&order).unwrap();
+        assert_eq!(deck.remaining(), 52);
+        assert_eq!(deck.draw(3), order);
+        assert_eq!(
+            deck.stack(&order[..1]),
+            Err(PokerError::DuplicateCard(order[0]))
+        );
+    }
+
+    #[test]
+    fn deck_combinations() {
+        let mut deck = Deck::new();
+        deck.draw(48);
+
+        let pairs: Vec<Vec<Card>> = deck.combinations(2).collect();
+        assert_eq!(pairs.len(), 6);
+        assert!(pairs
+            .iter()
+            .all(|pair| pair.len() == 2 && pair[0] != pair[1]));
+
+        assert_eq!(deck.combinations(4).count(), 1);
+        assert_eq!(deck.combinations(5).count(), 0);
+        assert_eq!(Deck::new().combinations(2).count(), 1326);
+    }
+
+    #[test]
+    fn snapshot_restore() {
+        let mut deck = Deck::new_shuffled();
+        deck.draw(4);
+
+        let snap = deck.snapshot();
+        let branch = deck.draw(5);
+        assert_eq!(deck.remaining(), 43);
+
+        deck.restore(&snap);
+        assert_eq!(deck.remaining(), 48);
+        assert_eq!(deck.snapshot(), snap);
+        assert_eq!(deck.draw(5), branch);
+    }
+
+    #[test]
+    fn deck_suit_counts() {
+        let mut deck = Deck::new();
+        assert!(deck.suit_counts().values().all(|count| *count == 13));
+
+        for rank in [Rank::Ace, Rank::Seven, Rank::Two] {
+            deck.remove(Card::new(rank, Suit::Hearts)).unwrap();
+        }
+        let counts = deck.suit_counts();
+        assert_eq!(counts[&Suit::Hearts], 10);
+        assert_eq!(counts[&Suit::Spades], 13);
+    }
+
+    #[test]
+    fn factory_order() {
+        let mut deck = Deck::new_deck_order();
+        assert_eq!(deck.assert_integrity(), Ok(()));
+
+        assert_eq!(
+            deck.draw(3),
+            vec![
+                Card::new(Rank::Ace, Suit::Spades),
+                Card::new(Rank::Two, Suit::Spades),
+                Card::new(Rank::Three, Suit::Spades),
+            ]
+        );
+        deck.draw(10);
+        assert_eq!(deck.draw(1), vec![Card::new(Rank::Ace, Suit::Diamonds)]);
+        deck.draw(12);
+        assert_eq!(
+            deck.draw(2),
+            vec![
+                Card::new(Rank::King, Suit::Clubs),
+                Card::new(Rank::Queen, Suit::Clubs)
+            ]
+        );
+        assert_eq!(deck.cards[0], Card::new(Rank::Ace, Suit::Hearts));
+    }
+
+    #[test]
+    fn recorded_deal() {
+        let mut deck = Deck::new_shuffled();
+        let (hole, _) = deck.deal_recorded(4);
+        let (flop, log) = deck.deal_recorded(3);
+        assert_eq!(log.cards().len(), 7);
+        assert_eq!(&log.cards()[..4], &hole[..]);
+
+        let mut replay = log.replay().unwrap();
+        assert_eq!(replay.draw(4), hole);
+        assert_eq!(replay.draw(3), flop);
+        assert_eq!(replay.remaining(), 45);
+        // No truncation past 255 cards
+        let mut shoe = Deck::multi(5);
+        let (cards, log) = shoe.deal_recorded(256);
+        assert_eq!(cards.len(), 256);
+        assert_eq!(log.cards(), &cards[..]);
+    }
+
+    #[test]
+    fn seven_stud_visibility() {
+        let mut deck = Deck::new_shuffled_seeded(3);
+        let mut reference = deck.clone();
+
+        let pattern = [false, false, true, true, true, true, false];
+        let dealt = deck.deal_with_visibility(&pattern);
+        let face_up: Vec<bool> = dealt.iter().map(|dealt| dealt.face_up).collect();
+        assert_eq!(face_up, pattern);
+
+        let cards: Vec<Card> = dealt.iter().map(|dealt| dealt.card).collect();
+        assert_eq!(cards, reference.draw(7));
+        assert_eq!(deck.remaining(), 45);
+
+        // Patterns longer than 255 entries deal every card
+        let mut shoe = Deck::multi(5);
+        assert_eq!(shoe.deal_with_visibility(&[true; 257]).len(), 257);
+        assert_eq!(shoe.remaining(), 3);
+    }
+
+    #[test]
+    fn joker_deck_state() {
+        let mut deck = Deck::with_jokers(2);
+        deck.shuffle_from_seed_bytes(b"jokers");
+        assert_eq!(deck.remaining(), 54);
+        let jokers_left = |deck: &Deck| {
+            let undealt = deck.undealt();
+            undealt
+                .iter()
+                .filter(|card| **card == DeckCard::Joker)
+                .count()
+        };
+        assert_eq!(jokers_left(&deck), 2);
+
+        // Snapshots and bytes keep the jokers, dealt or not, in their places
+        let snap = deck.snapshot();
+        let bytes = deck.to_bytes();
+        deck.draw_with_jokers(54);
+        deck.restore(&snap);
+        assert_eq!(deck.remaining(), 54);
+        assert_eq!(deck.to_bytes(), bytes);
+
+        let dealt = deck.draw_with_jokers(30);
+        let mut copy = Deck::from_bytes(&deck.to_bytes()).unwrap();
+        assert_eq!(copy.to_bytes(), deck.to_bytes());
+        assert_eq!(copy.remaining(), deck.remaining());
+        copy.reset();
+        assert_eq!(copy.remaining(), 54);
+        assert_eq!(
+            deck.draw_with_jokers(24),
+            Deck::from_bytes(&bytes).unwrap().draw_with_jokers(54)[30..]
+        );
+        assert_eq!(dealt.len(), 30);
+
+        // Taking cards out from under the jokers leaves them where they are
+        let mut deck = Deck::with_jokers(1);
+        let bottom = deck.cards[0];
+        deck.remove(deck.cards[10]).unwrap();
+        assert_eq!(deck.deal_bottom(), Some(bottom));
+        deck.stack(&[bottom]).unwrap_err();
+        assert_eq!(deck.remaining(), 51);
+        assert_eq!(deck.draw_with_jokers(1), vec![DeckCard::Joker]);
+        assert_eq!(jokers_left(&deck), 0);
+
+        // Stacked cards go above the jokers
+        let mut deck = Deck::with_jokers(1);
+        let order = [deck.cards[0], deck.cards[1]];
+        deck.stack(&order).unwrap();
+        assert_eq!(
+            deck.draw_with_jokers(3),
+            vec![
+                DeckCard::Standard(order[0]),
+                DeckCard::Standard(order[1]),
+                DeckCard::Joker
+            ]
+        );
+    }
+
+    #[test]
+    fn multi_deck() {
+        let mut deck = Deck::multi(2);
+        assert_eq!(deck.remaining(), 104);
+
+        deck.draw(30);
+        assert_eq!(deck.remaining(), 74);
+
+        deck.reset();
+        assert_eq!(deck.remaining(), 104);
+        assert_eq!(Deck::new().remaining(), 52);
+    }
+
+    #[test]
+    fn deck_with_jokers() {
+        let mut deck = Deck::with_jokers(2);
+        assert_eq!(deck.remaining(), 54);
+
+        deck.shuffle_with(&mut StdRng::seed_from_u64(433));
+        let dealt = deck.draw_with_jokers(54);
+        assert_eq!(deck.remaining(), 0);
+        assert_eq!(
+            dealt
+                .iter()
+                .filter(|card| **card == DeckCard::Joker)
+                .count(),
+            2
+        );
+        assert_eq!(deck.dealt.len(), 52);
+        assert_eq!(deck.assert_integrity(), Ok(()));
+
+        deck.reset();
+        assert_eq!(deck.remaining(), 54);
+        assert_eq!(deck.draw_with_jokers(2), vec![DeckCard::Joker; 2]);
+
+        // Plain draw deals the jokers it passes too, and keeps going until it has n cards
+        deck.reset();
+        assert_eq!(deck.draw(3).len(), 3);
+        assert_eq!(deck.remaining(), 49);
+        deck.reset();
+        deck.shuffle_with(&mut StdRng::seed_from_u64(434));
+        assert_eq!(deck.draw(52).len(), 52);
+        assert_eq!(deck.remaining(), 0);
+
+        // A joker on top is the burn card, and the board still gets three cards
+        deck.reset();
+        assert_eq!(deck.deal_to_street(Street::Preflop).unwrap().len(), 3);
+        assert_eq!(deck.remaining(), 49);
+
+        // Four cards left but one is a joker under the rest, so no flop after the burn
+        let mut short = Deck::new();
+        let mut undealt: Vec<DeckCard> =
+            short.draw(3).into_iter().map(DeckCard::Standard).collect();
+        undealt.insert(0, DeckCard::Joker);
+        short.set_undealt(undealt);
+        assert_eq!(short.remaining(), 4);
+        assert_eq!(
+            short.deal_to_street(Street::Preflop),
+            Err(PokerError::DeckEmpty)
+        );
+
+        // A joker fills the royal flush
+        let hand: Vec<DeckCard> = parse_cards("As Ks Qs Js 2d")
+            .unwrap()
+            .into_iter()
+            .map(DeckCard::Standard)
+            .chain([DeckCard::Joker])
+            .collect();
+        assert_eq!(best_with_jokers(&hand), HandRanking::RoyalFlush);
+    }
+
+    #[test]
+    fn card_groups() {
+        let cards = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Clubs),
+        ];
+
+        let suits = group_by_suit(&cards);
+        assert_eq!(suits.len(), 3);
+        assert_eq!(suits[&Suit::Hearts].len(), 4);
+        assert_eq!(suits[&Suit::Clubs].len(), 2);
+        assert_eq!(suits[&Suit::Spades], vec![cards[3]]);
+        assert!(!suits.contains_key(&Suit::Diamonds));
+
+        let ranks = group_by_rank(&cards);
+        assert_eq!(ranks.len(), 5);
+        assert_eq!(ranks[&Rank::King], vec![cards[1], cards[2]]);
+        assert_eq!(ranks[&Rank::Six].len(), 2);
+    }
+
+    #[test]
+    fn flush_draw_outs() {
+        // Already a straight, so only hearts improve it
+        let hole = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Hearts),
+        ];
+        let board = [
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Hearts),
+        ];
+
+        let cards = outs(&hole, &board);
+        assert_eq!(cards.len(), 9);
+        assert!(cards.iter().all(|card| card.suit == Suit::Hearts));
+        assert_eq!(count_outs(&hole, &board), 9);
+    }
+
+    #[test]
+    fn street_categories() {
+        let hole = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+        let board = five([
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Seven, Suit::Spades),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::King, Suit::Hearts),
+            (Rank::King, Suit::Spades),
+        ]);
+
+        assert_eq!(
+            category_at_each_street(&hole, &board),
+            [
+                HandCategory::OnePair,
+                HandCategory::TwoPair,
+                HandCategory::FullHouse
+            ]
+        );
+    }
+
+    #[test]
+    fn playing_the_board() {
+        let board = five([
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Eight, Suit::Clubs),
+            (Rank::Seven, Suit::Clubs),
+            (Rank::Six, Suit::Clubs),
+            (Rank::Five, Suit::Clubs),
+        ]);
+        assert_eq!(board_plays(&board), HandRanking::StraightFlush(Rank::Nine));
+        assert!(board_counterfeits(&board));
+
+        let board = five([
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Seven, Suit::Clubs),
+            (Rank::Six, Suit::Spades),
+            (Rank::Two, Suit::Clubs),
+        ]);
+        assert_eq!(board_plays(&board).category(), HandCategory::OnePair);
+        assert!(!board_counterfeits(&board));
+    }
+
+    #[test]
+    fn counterfeited_two_pair() {
+        let hole = [
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Spades),
+        ];
+        let flop = [
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+
+        // Kings paired on the turn and river leave kings and sevens, the Six only a kicker
+        let kings = [
+            flop[0],
+            flop[1],
+            flop[2],
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::King, Suit::Spades),
+        ];
+        assert!(counterfeited(&hole, &flop, &kings));
+
+        let blanks = [
+            flop[0],
+            flop[1],
+            flop[2],
+            Card::new(Rank::Three, Suit::Clubs),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+        assert!(!counterfeited(&hole, &flop, &blanks));
+
+        // Filling up is an improvement, not a counterfeit
+        let full = [
+            flop[0],
+            flop[1],
+            flop[2],
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Spades),
+        ];
+        assert!(!counterfeited(&hole, &flop, &full));
+    }
+
+    #[test]
+    fn possible_combos() {
+        let board = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Diamonds),
+        ];
+        let dead = [Card::new(Rank::King, Suit::Spades)];
+
+        let ace_king = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        assert!(combo_is_possible(&ace_king, &board, &dead));
+
+        let blocked = [board[0], Card::new(Rank::King, Suit::Hearts)];
+        assert!(!combo_is_possible(&blocked, &board, &dead));
+
+        let folded = [Card::new(Rank::Ace, Suit::Spades), dead[0]];
+        assert!(!combo_is_possible(&folded, &board, &dead));
+    }
+
+    #[test]
+    fn blocked_combos() {
+        let board = [
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Hearts),
+        ];
+        let ace_of_hearts = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+        let ace_of_spades = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+
+        // AhKh, AhJh and AhTh, the AhQh combo already being ruled out by the board
+        assert_eq!(blocker_count(&ace_of_hearts, "ATs+", &board), Ok(3));
+        let nut_flushes = ["AKs", "AJs", "ATs"]
+            .iter()
+            .map(|class| blocker_count(&ace_of_hearts, class, &board))
+            .sum::<Result<usize, _>>();
+        assert_eq!(nut_flushes, Ok(3));
+
+        // The Ace of spades blocks all four spade combos, none of them a flush
+        assert_eq!(blocker_count(&ace_of_spades, "ATs+", &board), Ok(4));
+        assert_eq!(blocker_count(&ace_of_hearts, "KQ", &board), Ok(0));
+        assert_eq!(blocker_count(&ace_of_hearts, "A2o", &board), Ok(5));
+        assert!(matches!(
+            blocker_count(&ace_of_hearts, "zz", &board),
+            Err(PokerError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn range_parsing() {
+        assert_eq!(parse_range("AK").unwrap().len(), 16);
+        assert_eq!(parse_range("AKs").unwrap().len(), 4);
+        assert_eq!(parse_range("ako").unwrap().len(), 12);
+        assert_eq!(parse_range("QQ").unwrap().len(), 6);
+        assert_eq!(parse_range("QQ+").unwrap().len(), 18);
+        assert_eq!(parse_range("ATs+").unwrap().len(), 16);
+        assert_eq!(parse_range("KA, 22, 76s").unwrap().len(), 26);
+
+        assert!(parse_range("AKx").is_err());
+        assert!(parse_range("QQs").is_err());
+        assert!(parse_range("A1").is_err());
+    }
+
+    #[test]
+    fn remaining_class_combos() {
+        let board = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Diamonds),
+        ];
+
+        assert_eq!(remaining_combos("AK", &board, &[]), Ok(12));
+        assert_eq!(remaining_combos("AKs", &board, &[]), Ok(3));
+        assert_eq!(remaining_combos("77", &board, &[]), Ok(3));
+        assert_eq!(
+            remaining_combos("AK", &board, &[Card::new(Rank::King, Suit::Clubs)]),
+            Ok(9)
+        );
+        assert!(matches!(
+            remaining_combos("AX", &board, &[]),
+            Err(PokerError::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn nut_and_worst_hands() {
+        let board = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+
+        // A K 9 6 with a Four, holding the Three and Four
+        assert_eq!(
+            worst_hand(&board, &[]),
+            HandRanking::HighCard(0b11_0001_0010_1000)
+        );
+        assert_eq!(
+            nut_hand(&board, &[]),
+            HandRanking::Set(Rank::Ace, 0b01_0001_0000_0000)
+        );
+    }
+
+    #[test]
+    fn scare_card_on_the_turn() {
+        let flop = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Ten, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+
+        // A pairing card only brings quads, a third heart brings a straight flush
+        let turn = scariest_turn_card(&flop, &[]);
+        assert_eq!(turn.suit, Suit::Hearts);
+        assert_eq!(
+            nut_hand(&[flop[0], flop[1], flop[2], turn], &[]),
+            HandRanking::StraightFlush(Rank::King)
+        );
+    }
+
+    #[test]
+    fn combos_beating_the_hero() {
+        let board = [
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Spades),
+        ];
+        let nut_flush = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let hero = Hand::new(&nut_flush, &board).best();
+        assert_eq!(hands_beating_count(&hero, &board, &nut_flush), 0);
+
+        // Top set loses to every flush that is left
+        let top_set = [
+            Card::new(Rank::Queen, Suit::Spades),
+            Card::new(Rank::Queen, Suit::Clubs),
+        ];
+        let hero = Hand::new(&top_set, &board).best();
+        assert_eq!(
+            hands_beating_count(&hero, &board, &top_set),
+            choose(10, 2) as usize
+        );
+    }
+
+    #[test]
+    fn strongest_rankings() {
+        let board = [
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Spades),
+        ];
+
+        assert_eq!(
+            top_rankings(&board, &[], 2),
+            vec![
+                HandRanking::Flush(0b11_1001_0000_1000),
+                HandRanking::Flush(0b10_1101_0000_1000),
+            ]
+        );
+
+        // Once the Ace of hearts is seen the top is a King high flush
+        let ace = [Card::new(Rank::Ace, Suit::Hearts)];
+        assert_eq!(
+            top_rankings(&board, &ace, 1),
+            vec![HandRanking::Flush(0b01_1101_0000_1000)]
+        );
+    }
+
+    #[test]
+    fn strength_against_random_hand() {
+        let kings = [
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let dry = [
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+
+        // Nothing is ahead of top set on a dry flop
+        assert_eq!(hand_strength(&kings, &dry), 1.0);
+
+        // Middle set only loses to the three combos of kings
+        let sevens = [
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Seven, Suit::Hearts),
+        ];
+        let strength = hand_strength(&sevens, &dry);
+        assert!((strength - (1.0 - 3.0 / 1081.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn wild_cards_to_come() {
+        let four_flush = [
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Hearts),
+            Card::new(Rank::King, Suit::Clubs),
+        ];
+
+        assert_eq!(
+            best_with_wilds(&four_flush, 1),
+            HandRanking::Flush(0b10_0101_0001_0010)
+        );
+        assert_eq!(
+            best_with_wilds(&four_flush, 0),
+            HandRanking::HighCard(0b01_0101_0001_0010)
+        );
+        // Two cards can't fill up or make a straight flush, but take the flush to A-K
+        assert_eq!(
+            best_with_wilds(&four_flush, 2),
+            HandRanking::Flush(0b11_0101_0001_0000)
+        );
+    }
+
+    #[test]
+    fn cards_needed_for_a_category() {
+        let four_flush = parse_cards("2h 5h 9h Jh Kc").unwrap();
+        assert_eq!(
+            cards_to_guarantee(&four_flush, HandCategory::Flush, &[]),
+            Some(1)
+        );
+        assert_eq!(
+            cards_to_guarantee(&four_flush, HandCategory::OnePair, &[]),
+            Some(1)
+        );
+        assert_eq!(
+            cards_to_guarantee(&four_flush, HandCategory::HighCard, &[]),
+            Some(0)
+        );
+        // Any heart makes at least a set, the flush being better
+        assert_eq!(
+            cards_to_guarantee(&four_flush, HandCategory::Set, &[]),
+            Some(1)
+        );
+
+        // Quads would take three more Kings, past seven cards
+        assert_eq!(
+            cards_to_guarantee(&four_flush, HandCategory::Quads, &[]),
+            None
+        );
+
+        // With every heart gone the flush can't come
+        let hearts: Vec<Card> = ALL_RANKS
+            .iter()
+            .map(|rank| Card::new(*rank, Suit::Hearts))
+            .collect();
+        let dead: Vec<Card> = hearts
+            .into_iter()
+            .filter(|card| !four_flush.contains(card))
+            .collect();
+        let six = parse_cards("2h 5h 9h Jh Kc 3d").unwrap();
+        assert_eq!(cards_to_guarantee(&six, HandCategory::Flush, &dead), None);
+    }
+
+    #[test]
+    fn top_combos_on_flush_board() {
+        let board = [
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Queen, Suit::Hearts),
+            Card::new(Rank::King, Suit::Clubs),
+        ];
+
+        let top = top_k_combos(&board, &[], 3);
+        let with_ace = |rank| {
+            [
+                Card::new(rank, Suit::Hearts),
+                Card::new(Rank::Ace, Suit::Hearts),
+            ]
+        };
+        assert_eq!(
+            top,
+            vec![
+                (
+                    with_ace(Rank::King),
+                    HandRanking::Flush(0b11_1001_0010_0000)
+                ),
+                (
+                    with_ace(Rank::Jack),
+                    HandRanking::Flush(0b10_1101_0010_0000)
+                ),
+                (with_ace(Rank::Ten), HandRanking::Flush(0b10_1011_0010_0000)),
+            ]
+        );
+        assert!(top_k_combos(&board, &[], 0).is_empty());
+    }
+
+    #[test]
+    fn gaps_between_ranks() {
+        let cards = [
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::Six, Suit::Diamonds),
+            Card::new(Rank::Six, Suit::Clubs),
+        ];
+
+        assert_eq!(rank_gaps(&cards), vec![1, 2, 1]);
+        assert!(rank_gaps(&cards[..1]).is_empty());
+    }
+
+    #[test]
+    fn straight_draws() {
+        let open_ended = [
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let info = draws(&open_ended);
+        assert!(info.open_ended_straight_draw);
+        assert!(!info.gutshot);
+        assert!(!info.flush_draw);
+
+        let gutshot = [
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Eight, Suit::Diamonds),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let info = draws(&gutshot);
+        assert!(!info.open_ended_straight_draw);
+        assert!(info.gutshot);
+    }
+
+    #[test]
+    fn flush_draws() {
+        let cards = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Spades),
+            Card::new(Rank::Nine, Suit::Clubs),
+        ];
+        assert_eq!(
+            draws(&cards),
+            DrawInfo {
+                backdoor_flush: true,
+                ..DrawInfo::default()
+            }
+        );
+
+        let mut cards = cards.to_vec();
+        cards.push(Card::new(Rank::Three, Suit::Hearts));
+        assert!(draws(&cards).flush_draw);
+        assert!(!draws(&cards).backdoor_flush);
+    }
+
+    #[test]
+    fn named_draws() {
+        let nut_flush_draw = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Clubs),
+        ];
+        assert_eq!(draw_description(&nut_flush_draw), vec!["nut flush draw"]);
+
+        let combo = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+        assert_eq!(
+            draw_description(&combo),
+            vec!["flush draw", "open-ended straight draw", "combo draw"]
+        );
+
+        let gutshot = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Spades),
+            Card::new(Rank::Six, Suit::Clubs),
+            Card::new(Rank::Five, Suit::Diamonds),
+        ];
+        assert_eq!(draw_description(&gutshot), vec!["gutshot"]);
+    }
+
+    #[test]
+    fn monotone_board() {
+        let board = [
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+        let texture = board_texture(&board);
+        assert!(texture.is_monotone);
+        assert!(!texture.is_two_tone);
+        assert!(!texture.is_rainbow);
+        assert!(!texture.is_paired);
+        assert!(!texture.straight_possible);
+    }
+
+    #[test]
+    fn paired_board() {
+        let board = [
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+        let texture = board_texture(&board);
+        assert!(texture.is_paired);
+        assert!(texture.is_two_tone);
+        assert!(!texture.straight_possible);
+    }
+
+    #[test]
+    fn completable_straights_on_board() {
+        let board = [
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Clubs),
+        ];
+        assert_eq!(
+            possible_straights(&board),
+            vec![Rank::Nine, Rank::Eight, Rank::Seven]
+        );
+        assert!(possible_straight_flushes(&board).is_empty());
+
+        let suited = [board[0], board[1], Card::new(Rank::Seven, Suit::Hearts)];
+        assert_eq!(
+            possible_straight_flushes(&suited),
+            vec![Rank::Nine, Rank::Eight, Rank::Seven]
+        );
+
+        // The Ace also plays low
+        let wheel = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Clubs),
+        ];
+        assert_eq!(possible_straights(&wheel), vec![Rank::Five]);
+    }
+
+    #[test]
+    fn connected_board() {
+        let board = [
+            Card::new(Rank::Eight, Suit::Clubs),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Spades),
+        ];
+        let texture = board_texture(&board);
+        assert!(texture.straight_possible);
+        assert!(texture.is_rainbow);
+
+        let wheel = [
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Spades),
+        ];
+        assert!(board_texture(&wheel).straight_possible);
+    }
+
+    #[test]
+    fn wet_and_dry_boards() {
+        let wet = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Hearts),
+        ];
+        let dry = [
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Spades),
+        ];
+
+        assert_eq!(board_wetness(&wet), 6);
+        assert_eq!(board_wetness(&dry), 0);
+        assert!(board_wetness(&wet) > board_wetness(&dry));
+
+        let soaked = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Six, Suit::Clubs),
+        ];
+        assert_eq!(board_wetness(&soaked), 9);
+    }
+
+    #[test]
+    fn canonical_keys() {
+        let ace_king_spades = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Spades),
+        ];
+        let ace_king_hearts = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let ace_king_offsuit = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+
+        assert_eq!(
+            canonicalize(&ace_king_spades),
+            canonicalize(&ace_king_hearts)
+        );
+        assert_ne!(
+            canonicalize(&ace_king_spades),
+            canonicalize(&ace_king_offsuit)
+        );
+        assert_eq!(
+            canonicalize(&ace_king_offsuit),
+            canonicalize(&[ace_king_hearts[0], ace_king_spades[1]])
+        );
+    }
+
+    #[test]
+    fn random_holdem_hand() {
+        let (hole, board, ranking) = random_holdem(&mut StdRng::seed_from_u64(21));
+        assert_eq!(Hand::new(&hole, &board).best(), ranking);
+
+        let mut cards = hole.to_vec();
+        cards.extend(board);
+        cards.sort();
+        cards.dedup();
+        assert_eq!(cards.len(), 7);
+
+        let again = random_holdem(&mut StdRng::seed_from_u64(21));
+        assert_eq!(again, (hole, board, ranking));
+    }
+
+    fn aces_and_kings() -> [[Card; 2]; 2] {
+        [
+            [
+                Card::new(Rank::Ace, Suit::Spades),
+                Card::new(Rank::Ace, Suit::Hearts),
+            ],
+            [
+                Card::new(Rank::King, Suit::Spades),
+                Card::new(Rank::King, Suit::Hearts),
+            ],
+        ]
+    }
+
+    #[test]
+    fn monte_carlo_equity() {
+        let hands = aces_and_kings();
+        let equity = equity_monte_carlo(&hands, &[], 20_000, &mut StdRng::seed_from_u64(3));
+
+        assert!((equity[0] - 0.82).abs() < 0.02);
+        assert!((equity[0] + equity[1] - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn equity_with_dead_cards() {
+        // Nut flush draw against top pair on the turn
+        let hands = [
+            [
+                Card::new(Rank::Ace, Suit::Hearts),
+                Card::new(Rank::Five, Suit::Hearts),
+            ],
+            [
+                Card::new(Rank::King, Suit::Clubs),
+                Card::new(Rank::Queen, Suit::Spades),
+            ],
+        ];
+        let board = [
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Spades),
+        ];
+        let folded = [
+            Card::new(Rank::Jack, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Hearts),
+        ];
+
+        // Nine flush cards plus three aces among 44 unseen, then 7 of 42 with two folded
+        let live = equity_exact(&hands, &board, &[]);
+        assert!((live[0] - 12.0 / 44.0).abs() < 1e-9);
+        let dead = equity_exact(&hands, &board, &folded);
+        assert!((dead[0] - 10.0 / 42.0).abs() < 1e-9);
+        assert!(dead[0] < live[0]);
+
+        let sampled =
+            equity_monte_carlo_dead(&hands, &board, &folded, 5000, &mut StdRng::seed_from_u64(8));
+        assert!((sampled[0] - dead[0]).abs() < 0.03);
+    }
+
+    #[test]
+    fn preflop_table_equity() {
+        let (aces, kings) = (aces_and_kings()[0], aces_and_kings()[1]);
+
+        let (a, k) = preflop_equity_2way(&aces, &kings);
+        assert!((a - 0.82).abs() < 0.01, "aces have {}", a);
+        assert!((a + k - 1.0).abs() < 1e-9);
+
+        // Relabelling the suits keeps both hands in their classes, and swapping the seats
+        // swaps the looked up equities
+        let relabel = |hand: [Card; 2]| {
+            hand.map(|card| {
+                let suit = Suit::from_ordinal(3 - card.suit.ordinal()).unwrap();
+                Card::new(card.rank, suit)
+            })
+        };
+        assert_eq!(preflop_equity_2way(&relabel(kings), &relabel(aces)), (k, a));
+    }
+
+    #[test]
+    fn tracked_equity() {
+        let flush_draw = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        let queens = [
+            Card::new(Rank::Queen, Suit::Spades),
+            Card::new(Rank::Queen, Suit::Clubs),
+        ];
+        let mut tracker = EquityTracker::new(&[flush_draw, queens]);
+
+        let flop = [
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Diamonds),
+        ];
+        for card in flop {
+            tracker.reveal(card).unwrap();
+        }
+        let on_flop = tracker.equity()[0];
+        assert!(on_flop > 0.4 && on_flop < 0.6, "flush draw has {}", on_flop);
+
+        assert_eq!(tracker.runouts.len(), 990);
+        assert_eq!(
+            tracker.equity(),
+            &equity_exact(&[flush_draw, queens], &flop, &[])[..]
+        );
+
+        // The flush comes in on the turn and leaves the queens drawing dead. Only the
+        // runouts with that turn are left, nothing is enumerated again.
+        tracker.reveal(Card::new(Rank::Nine, Suit::Hearts)).unwrap();
+        assert_eq!(tracker.runouts.len(), 44);
+        assert_eq!(tracker.equity(), &[1.0, 0.0]);
+
+        tracker.reveal(Card::new(Rank::Three, Suit::Clubs)).unwrap();
+        assert_eq!(tracker.equity(), &[1.0, 0.0]);
+
+        assert_eq!(
+            tracker.reveal(Card::new(Rank::Four, Suit::Clubs)),
+            Err(PokerError::TooManyCards(6))
+        );
+        assert_eq!(tracker.board().len(), 5);
+    }
+
+    #[test]
+    fn flush_draw_strength() {
+        let hole = [
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Hearts),
+        ];
+        let flop = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+
+        let mut rng = StdRng::seed_from_u64(417);
+        let (strength, positive, _) = strength_and_potential(&hole, &flop, &mut rng, 3000);
+        let ehs = effective_hand_strength(&hole, &flop, &mut rng, 3000);
+        assert!(positive > 0.2);
+        assert!(ehs > strength, "EHS {} against strength {}", ehs, strength);
+
+        // Nothing left to come on the river
+        let river = [
+            flop[0],
+            flop[1],
+            flop[2],
+            Card::new(Rank::Three, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Spades),
+        ];
+        let (strength, positive, negative) = strength_and_potential(&hole, &river, &mut rng, 500);
+        assert_eq!((positive, negative), (0.0, 0.0));
+        assert!(strength < 0.3);
+    }
+
+    #[test]
+    fn category_odds() {
+        let hole = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Hearts),
+        ];
+        let turn = [
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Hearts),
+        ];
+
+        let odds = category_distribution(&hole, &turn);
+        let flush_or_better: f64 = odds
+            .iter()
+            .filter(|(category, _)| **category >= HandCategory::Flush)
+            .map(|(_, odds)| odds)
+            .sum();
+        assert!((flush_or_better - 1.0).abs() < 1e-9);
+
+        let flop = &turn[..3];
+        let odds = category_distribution(&hole, flop);
+        assert!((odds.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!(odds[&HandCategory::Flush] > 0.3);
+    }
+
+    #[test]
+    fn seven_card_category_frequencies() {
+        let exact = category_frequencies();
+        assert_eq!(exact.len(), 10);
+        assert!((exact.values().sum::<f64>() - 1.0).abs() < 1e-9);
+        assert!((exact[&HandCategory::Flush] - 0.0303).abs() < 1e-4);
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let estimate = estimate_category_frequencies(20_000, &mut rng);
+        for (category, odds) in &exact {
+            let estimated = estimate.get(category).copied().unwrap_or(0.0);
+            assert!(
+                (estimated - odds).abs() < 0.01,
+                "{} estimated at {} but is {}",
+                category,
+                estimated,
+                odds
+            );
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_monte_carlo_equity() {
+        let hands = aces_and_kings();
+        let serial = equity_monte_carlo(&hands, &[], 20_000, &mut StdRng::seed_from_u64(3));
+        let parallel = equity_monte_carlo_par(&hands, &[], 20_000, 3);
+
+        assert!((serial[0] - parallel[0]).abs() < 0.02);
+        assert_eq!(parallel, equity_monte_carlo_par(&hands, &[], 20_000, 3));
+
+        // Fewer iterations than a chunk still runs, on a single thread
+        let tiny = equity_monte_carlo_par(&hands, &[], 10, 3);
+        assert!((tiny.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+    }
+
+    fn five(cards: [(Rank, Suit); 5]) -> [Card; 5] {
+        cards.map(|(rank, suit)| Card::new(rank, suit))
+    }
+
+    #[test]
+    fn eval5_flushes() {
+        let flush = five([
+            (Rank::Seven, Suit::Hearts),
+            (Rank::Eight, Suit::Hearts),
+            (Rank::King, Suit::Hearts),
+            (Rank::Five, Suit::Hearts),
+            (Rank::Two, Suit::Hearts),
+        ]);
+        assert_eq!(eval5(&flush), HandRanking::Flush(0b01_0000_1101_0010));
+
+        let straight_flush = five([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Two, Suit::Hearts),
+            (Rank::Three, Suit::Hearts),
+            (Rank::Four, Suit::Hearts),
+            (Rank::Five, Suit::Hearts),
+        ]);
+        assert_eq!(
+            eval5(&straight_flush),
+            HandRanking::StraightFlush(Rank::Five)
+        );
+
+        let royal_flush = five([
+            (Rank::Ace, Suit::Spades),
+            (Rank::King, Suit::Spades),
+            (Rank::Queen, Suit::Spades),
+            (Rank::Jack, Suit::Spades),
+            (Rank::Ten, Suit::Spades),
+        ]);
+        assert_eq!(eval5(&royal_flush), HandRanking::RoyalFlush);
+    }
+
+    #[test]
+    fn eval5_straight() {
+        let straight = five([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Two, Suit::Clubs),
+            (Rank::Three, Suit::Hearts),
+            (Rank::Four, Suit::Diamonds),
+            (Rank::Five, Suit::Hearts),
+        ]);
+        assert_eq!(eval5(&straight), HandRanking::Straight(Rank::Five));
+    }
+
+    #[test]
+    fn all_ten_straights() {
+        let suits = [Suit::Hearts, Suit::Clubs, Suit::Diamonds, Suit::Spades];
+
+        for high in Rank::Five.ordinal()..=Rank::Ace.ordinal() {
+            // The wheel takes its low card from the Ace
+            let cards: Vec<Card> = (0..5)
+                .map(|offset| {
+                    let rank = match (high + 1).checked_sub(5 - offset) {
+                        Some(ordinal) => Rank::from_ordinal(ordinal).unwrap(),
+                        None => Rank::Ace,
+                    };
+                    Card::new(rank, suits[offset as usize % 4])
+                })
+                .collect();
+            let high = Rank::from_ordinal(high).unwrap();
+            let hand = Hand::new(&cards, &[]);
+
+            assert_eq!(hand.straight(), Some((high, high == Rank::Five)));
+            assert_eq!(hand.best(), HandRanking::Straight(high));
+            assert_eq!(
+                eval5(&[cards[0], cards[1], cards[2], cards[3], cards[4]]),
+                hand.best()
+            );
+        }
+    }
+
+    #[test]
+    fn eval5_quads_and_full_house() {
+        let quads = five([
+            (Rank::King, Suit::Hearts),
+            (Rank::King, Suit::Clubs),
+            (Rank::King, Suit::Diamonds),
+            (Rank::King, Suit::Spades),
+            (Rank::Nine, Suit::Hearts),
+        ]);
+        assert_eq!(eval5(&quads), HandRanking::Quads(Rank::King, Rank::Nine));
+
+        let full_house = five([
+            (Rank::Two, Suit::Hearts),
+            (Rank::Two, Suit::Clubs),
+            (Rank::Two, Suit::Spades),
+            (Rank::Seven, Suit::Clubs),
+            (Rank::Seven, Suit::Spades),
+        ]);
+        assert_eq!(
+            eval5(&full_house),
+            HandRanking::FullHouse(Rank::Two, Rank::Seven)
+        );
+    }
+
+    #[test]
+    fn eval5_sets_and_pairs() {
+        let set = five([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Ace, Suit::Diamonds),
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Nine, Suit::Clubs),
+            (Rank::King, Suit::Spades),
+        ]);
+        assert_eq!(
+            eval5(&set),
+            HandRanking::Set(Rank::Ace, 0b01_0001_0000_0000)
+        );
+
+        let two_pair = five([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Six, Suit::Diamonds),
+            (Rank::Eight, Suit::Hearts),
+            (Rank::Eight, Suit::Clubs),
+        ]);
+        assert_eq!(
+            eval5(&two_pair),
+            HandRanking::TwoPair(Rank::Ace, Rank::Eight, Rank::Six)
+        );
+
+        let one_pair = five([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Ace, Suit::Diamonds),
+            (Rank::Six, Suit::Spades),
+            (Rank::Eight, Suit::Hearts),
+        ]);
+        assert_eq!(
+            eval5(&one_pair),
+            HandRanking::OnePair(Rank::Ace, 0b00_0001_1010_0000)
+        );
+    }
+
+    #[test]
+    fn eval5_high_card() {
+        let high_card = five([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Nine, Suit::Clubs),
+            (Rank::King, Suit::Diamonds),
+            (Rank::Six, Suit::Spades),
+            (Rank::Four, Suit::Hearts),
+        ]);
+        assert_eq!(
+            eval5(&high_card),
+            HandRanking::HighCard(0b11_0001_0010_1000)
+        );
+    }
+
+    #[test]
+    fn distinct_rankings() {
+        let rankings = enumerate_distinct_rankings();
+        assert_eq!(rankings.len(), DISTINCT_HAND_RANKS);
+        assert!(rankings.windows(2).all(|pair| pair[0] < pair[1]));
+
+        for (strength, ranking) in rankings.iter().enumerate() {
+            assert_eq!(ranking.strength(), Some(strength as u16));
+        }
+        assert_eq!(HandRanking::RoyalFlush.strength(), Some(7461));
+
+        // Rankings only fewer cards make have no place on the scale
+        assert_eq!(HandRanking::OnePair(Rank::Ace, 0b1_1000).strength(), None);
+        assert_eq!(
+            Hand::new(&parse_cards("As Ks").unwrap(), &[])
+                .best()
+                .strength(),
+            None
+        );
+    }
+
+    #[test]
+    fn quick_categories() {
+        let mut rng = StdRng::seed_from_u64(12);
+
+        for _ in 0..5000 {
+            let mut deck = Deck::new();
+            deck.shuffle_with(&mut rng);
+            let cards = deck.draw(7);
+
+            assert_eq!(
+                quick_category(&cards),
+                Hand::new(&cards, &[]).best().category(),
+                "{:?}",
+                cards
+            );
+        }
+    }
+
+    #[test]
+    fn eval4_categories() {
+        let four = |cards: [(Rank, Suit); 4]| cards.map(|(rank, suit)| Card::new(rank, suit));
+
+        let quads = four([
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Nine, Suit::Diamonds),
+            (Rank::Nine, Suit::Spades),
+        ]);
+        assert_eq!(eval4(&quads), FourCardRanking::Quads(Rank::Nine));
+
+        let set = four([
+            (Rank::Nine, Suit::Hearts),
+            (Rank::Nine, Suit::Clubs),
+            (Rank::Nine, Suit::Diamonds),
+            (Rank::Ace, Suit::Spades),
+        ]);
+        assert_eq!(eval4(&set), FourCardRanking::Set(Rank::Nine, Rank::Ace));
+
+        let two_pair = four([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::Two, Suit::Spades),
+        ]);
+        assert_eq!(
+            eval4(&two_pair),
+            FourCardRanking::TwoPair(Rank::Ace, Rank::Two)
+        );
 
-                    // Also set bit 1 if Ace
-                    if card.score() == 13 {
-                        bitmask |= 0x01;
-                    }
-                }
-            }
+        let pair = four([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::Ace, Suit::Clubs),
+            (Rank::Two, Suit::Diamonds),
+            (Rank::Five, Suit::Spades),
+        ]);
+        assert_eq!(
+            eval4(&pair),
+            FourCardRanking::OnePair(Rank::Ace, 0b00_0000_0001_0010)
+        );
 
-            if let Some(card) = self.best_straight(bitmask) {
-                if card == Rank::Ace {
-                    Some(HandRanking::RoyalFlush)
-                } else {
-                    Some(HandRanking::StraightFlush(card))
-                }
-            } else {
-                bitmask = self.highcards(bitmask, 5);
+        // Four to a straight flush is still just a high card
+        let high_card = four([
+            (Rank::Ace, Suit::Hearts),
+            (Rank::King, Suit::Hearts),
+            (Rank::Queen, Suit::Hearts),
+            (Rank::Jack, Suit::Hearts),
+        ]);
+        assert_eq!(
+            eval4(&high_card),
+            FourCardRanking::HighCard(0b11_1100_0000_0000)
+        );
+        assert_eq!(eval4(&high_card).category(), HandCategory::HighCard);
 
-                Some(HandRanking::Flush(bitmask))
-            }
-        } else {
-            None
-        }
+        assert!(eval4(&two_pair) > eval4(&pair));
+        assert!(eval4(&quads) > eval4(&set));
+        assert!(eval4(&set) > eval4(&two_pair));
     }
 
-    // Returns the high card rank of the best possible straight, None if no straight found
-    fn best_straight(&self, bitmask: u16) -> Option<Rank> {
-        let mut card: Option<Rank> = None;
+    #[test]
+    fn index_evaluation() {
+        let royal = [
+            (Rank::Ace, Suit::Spades),
+            (Rank::King, Suit::Spades),
+            (Rank::Queen, Suit::Spades),
+            (Rank::Jack, Suit::Spades),
+            (Rank::Ten, Suit::Spades),
+            (Rank::Two, Suit::Hearts),
+            (Rank::Two, Suit::Clubs),
+        ]
+        .map(|(rank, suit)| Card::new(rank, suit).to_index());
+        assert_eq!(eval7_indices(&royal), DISTINCT_HAND_RANKS as u32 - 1);
 
-        // There are ten possible straights, check from highest to lowest
-        for i in (0..10).rev() {
-            if bitmask & 0x1F << i == 0x1F << i {
-                card = Some(Rank::id(i + 4));
-                break;
-            }
-        }
+        // The array evaluator agrees with Hand::best
+        let mut rng = StdRng::seed_from_u64(408);
+        for _ in 0..2000 {
+            let mut deck = Deck::new();
+            deck.shuffle_with(&mut rng);
+            let cards = deck.draw(7);
+            let indices = [0, 1, 2, 3, 4, 5, 6].map(|i| cards[i].to_index());
 
-        card
+            let best = Hand::new(&cards, &[]).best();
+            assert_eq!(eval_cards(&cards), best);
+            assert_eq!(Some(eval7_indices(&indices) as u16), best.strength());
+        }
     }
 
-    // Get bitmask representation of the high cards to be used in HandRanking
-    fn highcards(&self, mut bitmask: u16, bits_needed: u8) -> u16 {
-        // Make sure bit 1 is unset
-        bitmask &= !0x01;
+    #[test]
+    fn mask_evaluation() {
+        let mask = |cards: &[Card]| {
+            cards
+                .iter()
+                .fold(0u64, |mask, card| mask | 1 << card.to_index())
+        };
 
-        let mut bits_set = self.bits_set(bitmask);
+        let quads = parse_cards("9s 9h 9d 9c Kh 2c").unwrap();
+        assert_eq!(
+            eval_mask(mask(&quads)),
+            HandRanking::Quads(Rank::Nine, Rank::King)
+        );
 
-        while bits_set > bits_needed {
-            bitmask &= bitmask - 1; // Unset lsb
-            bits_set -= 1;
+        let mut rng = StdRng::seed_from_u64(432);
+        for count in 5..=7 {
+            let mut deck = Deck::new();
+            deck.shuffle_with(&mut rng);
+            let cards = deck.draw(count);
+            assert_eq!(eval_mask(mask(&cards)), Hand::new(&cards, &[]).best());
         }
+    }
 
-        bitmask
+    #[test]
+    #[should_panic(expected = "card mask holds 4 cards")]
+    fn mask_with_too_few_cards() {
+        eval_mask(0b1111);
     }
 
-    // Count number of bits that are set in bitmask
-    fn bits_set(&self, mut bitmask: u16) -> u8 {
-        let mut count = 0;
+    #[test]
+    fn two_plus_two_scale() {
+        let worst = HandRanking::HighCard(0b101_1110);
+        assert_eq!(to_tptt_strength(&worst), Some(4097));
+        assert_eq!(to_tptt_strength(&HandRanking::RoyalFlush), Some(36874));
+        assert_eq!(
+            to_tptt_strength(&HandRanking::StraightFlush(Rank::Five)),
+            Some(9 << 12 | 1)
+        );
+        assert_eq!(
+            to_tptt_strength(&HandRanking::OnePair(Rank::Two, 0b1_1100)),
+            Some(2 << 12 | 1)
+        );
+        assert_eq!(to_tptt_strength(&HandRanking::HighCard(0b11)), None);
 
-        while bitmask != 0 {
-            bitmask &= bitmask - 1;
-            count += 1;
+        let ascending = [
+            HandRanking::HighCard(0b11_1100_0010_0000),
+            HandRanking::OnePair(Rank::Ace, 0b01_1100_0000_0000),
+            HandRanking::TwoPair(Rank::Three, Rank::Two, Rank::Four),
+            HandRanking::Set(Rank::Queen, 0b00_0100_0000_0010),
+            HandRanking::Straight(Rank::Ace),
+            HandRanking::Flush(0b00_0011_0110_1000),
+            HandRanking::FullHouse(Rank::Two, Rank::Three),
+            HandRanking::Quads(Rank::Ace, Rank::King),
+            HandRanking::StraightFlush(Rank::King),
+        ];
+        for (i, pair) in ascending.windows(2).enumerate() {
+            let (low, high) = (
+                to_tptt_strength(&pair[0]).unwrap(),
+                to_tptt_strength(&pair[1]).unwrap(),
+            );
+            assert!(low < high);
+            assert_eq!(low >> 12, i as u16 + 1);
         }
+    }
 
-        count
+    #[test]
+    fn margins() {
+        let high_card = HandRanking::HighCard(0b00_0000_0101_1110);
+        assert_eq!(
+            strength_margin(&HandRanking::RoyalFlush, &high_card),
+            Some(DISTINCT_HAND_RANKS as i64 - 1)
+        );
+        assert_eq!(
+            strength_margin(&high_card, &HandRanking::RoyalFlush),
+            Some(1 - DISTINCT_HAND_RANKS as i64)
+        );
+        assert_eq!(
+            strength_margin(&HandRanking::HighCard(0b110), &high_card),
+            None
+        );
+        assert_eq!(
+            strength_margin(
+                &HandRanking::Straight(Rank::Nine),
+                &HandRanking::Straight(Rank::Nine)
+            ),
+            Some(0)
+        );
+        assert_eq!(
+            strength_margin(
+                &HandRanking::Straight(Rank::Ten),
+                &HandRanking::Straight(Rank::Nine)
+            ),
+            Some(1)
+        );
     }
 
-    fn best(&self) -> HandRanking {
-        let mut pair: Vec<Rank> = Vec::with_capacity(3);
-        let mut set: Vec<Rank> = Vec::with_capacity(2);
+    #[test]
+    fn fast_eval_table() {
+        let mut rng = StdRng::seed_from_u64(11);
+        let hands: Vec<[Card; 5]> = (0..2000)
+            .map(|_| {
+                let mut deck = Deck::new();
+                deck.shuffle_with(&mut rng);
+                let cards = deck.draw(5);
+                [cards[0], cards[1], cards[2], cards[3], cards[4]]
+            })
+            .collect();
+        let direct: Vec<HandRanking> = hands
+            .iter()
+            .map(|cards| Hand::new(cards, &[]).best())
+            .collect();
 
-        for (card, count) in &self.rank_map {
-            match count {
-                2 => pair.push(*card),
-                3 => set.push(*card),
+        init_tables();
+        assert_eq!(EVAL_TABLE.get().unwrap().len(), DISTINCT_HAND_RANKS);
 
-                // Check for Quads
-                4 => {
-                    let bitmask = self.bitmask ^ 1 << card.score(); // Unset quads bit
-                    let id = (bitmask as f64).log2() as u8; // Find most significant bit
+        let tabled: Vec<HandRanking> = hands.iter().map(fast_eval).collect();
+        assert_eq!(tabled, direct);
+    }
 
-                    return HandRanking::Quads(*card, Rank::id(id));
-                }
-                _ => (),
-            }
-        }
+    #[test]
+    fn eval_cache() {
+        let spades = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Two, Suit::Spades),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+        let diamonds = spades.map(|card| {
+            let suit = match card.suit {
+                Suit::Spades => Suit::Diamonds,
+                _ => Suit::Clubs,
+            };
+            Card::new(card.rank, suit)
+        });
 
-        // Check for FullHouse
-        if set.len() == 2 {
-            set.sort();
-            set.reverse();
+        let mut cache = EvalCache::new();
+        let first = cache.get_or_eval(&spades);
+        assert_eq!((cache.hits(), cache.misses()), (0, 1));
 
-            return HandRanking::FullHouse(set[0], set[1]);
-        }
-        if set.len() == 1 && !pair.is_empty() {
-            pair.sort();
-            pair.reverse();
+        let second = cache.get_or_eval(&diamonds);
+        assert_eq!((cache.hits(), cache.misses()), (1, 1));
+        assert_eq!(first, second);
+        assert_eq!(first.category(), HandCategory::Flush);
+    }
 
-            return HandRanking::FullHouse(set[0], pair[0]);
-        }
+    #[test]
+    fn flush_draw_probability() {
+        assert!((draw_probability(9, 2, 47) - 0.35).abs() < 0.001);
+        assert!((draw_probability(9, 1, 46) - 0.196).abs() < 0.001);
+        assert_eq!(draw_probability(0, 2, 47), 0.0);
+    }
 
-        // Check for Flush and StraightFlush
-        if let Some(flush) = self.check_flush() {
-            return flush;
-        }
+    #[test]
+    fn seeded_shuffle() {
+        let mut deck1 = Deck::new_shuffled_seeded(17);
+        let mut deck2 = Deck::new_shuffled_seeded(17);
+        let mut deck3 = Deck::new_shuffled_seeded(18);
 
-        // Check for Straight
-        if let Some(card) = self.best_straight(self.bitmask) {
-            return HandRanking::Straight(card);
-        }
+        let cards = deck1.draw(52);
+        assert_eq!(cards, deck2.draw(52));
+        assert_ne!(cards, deck3.draw(52));
+        assert_eq!(Deck::new_shuffled().remaining(), 52);
+    }
 
-        // Make sure bit 1 is unset, as it is no longer needed
-        let mut bitmask = self.bitmask;
+    #[test]
+    fn deck_integrity() {
+        let mut deck = Deck::new_shuffled();
+        deck.draw(12);
+        assert_eq!(deck.assert_integrity(), Ok(()));
+        assert_eq!(Deck::multi(3).assert_integrity(), Ok(()));
 
-        // Check for Set
-        if set.len() == 1 {
-            bitmask ^= 1 << set[0].score(); // Unset set1 bit
+        deck.cards[1] = deck.dealt[0];
+        assert!(matches!(
+            deck.assert_integrity(),
+            Err(PokerError::CorruptDeck(_))
+        ));
 
-            bitmask = self.highcards(bitmask, 2); // Two high cards
+        deck.cards.pop();
+        assert!(deck.assert_integrity().is_err());
+    }
 
-            return HandRanking::Set(set[0], bitmask);
-        }
+    #[test]
+    fn render_cards() {
+        let ten = Card::new(Rank::Ten, Suit::Hearts).render_ascii();
+        let nine = Card::new(Rank::Nine, Suit::Spades).render_ascii();
 
-        // Check for TwoPair
-        if pair.len() > 1 {
-            pair.sort();
-            pair.reverse();
+        assert_eq!(ten[0], "┌10─┐");
+        assert_eq!(nine[0], "┌9──┐");
+        assert_eq!(nine[1], "│ ♠ │");
+        for line in ten.iter().chain(&nine) {
+            assert_eq!(line.chars().count(), 5);
+        }
 
-            bitmask ^= 1 << pair[0].score(); // Unset pair1 bit
-            bitmask ^= 1 << pair[1].score(); // Unset pair2 bit
-            let id = (bitmask as f64).log2() as u8; // Find most significant bit
+        let row = render_row(&[
+            Card::new(Rank::Ten, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Spades),
+        ]);
+        let lines: Vec<&str> = row.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "┌10─┐ ┌9──┐");
+        assert!(lines.iter().all(|line| line.chars().count() == 11));
+    }
 
-            return HandRanking::TwoPair(pair[0], pair[1], Rank::id(id));
+    #[test]
+    fn card_index() {
+        for index in 0..52 {
+            assert_eq!(Card::from_index(index).unwrap().to_index(), index);
         }
+        assert_eq!(Card::from_index(52), None);
+        assert_eq!(
+            Card::from_index(51),
+            Some(Card::new(Rank::Ace, Suit::Spades))
+        );
+    }
 
-        // Check for OnePair
-        if !pair.is_empty() {
-            bitmask ^= 1 << pair[0].score(); // Unset pair1 bit
-
-            bitmask = self.highcards(bitmask, 3); // Three high cards
+    #[test]
+    fn deck_bytes_round_trip() {
+        let mut deck = Deck::new();
+        deck.shuffle();
+        deck.draw(9);
 
-            return HandRanking::OnePair(pair[0], bitmask);
-        }
+        let bytes = deck.to_bytes();
+        assert_eq!(bytes.len(), 56);
 
-        // None of the above means HighCard
-        bitmask = self.highcards(bitmask, 5); // Five high cards
+        let mut restored = Deck::from_bytes(&bytes).unwrap();
+        assert_eq!(restored.cards, deck.cards);
+        assert_eq!(restored.dealt, deck.dealt);
+        assert_eq!(restored.draw(5), deck.draw(5));
 
-        HandRanking::HighCard(bitmask)
+        assert!(Deck::from_bytes(&bytes[..20]).is_err());
+        assert!(Deck::from_bytes(&[1, 0, 53, 0, 0]).is_err());
     }
 
-    pub fn display(&self) {
-        println!("Hand is {:?}", self.cards)
-    }
-}
+    #[test]
+    fn hand_builder() {
+        let mut deck = Deck::new_shuffled();
+        let cards = deck.draw(7);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let mut builder = HandBuilder::new();
+        for card in &cards {
+            builder.add_card(*card);
+        }
+        assert_eq!(builder.hand().len(), 7);
+
+        let built = builder.build();
+        let hand = Hand::new(&cards[..2], &cards[2..]);
+        assert_eq!(built.cards(), hand.cards());
+        assert_eq!(built.bitmask, hand.bitmask);
+        assert_eq!(built.suit_map, hand.suit_map);
+        assert_eq!(built.rank_map, hand.rank_map);
+        assert_eq!(built.best(), hand.best());
+    }
 
     #[test]
-    fn hand_ranking() {
-        assert!(
-            Some(HandRanking::Flush(0b01_0011_0001_0010))
-                > Some(HandRanking::Flush(0b01_0001_1101_0010))
-        );
-        assert!(HandRanking::RoyalFlush > HandRanking::StraightFlush(Rank::King));
-        assert!(HandRanking::Straight(Rank::Queen) > HandRanking::Straight(Rank::Jack));
-        assert!(
-            HandRanking::Quads(Rank::Queen, Rank::Jack)
-                < HandRanking::Quads(Rank::Queen, Rank::King)
-        );
-        assert!(
-            HandRanking::FullHouse(Rank::King, Rank::Two)
-                > HandRanking::FullHouse(Rank::Two, Rank::Seven)
-        );
-        assert!(
-            HandRanking::Set(Rank::Two, 0b10_0001_1010_0010)
-                < HandRanking::Set(Rank::Three, 0b01_0001_1010_0010)
-        );
-        assert!(
-            HandRanking::TwoPair(Rank::Ace, Rank::Eight, Rank::Six)
-                > HandRanking::TwoPair(Rank::Ace, Rank::Eight, Rank::Five)
-        );
-        assert!(
-            HandRanking::OnePair(Rank::Ace, 0b10_0001_1010_1100)
-                < HandRanking::OnePair(Rank::Ace, 0b10_0010_1010_1100)
-        );
-        assert!(
-            HandRanking::HighCard(0b10_0001_1010_1100) < HandRanking::HighCard(0b10_0010_1010_1100)
-        );
+    fn hand_cards() {
+        let mut deck = Deck::new();
+        let hole = deck.draw(2);
+        let board = deck.draw(5);
+
+        let hand = Hand::new(&hole, &board);
+        assert_eq!(hand.len(), 7);
+        assert_eq!(hand.cards().len(), 7);
+        assert_eq!(&hand.cards()[..2], &hole[..]);
+        assert_eq!(&hand.cards()[2..], &board[..]);
     }
 
     #[test]
@@ -589,6 +6064,144 @@ mod tests {
         assert_eq!(hand.best(), HandRanking::Straight(Rank::Five));
     }
 
+    #[test]
+    fn check_wheel() {
+        let wheel = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Five, Suit::Hearts),
+        ];
+        assert_eq!(Hand::new(&wheel, &[]).straight(), Some((Rank::Five, true)));
+
+        let six_high = [
+            Card::new(Rank::Six, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Five, Suit::Hearts),
+        ];
+        assert_eq!(
+            Hand::new(&six_high, &[]).straight(),
+            Some((Rank::Six, false))
+        );
+        assert_eq!(
+            Hand::new(&six_high, &[wheel[0]]).straight(),
+            Some((Rank::Six, false))
+        );
+        assert_eq!(Hand::new(&six_high[1..], &[]).straight(), None);
+    }
+
+    #[test]
+    fn best_five_cards() {
+        let hole = [
+            Card::new(Rank::Nine, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+        let board = [
+            Card::new(Rank::King, Suit::Diamonds),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Spades),
+            Card::new(Rank::Four, Suit::Clubs),
+        ];
+        assert_eq!(
+            Hand::new(&hole, &board).best_five(),
+            vec![board[3], board[1], hole[0], board[2], board[0]]
+        );
+
+        let wheel = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Diamonds),
+            Card::new(Rank::Five, Suit::Spades),
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::King, Suit::Hearts),
+        ];
+        assert_eq!(
+            Hand::new(&wheel, &[]).best_five(),
+            vec![wheel[3], wheel[4], wheel[1], wheel[2], wheel[0]]
+        );
+    }
+
+    #[test]
+    fn display_order() {
+        let hole = [
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Ace, Suit::Clubs),
+        ];
+        let board = [
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Jack, Suit::Hearts),
+            Card::new(Rank::Ace, Suit::Spades),
+        ];
+
+        assert_eq!(
+            Hand::new(&hole, &board).ordered_for_display(),
+            vec![board[0], board[3], board[1], board[2], hole[0], board[4], hole[1]]
+        );
+    }
+
+    #[test]
+    fn omaha_hole_cards() {
+        let hole = [
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Ace, Suit::Diamonds),
+            Card::new(Rank::Seven, Suit::Spades),
+            Card::new(Rank::Six, Suit::Clubs),
+        ];
+        let board = [
+            Card::new(Rank::King, Suit::Hearts),
+            Card::new(Rank::Nine, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Hearts),
+        ];
+
+        // The board flush plays in hold'em but not with two hole cards in Omaha
+        let ranking = Hand::new(&hole, &board).best();
+        assert_eq!(ranking.category(), HandCategory::Flush);
+        assert!(uses_n_hole_cards(&hole, &board, &ranking, 0));
+        assert!(!uses_n_hole_cards(&hole, &board, &ranking, 2));
+
+        let aces = HandRanking::OnePair(Rank::Ace, 0b01_0001_0001_0000);
+        assert!(uses_n_hole_cards(&hole, &board, &aces, 2));
+    }
+
+    #[test]
+    fn check_no_wheel() {
+        let hole = [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+        ];
+        let board = [
+            Card::new(Rank::Three, Suit::Diamonds),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::Five, Suit::Hearts),
+        ];
+
+        let hand = Hand::new(&hole, &board);
+        assert_eq!(hand.best(), HandRanking::Straight(Rank::Five));
+        assert_eq!(
+            hand.best_no_wheel(),
+            HandRanking::HighCard(0b10_0000_0001_1110)
+        );
+
+        let suited: Vec<Card> = [Rank::Ace, Rank::Two, Rank::Three, Rank::Four, Rank::Five]
+            .iter()
+            .map(|rank| Card::new(*rank, Suit::Clubs))
+            .collect();
+        let hand = Hand::new(&suited, &[]);
+        assert_eq!(hand.best(), HandRanking::StraightFlush(Rank::Five));
+        assert_eq!(
+            hand.best_no_wheel(),
+            HandRanking::Flush(0b10_0000_0001_1110)
+        );
+    }
+
     #[test]
     fn check_quads() {
         let hole = [
@@ -628,6 +6241,16 @@ mod tests {
         assert_eq!(hand.best(), HandRanking::Quads(Rank::King, Rank::Nine));
     }
 
+    #[test]
+    fn check_quads_without_kicker() {
+        for rank in [Rank::King, Rank::Ace, Rank::Two] {
+            let cards: Vec<Card> = Suit::iter().map(|suit| Card::new(rank, suit)).collect();
+
+            let hand = Hand::new(&cards[..2], &cards[2..]);
+            assert_eq!(hand.best(), HandRanking::Quads(rank, rank));
+        }
+    }
+
     #[test]
     fn check_full_house_two_sets() {
         let hole = [
@@ -706,6 +6329,25 @@ mod tests {
         assert_eq!(hand.best(), HandRanking::FullHouse(Rank::Two, Rank::Seven));
     }
 
+    #[test]
+    fn check_full_house_large_pool() {
+        let hole = [
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Spades),
+        ];
+        let board = [
+            Card::new(Rank::Four, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Clubs),
+            Card::new(Rank::Four, Suit::Spades),
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::King, Suit::Diamonds),
+        ];
+
+        let hand = Hand::new(&hole, &board);
+        assert_eq!(hand.best(), HandRanking::FullHouse(Rank::Four, Rank::King));
+    }
+
     #[test]
     fn check_set() {
         let hole1 = [
@@ -0,0 +1,55 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Pot {
+    size: u64,
+    big_blind: u64,
+}
+
+impl Pot {
+    pub fn new(big_blind: u64) -> Self {
+        Pot { size: 0, big_blind }
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn add(&mut self, chips: u64) {
+        self.size += chips;
+    }
+
+    // Smallest total a raise must reach: the last bet plus at least as much again,
+    // and never less than the big blind when opening the betting
+    pub fn min_raise(&self, last_bet: u64) -> u64 {
+        (last_bet * 2).max(self.big_blind)
+    }
+
+    // Betting the pot when nobody has bet yet on this street
+    pub fn pot_sized_bet(&self) -> u64 {
+        self.size.max(self.big_blind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn min_raise() {
+        let mut pot = Pot::new(10);
+        pot.add(15);
+
+        assert_eq!(pot.min_raise(0), 10);
+        assert_eq!(pot.min_raise(40), 80);
+    }
+
+    #[test]
+    fn pot_sized_bet() {
+        let mut pot = Pot::new(10);
+        assert_eq!(pot.pot_sized_bet(), 10);
+
+        pot.add(120);
+        pot.add(30);
+        assert_eq!(pot.size(), 150);
+        assert_eq!(pot.pot_sized_bet(), 150);
+    }
+}
@@ -0,0 +1,1604 @@
+// Generated by examples/preflop_table.rs, do not edit.
+//
+// Heads-up preflop equity of the first class against the second, averaged over
+// every concrete matchup of the two. Classes are indexed as in preflop_class, and
+// entry i * (339 - i) / 2 + (j - i) holds class i against class j >= i.
+pub(crate) static PREFLOP_EQUITY: [f64; 14365] = [
+    0.500000, 0.665767, 0.659646, 0.653581, 0.664872, 0.674108, 0.670311, 0.668245, 0.665645,
+    0.667565, 0.669516, 0.671499, 0.658201, 0.627912, 0.195705, 0.528293, 0.522975, 0.527545,
+    0.527240, 0.539264, 0.534066, 0.531842, 0.533732, 0.535653, 0.537606, 0.531479, 0.622253,
+    0.502658, 0.189396, 0.510783, 0.515396, 0.515133, 0.527199, 0.534001, 0.528266, 0.530156,
+    0.532077, 0.534030, 0.527903, 0.616637, 0.497734, 0.486539, 0.184271, 0.503230, 0.503009,
+    0.515116, 0.521960, 0.528226, 0.526604, 0.528525, 0.530478, 0.524351, 0.626891, 0.502133,
+    0.490980, 0.479786, 0.184433, 0.489427, 0.502005, 0.508892, 0.515199, 0.525578, 0.523987,
+    0.525940, 0.530656, 0.635288, 0.501920, 0.490807, 0.479654, 0.466994, 0.181324, 0.488707,
+    0.495635, 0.501984, 0.512405, 0.522815, 0.521256, 0.523958, 0.631744, 0.513091, 0.502017,
+    0.490904, 0.478709, 0.466329, 0.183519, 0.488023, 0.494415, 0.504877, 0.515329, 0.525771,
+    0.524961, 0.629796, 0.508285, 0.508281, 0.497207, 0.485051, 0.472711, 0.465697, 0.182174,
+    0.485796, 0.496300, 0.506794, 0.517278, 0.528469, 0.627364, 0.506204, 0.502986, 0.502982,
+    0.490866, 0.478565, 0.471591, 0.463613, 0.181336, 0.483671, 0.494207, 0.504733, 0.515965,
+    0.629066, 0.507891, 0.504673, 0.501455, 0.500408, 0.488147, 0.481212, 0.473274, 0.461616,
+    0.181305, 0.497716, 0.508242, 0.519474, 0.630799, 0.509610, 0.506392, 0.503173, 0.498912,
+    0.497721, 0.490826, 0.482927, 0.471308, 0.474520, 0.181526, 0.511750, 0.522983, 0.632563,
+    0.511360, 0.508142, 0.504923, 0.500662, 0.496256, 0.500431, 0.492572, 0.480993, 0.484204,
+    0.487415, 0.181874, 0.526492, 0.620358, 0.505456, 0.502237, 0.499019, 0.505080, 0.498801,
+    0.499762, 0.502973, 0.491433, 0.494644, 0.497856, 0.501067, 0.177833, 0.500000, 0.468198,
+    0.401498, 0.371323, 0.348077, 0.323363, 0.308481, 0.294752, 0.285899, 0.279643, 0.274754,
+    0.253202, 0.474842, 0.107966, 0.452275, 0.391596, 0.358610, 0.322750, 0.315584, 0.298114,
+    0.284009, 0.274782, 0.268150, 0.262887, 0.241126, 0.443836, 0.431101, 0.125813, 0.394476,
+    0.375197, 0.358510, 0.354636, 0.350429, 0.343719, 0.341437, 0.340302, 0.339878, 0.325109,
+    0.382484, 0.375259, 0.374732, 0.120985, 0.366501, 0.352916, 0.349541, 0.345375, 0.343679,
+    0.340469, 0.339335, 0.338910, 0.324141, 0.353905, 0.344111, 0.357197, 0.349227, 0.134652,
+    0.344125, 0.343101, 0.342907, 0.341253, 0.343056, 0.340994, 0.340570, 0.340644, 0.331714,
+    0.310213, 0.341810, 0.336695, 0.327929, 0.139841, 0.325300, 0.336272, 0.334898, 0.336743,
+    0.339695, 0.338342, 0.335497, 0.308523, 0.303293, 0.338308, 0.333655, 0.327075, 0.310045,
+    0.141183, 0.333702, 0.333065, 0.335080, 0.338074, 0.341735, 0.337961, 0.294395, 0.286741,
+    0.334514, 0.329901, 0.326988, 0.320365, 0.318019, 0.144228, 0.331873, 0.334406, 0.337460,
+    0.341163, 0.342403, 0.281357, 0.273336, 0.328288, 0.328285, 0.325412, 0.319048, 0.317385,
+    0.316303, 0.143398, 0.328160, 0.331841, 0.335605, 0.337456, 0.272872, 0.264482, 0.326128,
+    0.325283, 0.327020, 0.320696, 0.319191, 0.318589, 0.312831, 0.143087, 0.332766, 0.336529,
+    0.338381, 0.266835, 0.258078, 0.325035, 0.324190, 0.325085, 0.323371, 0.321905, 0.321361,
+    0.316184, 0.317022, 0.143028, 0.337454, 0.339305, 0.262087, 0.252962, 0.324604, 0.323759,
+    0.324654, 0.322098, 0.325242, 0.324737, 0.319618, 0.320456, 0.321294, 0.143096, 0.340230,
+    0.242421, 0.233091, 0.310720, 0.309874, 0.325341, 0.319950, 0.322252, 0.326357, 0.321799,
+    0.322637, 0.323475, 0.324313, 0.127861, 0.500000, 0.402624, 0.372827, 0.353080, 0.328372,
+    0.309105, 0.299316, 0.290463, 0.284206, 0.279318, 0.257702, 0.505376, 0.298424, 0.463253,
+    0.442337, 0.416826, 0.402266, 0.398356, 0.384931, 0.382668, 0.380386, 0.379251, 0.378826,
+    0.366150, 0.474854, 0.439431, 0.105992, 0.384143, 0.355720, 0.321259, 0.314253, 0.298093,
+    0.285715, 0.276488, 0.269856, 0.264593, 0.242832, 0.383662, 0.418843, 0.368496, 0.122501,
+    0.363373, 0.351821, 0.348473, 0.345914, 0.345351, 0.342141, 0.341007, 0.340582, 0.325814,
+    0.355423, 0.395502, 0.341541, 0.346490, 0.136738, 0.346612, 0.345615, 0.343445, 0.343494,
+    0.345298, 0.343236, 0.342811, 0.342745, 0.336456, 0.382192, 0.308967, 0.335942, 0.330482,
+    0.145509, 0.337741, 0.336811, 0.340153, 0.341998, 0.344949, 0.343597, 0.340751, 0.313273,
+    0.378650, 0.302194, 0.332921, 0.329648, 0.321879, 0.146863, 0.334247, 0.338326, 0.340341,
+    0.343335, 0.346996, 0.343222, 0.294980, 0.366098, 0.286802, 0.330423, 0.327510, 0.320887,
+    0.318541, 0.144266, 0.332417, 0.334951, 0.338005, 0.341708, 0.342948, 0.285588, 0.363981,
+    0.274986, 0.329996, 0.327645, 0.324066, 0.322408, 0.316825, 0.147817, 0.332223, 0.335904,
+    0.339668, 0.341519, 0.277102, 0.361821, 0.266133, 0.326995, 0.329253, 0.325713, 0.324214,
+    0.319111, 0.316621, 0.147506, 0.336828, 0.340592, 0.342444, 0.271066, 0.360727, 0.259729,
+    0.325901, 0.327318, 0.328388, 0.326929, 0.321883, 0.319973, 0.320811, 0.147448, 0.341517,
+    0.343368, 0.266318, 0.360296, 0.254613, 0.325470, 0.326887, 0.327116, 0.330266, 0.325259,
+    0.323407, 0.324245, 0.325083, 0.147515, 0.344293, 0.246592, 0.348428, 0.234742, 0.311586,
+    0.327465, 0.324967, 0.327276, 0.326880, 0.325588, 0.326426, 0.327264, 0.328102, 0.132280,
+    0.500000, 0.373997, 0.354566, 0.329857, 0.314032, 0.299857, 0.294944, 0.288688, 0.283799,
+    0.262183, 0.566740, 0.304213, 0.492054, 0.463253, 0.418212, 0.403972, 0.400125, 0.389860,
+    0.383203, 0.384439, 0.383305, 0.382880, 0.370203, 0.565778, 0.466194, 0.294780, 0.452531,
+    0.405477, 0.391236, 0.387389, 0.389298, 0.379519, 0.380755, 0.379620, 0.379196, 0.366519,
+    0.474854, 0.439444, 0.429554, 0.104368, 0.349741, 0.318630, 0.312771, 0.296771, 0.285703,
+    0.278202, 0.271570, 0.266307, 0.244546, 0.356644, 0.396926, 0.385112, 0.336097, 0.138242,
+    0.343868, 0.342373, 0.342871, 0.343958, 0.346895, 0.344833, 0.344408, 0.344342, 0.337958,
+    0.383926, 0.372112, 0.306611, 0.328087, 0.147582, 0.337511, 0.339250, 0.340616, 0.344164,
+    0.347116, 0.345763, 0.342918, 0.314775, 0.380444, 0.368629, 0.300924, 0.326791, 0.321807,
+    0.148937, 0.336686, 0.338789, 0.342508, 0.345501, 0.349162, 0.345389, 0.299654, 0.370793,
+    0.370436, 0.285679, 0.327220, 0.323382, 0.321043, 0.149934, 0.332887, 0.340136, 0.343190,
+    0.346893, 0.348133, 0.286097, 0.364485, 0.361251, 0.275023, 0.328089, 0.324511, 0.322853,
+    0.317270, 0.147842, 0.332692, 0.336373, 0.340137, 0.341989, 0.281257, 0.365591, 0.362358,
+    0.267759, 0.330887, 0.327869, 0.326370, 0.324057, 0.317065, 0.151913, 0.340816, 0.344580,
+    0.346431, 0.275221, 0.364498, 0.361264, 0.261355, 0.328952, 0.330544, 0.329084, 0.326829,
+    0.320418, 0.324523, 0.151855, 0.345505, 0.347356, 0.270473, 0.364067, 0.360833, 0.256239,
+    0.328521, 0.329271, 0.332421, 0.330206, 0.323852, 0.327957, 0.328795, 0.151922, 0.348280,
+    0.250747, 0.352198, 0.348965, 0.236368, 0.329099, 0.327123, 0.329432, 0.331826, 0.326033,
+    0.330138, 0.330976, 0.331814, 0.136687, 0.500000, 0.337711, 0.312750, 0.297493, 0.286760,
+    0.277462, 0.275145, 0.270256, 0.265247, 0.594169, 0.303178, 0.520043, 0.518593, 0.461919,
+    0.389569, 0.385613, 0.375917, 0.372280, 0.369123, 0.371506, 0.371082, 0.361107, 0.592863,
+    0.491458, 0.293409, 0.503459, 0.451082, 0.376770, 0.372814, 0.375215, 0.368595, 0.365438,
+    0.367822, 0.367397, 0.356994, 0.591846, 0.490184, 0.476036, 0.284076, 0.422080, 0.364053,
+    0.360097, 0.362498, 0.368052, 0.361772, 0.364156, 0.363731, 0.353328, 0.474854, 0.437612,
+    0.427611, 0.400760, 0.082086, 0.295903, 0.292466, 0.277359, 0.266452, 0.260261, 0.255356,
+    0.250092, 0.244710, 0.322397, 0.370115, 0.358242, 0.346427, 0.285523, 0.126884, 0.316800,
+    0.318579, 0.322613, 0.327199, 0.331284, 0.329932, 0.329663, 0.298975, 0.366532, 0.354658,
+    0.342843, 0.282087, 0.302549, 0.128238, 0.316015, 0.320786, 0.325542, 0.329669, 0.333331,
+    0.332134, 0.284374, 0.357402, 0.356935, 0.345121, 0.267689, 0.304175, 0.301835, 0.129805,
+    0.317897, 0.323171, 0.327928, 0.331631, 0.335448, 0.273989, 0.353885, 0.350651, 0.350294,
+    0.257180, 0.307871, 0.306213, 0.303421, 0.131308, 0.316303, 0.324700, 0.328464, 0.332323,
+    0.264985, 0.350801, 0.347567, 0.344333, 0.251076, 0.311963, 0.310465, 0.308152, 0.301681,
+    0.129736, 0.324426, 0.328190, 0.332049, 0.262594, 0.352974, 0.349741, 0.346507, 0.246261,
+    0.315828, 0.314368, 0.312634, 0.309536, 0.309139, 0.134059, 0.332633, 0.336492, 0.257846,
+    0.352543, 0.349309, 0.346076, 0.241146, 0.314555, 0.317705, 0.316011, 0.312969, 0.312573,
+    0.316678, 0.134127, 0.337417, 0.252988, 0.343318, 0.339691, 0.336457, 0.235912, 0.314273,
+    0.316582, 0.319498, 0.316496, 0.316099, 0.320204, 0.321042, 0.134185, 0.500000, 0.297683,
+    0.282173, 0.272008, 0.266152, 0.259450, 0.258501, 0.249957, 0.615421, 0.303832, 0.536699,
+    0.534931, 0.547148, 0.460738, 0.374477, 0.365101, 0.362033, 0.361894, 0.359885, 0.362979,
+    0.352209, 0.610880, 0.506451, 0.293726, 0.519765, 0.531981, 0.449786, 0.361678, 0.363970,
+    0.357919, 0.357781, 0.355772, 0.358865, 0.348096, 0.609582, 0.504885, 0.490707, 0.284099,
+    0.516928, 0.420700, 0.348898, 0.351189, 0.357236, 0.354115, 0.352106, 0.355199, 0.344430,
+    0.625205, 0.516243, 0.502068, 0.487985, 0.277633, 0.381927, 0.340195, 0.342487, 0.348533,
+    0.357587, 0.352455, 0.355548, 0.354304, 0.474853, 0.436063, 0.425948, 0.399016, 0.362912,
+    0.064838, 0.274696, 0.261369, 0.251102, 0.245071, 0.241476, 0.237939, 0.228499, 0.285051,
+    0.355870, 0.343996, 0.332122, 0.323844, 0.265562, 0.112188, 0.298312, 0.303232, 0.310657,
+    0.316391, 0.321185, 0.316677, 0.270211, 0.347032, 0.346172, 0.334298, 0.326020, 0.252823,
+    0.285370, 0.113755, 0.300913, 0.308855, 0.314649, 0.319486, 0.319991, 0.260346, 0.344036,
+    0.340409, 0.339943, 0.331665, 0.242922, 0.289900, 0.287629, 0.115828, 0.305000, 0.311421,
+    0.316888, 0.317435, 0.254513, 0.343743, 0.340116, 0.336883, 0.340062, 0.236965, 0.296718,
+    0.294927, 0.291247, 0.117851, 0.311154, 0.319634, 0.320181, 0.247958, 0.341726, 0.338099,
+    0.334866, 0.335168, 0.233310, 0.301878, 0.300144, 0.297045, 0.296648, 0.116531, 0.319360,
+    0.319907, 0.246855, 0.344562, 0.340935, 0.337701, 0.338004, 0.229784, 0.306404, 0.304710,
+    0.302189, 0.304584, 0.304187, 0.120981, 0.324350, 0.238799, 0.334584, 0.330957, 0.327723,
+    0.336955, 0.220936, 0.302368, 0.305284, 0.302803, 0.305197, 0.304801, 0.308906, 0.118004,
+    0.500000, 0.280898, 0.270480, 0.265192, 0.261932, 0.256598, 0.251993, 0.638562, 0.312666,
+    0.557559, 0.552636, 0.563964, 0.570484, 0.470556, 0.369077, 0.365900, 0.366331, 0.367341,
+    0.366041, 0.358790, 0.634021, 0.525945, 0.302212, 0.537464, 0.548792, 0.555312, 0.459595,
+    0.367940, 0.361781, 0.362212, 0.363222, 0.361922, 0.354671, 0.632723, 0.521478, 0.507301,
+    0.292290, 0.533707, 0.540227, 0.423980, 0.355160, 0.361097, 0.358546, 0.359555, 0.358256,
+    0.351004, 0.648585, 0.532005, 0.517830, 0.503717, 0.285002, 0.525100, 0.387751, 0.345825,
+    0.351762, 0.361308, 0.359335, 0.358036, 0.360773, 0.662583, 0.538017, 0.523843, 0.509731,
+    0.495420, 0.274980, 0.347929, 0.333186, 0.339123, 0.348669, 0.358871, 0.354449, 0.354386,
+    0.474853, 0.445206, 0.435092, 0.402130, 0.368363, 0.331235, 0.066617, 0.260306, 0.251459,
+    0.245815, 0.242380, 0.240153, 0.232441, 0.269176, 0.350839, 0.349979, 0.338105, 0.329247,
+    0.317350, 0.251873, 0.115230, 0.296624, 0.304825, 0.313288, 0.319731, 0.321370, 0.259072,
+    0.347742, 0.344115, 0.343649, 0.334790, 0.322893, 0.243306, 0.283789, 0.117303, 0.301539,
+    0.310629, 0.317133, 0.318814, 0.253760, 0.347971, 0.344343, 0.341110, 0.343658, 0.331762,
+    0.237718, 0.291341, 0.288182, 0.119895, 0.313375, 0.319879, 0.322129, 0.250376, 0.348744,
+    0.345117, 0.341884, 0.341665, 0.341226, 0.234209, 0.299125, 0.296547, 0.298941, 0.122170,
+    0.319612, 0.324875, 0.245109, 0.347389, 0.343762, 0.340529, 0.340310, 0.336994, 0.231843,
+    0.304946, 0.302426, 0.304820, 0.304424, 0.120977, 0.324601, 0.240698, 0.340678, 0.337051,
+    0.333817, 0.342949, 0.337067, 0.224585, 0.306710, 0.304229, 0.307145, 0.309539, 0.309142,
+    0.122382, 0.500000, 0.267431, 0.261890, 0.259198, 0.257305, 0.248315, 0.652706, 0.313771,
+    0.564514, 0.559585, 0.570913, 0.577542, 0.593222, 0.468331, 0.362618, 0.362940, 0.364519,
+    0.366239, 0.354594, 0.652097, 0.532300, 0.308662, 0.549428, 0.557743, 0.563803, 0.579482,
+    0.467028, 0.362569, 0.362891, 0.364470, 0.366190, 0.354545, 0.647627, 0.527833, 0.518453,
+    0.298433, 0.542652, 0.548712, 0.564391, 0.431405, 0.361450, 0.358790, 0.360369, 0.362089,
+    0.350444, 0.662969, 0.538360, 0.526209, 0.512096, 0.290893, 0.533553, 0.549661, 0.390429,
+    0.352115, 0.361552, 0.360149, 0.361868, 0.360212, 0.677207, 0.544474, 0.531802, 0.517690,
+    0.503349, 0.280661, 0.534813, 0.349366, 0.339414, 0.348850, 0.359544, 0.358281, 0.353825,
+    0.678439, 0.559153, 0.546481, 0.532369, 0.518447, 0.504414, 0.276605, 0.318229, 0.331173,
+    0.340609, 0.351303, 0.362215, 0.354636, 0.474853, 0.443176, 0.441921, 0.408959, 0.370802,
+    0.332521, 0.303476, 0.064446, 0.246783, 0.242484, 0.239183, 0.237117, 0.230714, 0.256386,
+    0.344854, 0.344850, 0.343991, 0.335132, 0.323176, 0.315470, 0.239010, 0.114407, 0.293565,
+    0.303023, 0.312196, 0.315483, 0.250835, 0.344981, 0.344978, 0.341350, 0.343899, 0.331943,
+    0.324237, 0.234693, 0.280919, 0.116999, 0.306338, 0.315511, 0.318798, 0.247971, 0.346276,
+    0.346273, 0.342646, 0.342427, 0.341878, 0.334172, 0.231314, 0.289639, 0.292555, 0.119844,
+    0.318256, 0.321544, 0.245876, 0.347712, 0.347709, 0.344082, 0.343863, 0.340547, 0.344299,
+    0.229095, 0.298085, 0.301001, 0.303395, 0.122246, 0.321276, 0.237300, 0.336810, 0.336807,
+    0.333180, 0.342311, 0.336429, 0.337304, 0.222996, 0.301144, 0.304059, 0.306454, 0.306057,
+    0.118008, 0.500000, 0.258776, 0.255831, 0.254507, 0.249527, 0.665736, 0.315994, 0.566777,
+    0.566242, 0.574551, 0.580610, 0.596399, 0.595235, 0.468331, 0.360529, 0.361998, 0.364288,
+    0.356232, 0.661506, 0.534455, 0.309967, 0.552737, 0.561046, 0.567106, 0.582895, 0.594675,
+    0.464803, 0.359546, 0.361015, 0.363304, 0.355248, 0.660969, 0.533923, 0.521443, 0.305125,
+    0.550971, 0.554017, 0.569237, 0.581017, 0.438837, 0.359515, 0.360984, 0.363273, 0.355218,
+    0.673139, 0.541677, 0.529199, 0.519883, 0.297321, 0.538853, 0.554501, 0.566281, 0.396354,
+    0.361842, 0.360329, 0.362618, 0.364551, 0.686857, 0.547270, 0.534792, 0.522704, 0.508364,
+    0.286878, 0.539621, 0.551401, 0.351825, 0.349140, 0.359724, 0.359031, 0.358164, 0.688328,
+    0.562050, 0.549572, 0.536963, 0.523041, 0.508979, 0.282654, 0.541014, 0.319666, 0.340836,
+    0.351420, 0.362824, 0.358974, 0.691208, 0.561051, 0.560543, 0.547934, 0.534012, 0.519951,
+    0.510172, 0.275050, 0.291173, 0.329079, 0.339663, 0.351067, 0.359251, 0.474853, 0.443176,
+    0.439890, 0.415789, 0.376251, 0.334758, 0.304761, 0.278131, 0.062969, 0.237342, 0.235638,
+    0.233706, 0.227464, 0.248105, 0.342893, 0.342048, 0.342044, 0.344200, 0.332244, 0.324478,
+    0.313506, 0.229968, 0.114391, 0.295981, 0.305522, 0.311907, 0.245002, 0.344087, 0.343242,
+    0.343238, 0.342626, 0.342078, 0.334312, 0.323340, 0.228100, 0.283119, 0.117236, 0.308837,
+    0.315222, 0.243427, 0.346045, 0.345199, 0.345196, 0.344584, 0.341268, 0.344910, 0.333937,
+    0.226009, 0.291920, 0.294835, 0.120207, 0.318537, 0.238543, 0.338455, 0.337610, 0.337606,
+    0.346344, 0.340462, 0.341337, 0.341712, 0.220058, 0.297957, 0.300873, 0.303789, 0.120133,
+    0.500000, 0.252026, 0.250702, 0.245722, 0.674192, 0.313676, 0.569059, 0.565006, 0.577708,
+    0.580749, 0.595968, 0.594913, 0.597324, 0.468331, 0.358610, 0.360899, 0.352843, 0.669962,
+    0.536563, 0.307312, 0.551502, 0.564204, 0.567244, 0.582463, 0.594353, 0.593252, 0.464803,
+    0.357627, 0.359916, 0.351860, 0.665804, 0.532810, 0.520330, 0.301594, 0.550781, 0.553821,
+    0.569041, 0.580930, 0.592774, 0.436613, 0.356662, 0.358951, 0.350895, 0.681906, 0.544499,
+    0.532021, 0.519605, 0.299219, 0.543672, 0.556307, 0.567627, 0.579471, 0.403502, 0.360077,
+    0.362366, 0.364298, 0.692452, 0.547319, 0.534841, 0.522426, 0.512883, 0.288553, 0.541421,
+    0.552741, 0.564585, 0.356535, 0.359037, 0.358344, 0.357477, 0.693403, 0.561577, 0.549100,
+    0.536685, 0.524787, 0.510725, 0.284162, 0.542322, 0.554166, 0.321906, 0.350733, 0.362136,
+    0.358287, 0.696522, 0.560680, 0.560172, 0.547758, 0.535339, 0.521277, 0.511469, 0.276431,
+    0.542910, 0.292610, 0.338913, 0.350317, 0.358564, 0.699482, 0.562985, 0.559256, 0.558811,
+    0.546392, 0.532330, 0.522522, 0.511913, 0.268601, 0.268350, 0.323658, 0.335062, 0.343309,
+    0.474853, 0.443167, 0.439882, 0.413750, 0.382812, 0.339079, 0.306788, 0.279409, 0.256716,
+    0.059481, 0.233912, 0.231980, 0.225738, 0.241484, 0.340942, 0.340097, 0.339252, 0.342263,
+    0.341321, 0.333556, 0.322524, 0.308322, 0.226510, 0.112861, 0.305316, 0.311701, 0.239909,
+    0.342899, 0.342054, 0.341209, 0.344220, 0.340511, 0.344153, 0.333121, 0.318919, 0.224419,
+    0.291572, 0.115832, 0.315016, 0.235026, 0.335310, 0.334464, 0.333619, 0.345981, 0.339705,
+    0.340580, 0.340956, 0.326754, 0.218468, 0.297610, 0.300525, 0.115759, 0.500000, 0.246834,
+    0.241854, 0.680200, 0.311326, 0.570194, 0.566140, 0.575324, 0.582758, 0.594958, 0.593334,
+    0.595855, 0.599243, 0.468331, 0.357447, 0.349391, 0.675971, 0.537607, 0.304625, 0.552636,
+    0.561820, 0.569254, 0.581454, 0.592774, 0.591783, 0.595171, 0.464803, 0.356464, 0.348408,
+    0.671812, 0.533854, 0.521374, 0.298614, 0.548397, 0.555831, 0.568031, 0.579351, 0.591304,
+    0.591181, 0.436613, 0.355499, 0.347443, 0.684294, 0.542322, 0.529844, 0.517428, 0.295404,
+    0.542334, 0.554963, 0.566283, 0.578236, 0.591057, 0.401277, 0.357980, 0.359913, 0.698771,
+    0.549076, 0.536598, 0.524183, 0.511540, 0.290210, 0.545093, 0.553400, 0.564783, 0.577605,
+    0.363115, 0.358028, 0.357161, 0.696551, 0.560562, 0.548084, 0.535669, 0.523444, 0.514179,
+    0.285638, 0.542974, 0.554358, 0.567179, 0.325685, 0.361386, 0.357537, 0.699150, 0.559143,
+    0.558635, 0.546220, 0.533996, 0.521958, 0.512150, 0.277781, 0.543071, 0.555892, 0.294631,
+    0.349566, 0.357813, 0.702349, 0.561549, 0.557820, 0.557375, 0.545150, 0.532592, 0.522784,
+    0.512145, 0.269867, 0.540878, 0.269786, 0.334248, 0.342495, 0.705835, 0.564641, 0.560912,
+    0.557246, 0.556991, 0.544432, 0.534624, 0.523985, 0.509899, 0.268984, 0.257590, 0.335179,
+    0.343426, 0.474853, 0.443159, 0.439874, 0.413741, 0.380773, 0.345120, 0.310249, 0.281233,
+    0.257993, 0.246455, 0.056403, 0.230254, 0.224011, 0.236332, 0.339695, 0.338850, 0.338005,
+    0.340174, 0.340089, 0.343337, 0.332305, 0.318044, 0.318883, 0.222829, 0.111457, 0.311495,
+    0.231448, 0.332105, 0.331260, 0.330415, 0.341935, 0.339283, 0.339764, 0.340140, 0.325879,
+    0.326717, 0.216878, 0.297262, 0.111384, 0.500000, 0.237923, 0.684922, 0.308944, 0.570618,
+    0.566565, 0.575749, 0.579664, 0.596258, 0.591614, 0.593566, 0.596954, 0.600406, 0.468331,
+    0.345876, 0.680692, 0.537990, 0.301907, 0.553061, 0.562244, 0.566160, 0.582753, 0.591054,
+    0.589494, 0.592882, 0.596334, 0.464803, 0.344893, 0.676534, 0.534237, 0.521757, 0.295601,
+    0.548822, 0.552737, 0.569330, 0.577631, 0.589015, 0.588892, 0.592344, 0.436613, 0.343928,
+    0.689015, 0.542705, 0.530227, 0.517811, 0.292139, 0.539241, 0.556263, 0.564564, 0.575947,
+    0.588768, 0.588708, 0.401277, 0.356398, 0.699872, 0.546238, 0.533760, 0.521346, 0.508702,
+    0.286153, 0.543045, 0.551346, 0.562730, 0.575551, 0.588435, 0.360890, 0.352712, 0.701584,
+    0.561658, 0.549181, 0.536766, 0.524541, 0.512176, 0.287094, 0.545936, 0.553737, 0.566558,
+    0.579442, 0.331412, 0.357158, 0.701011, 0.557467, 0.556959, 0.544545, 0.532320, 0.519955,
+    0.514944, 0.279099, 0.542443, 0.555265, 0.568149, 0.297764, 0.357000, 0.703690, 0.559352,
+    0.555623, 0.555178, 0.542953, 0.530589, 0.522284, 0.511645, 0.271101, 0.540219, 0.553103,
+    0.271589, 0.341682, 0.707176, 0.562443, 0.558714, 0.555048, 0.554793, 0.542429, 0.534124,
+    0.523485, 0.509369, 0.270176, 0.556555, 0.259027, 0.342612, 0.710725, 0.565598, 0.561869,
+    0.558203, 0.554727, 0.554332, 0.546027, 0.535388, 0.521273, 0.524427, 0.269945, 0.249466,
+    0.343543, 0.474853, 0.443151, 0.439866, 0.413733, 0.380765, 0.343081, 0.315511, 0.284094,
+    0.259616, 0.247733, 0.238613, 0.053734, 0.222285, 0.227812, 0.328842, 0.327997, 0.327152,
+    0.338671, 0.335178, 0.339283, 0.339265, 0.325004, 0.325842, 0.326680, 0.215288, 0.107009,
+    0.500000, 0.705524, 0.311577, 0.581110, 0.577056, 0.583405, 0.592297, 0.605372, 0.605121,
+    0.603484, 0.606873, 0.610325, 0.613840, 0.469504, 0.701357, 0.548062, 0.304204, 0.563552,
+    0.569964, 0.578856, 0.591930, 0.604624, 0.599475, 0.602864, 0.606316, 0.609831, 0.466091,
+    0.697199, 0.544309, 0.531829, 0.297603, 0.556541, 0.565433, 0.578508, 0.591202, 0.598997,
+    0.598874, 0.602325, 0.605840, 0.437983, 0.693849, 0.550081, 0.537666, 0.525250, 0.299170,
+    0.539503, 0.555325, 0.568019, 0.575815, 0.588636, 0.588575, 0.592090, 0.413370, 0.708089,
+    0.558417, 0.546003, 0.533588, 0.508931, 0.297537, 0.545449, 0.558143, 0.565938, 0.578759,
+    0.591643, 0.591646, 0.374624, 0.706180, 0.570617, 0.558202, 0.545787, 0.523786, 0.514645,
+    0.297728, 0.549386, 0.557181, 0.570002, 0.582886, 0.595833, 0.342922, 0.709539, 0.570360,
+    0.569915, 0.557500, 0.535499, 0.526358, 0.518247, 0.295288, 0.547320, 0.560141, 0.573025,
+    0.585972, 0.316088, 0.708526, 0.568951, 0.565285, 0.564840, 0.542839, 0.533698, 0.525587,
+    0.516451, 0.287193, 0.545089, 0.557973, 0.570920, 0.288093, 0.712012, 0.572042, 0.568376,
+    0.564710, 0.554679, 0.545538, 0.537427, 0.528291, 0.514176, 0.286227, 0.561425, 0.574372,
+    0.274344, 0.715561, 0.575197, 0.571531, 0.567865, 0.554612, 0.557441, 0.549330, 0.540194,
+    0.526079, 0.529233, 0.285996, 0.577887, 0.264637, 0.719174, 0.578414, 0.574748, 0.571082,
+    0.557829, 0.557437, 0.561296, 0.552161, 0.538045, 0.541199, 0.544417, 0.286501, 0.259188,
+    0.474853, 0.444700, 0.441528, 0.415478, 0.391954, 0.355908, 0.326307, 0.301152, 0.274972,
+    0.261989, 0.252726, 0.247385, 0.067845, 0.500000, 0.158686, 0.481782, 0.425920, 0.395916,
+    0.362949, 0.356009, 0.339454, 0.326050, 0.317221, 0.310839, 0.305745, 0.284935, 0.471434,
+    0.459458, 0.168087, 0.427305, 0.410251, 0.395305, 0.392221, 0.388388, 0.382124, 0.380016,
+    0.378973, 0.378590, 0.364396, 0.415255, 0.408291, 0.400415, 0.163555, 0.402018, 0.389926,
+    0.387303, 0.383510, 0.382084, 0.379089, 0.378045, 0.377662, 0.363468, 0.389346, 0.380047,
+    0.384446, 0.376778, 0.176274, 0.381556, 0.381113, 0.380968, 0.379582, 0.381425, 0.379494,
+    0.379111, 0.379383, 0.369057, 0.348920, 0.370309, 0.365322, 0.357062, 0.181068, 0.364454,
+    0.374606, 0.373479, 0.375361, 0.378269, 0.376998, 0.374416, 0.347352, 0.342164, 0.367407,
+    0.362856, 0.356656, 0.340498, 0.182409, 0.372154, 0.371709, 0.373750, 0.376697, 0.380264,
+    0.376795, 0.333906, 0.326483, 0.363866, 0.359353, 0.356599, 0.350345, 0.348071, 0.185201,
+    0.370399, 0.372919, 0.375923, 0.379530, 0.380898, 0.321528, 0.313742, 0.357956, 0.357953,
+    0.355237, 0.349228, 0.347597, 0.346412, 0.184418, 0.366942, 0.370527, 0.374192, 0.376121,
+    0.313412, 0.305263, 0.355932, 0.355109, 0.356902, 0.350931, 0.349450, 0.348718, 0.343129,
+    0.184118, 0.371411, 0.375076, 0.377005, 0.307605, 0.299094, 0.354915, 0.354092, 0.355065,
+    0.353604, 0.352160, 0.351483, 0.346442, 0.347258, 0.184055, 0.375960, 0.377889, 0.303010,
+    0.294136, 0.354524, 0.353701, 0.354674, 0.352393, 0.355459, 0.354819, 0.349832, 0.350649,
+    0.351465, 0.184110, 0.378773, 0.284266, 0.275191, 0.341049, 0.340226, 0.355291, 0.350239,
+    0.352485, 0.356355, 0.351894, 0.352711, 0.353527, 0.354344, 0.169875, 0.500000, 0.650205,
+    0.644476, 0.656272, 0.666113, 0.675765, 0.672381, 0.670160, 0.672457, 0.674787, 0.677148,
+    0.664061, 0.666026, 0.613854, 0.189894, 0.523222, 0.528558, 0.531349, 0.531418, 0.543812,
+    0.538413, 0.540639, 0.542897, 0.545186, 0.539395, 0.660647, 0.608558, 0.498015, 0.184769,
+    0.516391, 0.519224, 0.519335, 0.531771, 0.538373, 0.537087, 0.539345, 0.541634, 0.535844,
+    0.661643, 0.619285, 0.503143, 0.491949, 0.185501, 0.506072, 0.506225, 0.518703, 0.525347,
+    0.536061, 0.534807, 0.537097, 0.542578, 0.661087, 0.628234, 0.505894, 0.494741, 0.482506,
+    0.186140, 0.492938, 0.505887, 0.512573, 0.523330, 0.534076, 0.532853, 0.535892, 0.652775,
+    0.637023, 0.506034, 0.494921, 0.482725, 0.470345, 0.183800, 0.492594, 0.499322, 0.510121,
+    0.520909, 0.531687, 0.531213, 0.651735, 0.633868, 0.517555, 0.506481, 0.494326, 0.482409,
+    0.470029, 0.186632, 0.491713, 0.502554, 0.513385, 0.524205, 0.535732, 0.649655, 0.631791,
+    0.512575, 0.512572, 0.500456, 0.488578, 0.476238, 0.469224, 0.185793, 0.489925, 0.500797,
+    0.511659, 0.523228, 0.651753, 0.633847, 0.514578, 0.511359, 0.510313, 0.498476, 0.486175,
+    0.479201, 0.467542, 0.185762, 0.504306, 0.515168, 0.526737, 0.653884, 0.635935, 0.516612,
+    0.513393, 0.509133, 0.508365, 0.496103, 0.489169, 0.477550, 0.480761, 0.185984, 0.518677,
+    0.530246, 0.656045, 0.638054, 0.518677, 0.515459, 0.511198, 0.507216, 0.506024, 0.499129,
+    0.487550, 0.490761, 0.493972, 0.186331, 0.533755, 0.653504, 0.626046, 0.513088, 0.509870,
+    0.516342, 0.510076, 0.505670, 0.509845, 0.498305, 0.501517, 0.504728, 0.507939, 0.182290,
+    0.500000, 0.403828, 0.374064, 0.354325, 0.340600, 0.322982, 0.313192, 0.304339, 0.298083,
+    0.293194, 0.271579, 0.508349, 0.474943, 0.124499, 0.389712, 0.363820, 0.341669, 0.315372,
+    0.315343, 0.303007, 0.293821, 0.287231, 0.282009, 0.260143, 0.480044, 0.385343, 0.374283,
+    0.127836, 0.374933, 0.365333, 0.358341, 0.360764, 0.360202, 0.356992, 0.355858, 0.355433,
+    0.340664, 0.454316, 0.357136, 0.349620, 0.357615, 0.142503, 0.360447, 0.355484, 0.358296,
+    0.358345, 0.360149, 0.358087, 0.357662, 0.357919, 0.438980, 0.338169, 0.328446, 0.348946,
+    0.343803, 0.153924, 0.347615, 0.351991, 0.355333, 0.357178, 0.360129, 0.358777, 0.355931,
+    0.419040, 0.324682, 0.303292, 0.342475, 0.339202, 0.331433, 0.159881, 0.334809, 0.348813,
+    0.350829, 0.353822, 0.357483, 0.353710, 0.412595, 0.308027, 0.302950, 0.344773, 0.341861,
+    0.335555, 0.319088, 0.161860, 0.346575, 0.349108, 0.352162, 0.355865, 0.357105, 0.410478,
+    0.298635, 0.291175, 0.344347, 0.341995, 0.338734, 0.332211, 0.330169, 0.165411, 0.346381,
+    0.350062, 0.353826, 0.355677, 0.408318, 0.290150, 0.282363, 0.341345, 0.343603, 0.340381,
+    0.334017, 0.332455, 0.329964, 0.165100, 0.350986, 0.354750, 0.356601, 0.407224, 0.284113,
+    0.275999, 0.340252, 0.341669, 0.343056, 0.336732, 0.335227, 0.333317, 0.334155, 0.165042,
+    0.355675, 0.357526, 0.406793, 0.279365, 0.270924, 0.339821, 0.341237, 0.341783, 0.340069,
+    0.338603, 0.336751, 0.337589, 0.338427, 0.165109, 0.358450, 0.397032, 0.259639, 0.250953,
+    0.325937, 0.342126, 0.339635, 0.337079, 0.340224, 0.338932, 0.339770, 0.340608, 0.341446,
+    0.149874, 0.500000, 0.375170, 0.355747, 0.345521, 0.327908, 0.313734, 0.308821, 0.302564,
+    0.297676, 0.276060, 0.527396, 0.565128, 0.302933, 0.464076, 0.418806, 0.404654, 0.399899,
+    0.402286, 0.392507, 0.393743, 0.392608, 0.392184, 0.379507, 0.508357, 0.474948, 0.440812,
+    0.122959, 0.357841, 0.339040, 0.313889, 0.314021, 0.302995, 0.295535, 0.288945, 0.283723,
+    0.261858, 0.455800, 0.358298, 0.397942, 0.344176, 0.144006, 0.357703, 0.354883, 0.357722,
+    0.358809, 0.361746, 0.359684, 0.359259, 0.359516, 0.440774, 0.339611, 0.385024, 0.326090,
+    0.341408, 0.155997, 0.350027, 0.354430, 0.355796, 0.359344, 0.362296, 0.360943, 0.358097,
+    0.423728, 0.329347, 0.380731, 0.302022, 0.338892, 0.333908, 0.165537, 0.347174, 0.349277,
+    0.356008, 0.359002, 0.362663, 0.358889, 0.417290, 0.312701, 0.382990, 0.301827, 0.341571,
+    0.338050, 0.330846, 0.167527, 0.347045, 0.354294, 0.357348, 0.361051, 0.362291, 0.410982,
+    0.299145, 0.373805, 0.291211, 0.342440, 0.339178, 0.332656, 0.330614, 0.165436, 0.346850,
+    0.350531, 0.354295, 0.356146, 0.412088, 0.294305, 0.374911, 0.283989, 0.345237, 0.342536,
+    0.338958, 0.337401, 0.330409, 0.169507, 0.354974, 0.358738, 0.360589, 0.410995, 0.288268,
+    0.373818, 0.277625, 0.343303, 0.345211, 0.341672, 0.340173, 0.333762, 0.337867, 0.169449,
+    0.359662, 0.361513, 0.410564, 0.283520, 0.373387, 0.272550, 0.342871, 0.343939, 0.345009,
+    0.343550, 0.337196, 0.341301, 0.342139, 0.169516, 0.362438, 0.400803, 0.263794, 0.361519,
+    0.252579, 0.343760, 0.341791, 0.342019, 0.345170, 0.339377, 0.343482, 0.344320, 0.345158,
+    0.154281, 0.500000, 0.338892, 0.328982, 0.311369, 0.300636, 0.291338, 0.289021, 0.284133,
+    0.279123, 0.550271, 0.592183, 0.301562, 0.518097, 0.462700, 0.390189, 0.385752, 0.388203,
+    0.381583, 0.378426, 0.380810, 0.380385, 0.369919, 0.549057, 0.591232, 0.490133, 0.292229,
+    0.427284, 0.377471, 0.373035, 0.375486, 0.381040, 0.374760, 0.377144, 0.376719, 0.366253,
+    0.509103, 0.474948, 0.438940, 0.406173, 0.100720, 0.316313, 0.293584, 0.294609, 0.283744,
+    0.277594, 0.272730, 0.267509, 0.262168, 0.429070, 0.324050, 0.371154, 0.359339, 0.305010,
+    0.135298, 0.329745, 0.333759, 0.337793, 0.342379, 0.346464, 0.345112, 0.344843, 0.412838,
+    0.314067, 0.367171, 0.355356, 0.283186, 0.315062, 0.145408, 0.329516, 0.334287, 0.339043,
+    0.343739, 0.347401, 0.346204, 0.406400, 0.297421, 0.369489, 0.357675, 0.283837, 0.318843,
+    0.314423, 0.147399, 0.332055, 0.337329, 0.342086, 0.345789, 0.349606, 0.402883, 0.287037,
+    0.363205, 0.362848, 0.273369, 0.322538, 0.318801, 0.316765, 0.148902, 0.330461, 0.338857,
+    0.342621, 0.346481, 0.399799, 0.278032, 0.360121, 0.356887, 0.267306, 0.326631, 0.323052,
+    0.321496, 0.315025, 0.147330, 0.338584, 0.342348, 0.346207, 0.401972, 0.275641, 0.362294,
+    0.359061, 0.262532, 0.330495, 0.327477, 0.325978, 0.322879, 0.322483, 0.151653, 0.346791,
+    0.350650, 0.401541, 0.270893, 0.361863, 0.358630, 0.257457, 0.329223, 0.330814, 0.329355,
+    0.326313, 0.325917, 0.330022, 0.151721, 0.351574, 0.394449, 0.266035, 0.352186, 0.348952,
+    0.252264, 0.328941, 0.329691, 0.332842, 0.329840, 0.329443, 0.333548, 0.334386, 0.151779,
+    0.500000, 0.313907, 0.296041, 0.285877, 0.280020, 0.273318, 0.272369, 0.263825, 0.563237,
+    0.610200, 0.301879, 0.534403, 0.546619, 0.461486, 0.374617, 0.376958, 0.370907, 0.370769,
+    0.368760, 0.371853, 0.361021, 0.561731, 0.608968, 0.504804, 0.292252, 0.531566, 0.426027,
+    0.361836, 0.364177, 0.370224, 0.367103, 0.365094, 0.368187, 0.357355, 0.575197, 0.624591,
+    0.516165, 0.502082, 0.285786, 0.389923, 0.353134, 0.355475, 0.361521, 0.370575, 0.365443,
+    0.368537, 0.367615, 0.509821, 0.474947, 0.437348, 0.404541, 0.370896, 0.083488, 0.275823,
+    0.278627, 0.268401, 0.262412, 0.258859, 0.255363, 0.245966, 0.406499, 0.300143, 0.356509,
+    0.344635, 0.336357, 0.266660, 0.129358, 0.312382, 0.317302, 0.324727, 0.330461, 0.335255,
+    0.330747, 0.399959, 0.283258, 0.358726, 0.346852, 0.338574, 0.268979, 0.298479, 0.131349,
+    0.315070, 0.323012, 0.328807, 0.333644, 0.334148, 0.396963, 0.273393, 0.352963, 0.352497,
+    0.344219, 0.259119, 0.303009, 0.300973, 0.133422, 0.319157, 0.325579, 0.331046, 0.331593,
+    0.396670, 0.267560, 0.352670, 0.349436, 0.352616, 0.253203, 0.309827, 0.308271, 0.304591,
+    0.135445, 0.325311, 0.333791, 0.334338, 0.394653, 0.261005, 0.350653, 0.347419, 0.347722,
+    0.249588, 0.314987, 0.313488, 0.310389, 0.309992, 0.134125, 0.333518, 0.334065, 0.397489,
+    0.259902, 0.353489, 0.350255, 0.350558, 0.246103, 0.319513, 0.318054, 0.315533, 0.317928,
+    0.317531, 0.138575, 0.338507, 0.385714, 0.251846, 0.343451, 0.340218, 0.349820, 0.237297,
+    0.315477, 0.318628, 0.316147, 0.318541, 0.318145, 0.322250, 0.135598, 0.500000, 0.280906,
+    0.270489, 0.265201, 0.261940, 0.256606, 0.252002, 0.566361, 0.622750, 0.302159, 0.541255,
+    0.553153, 0.562426, 0.460305, 0.365754, 0.360023, 0.360455, 0.361464, 0.360165, 0.352850,
+    0.564791, 0.618283, 0.510782, 0.292237, 0.538068, 0.547341, 0.424731, 0.352974, 0.358911,
+    0.356360, 0.357370, 0.356070, 0.348755, 0.578359, 0.633625, 0.521851, 0.507738, 0.285519,
+    0.532643, 0.388544, 0.344208, 0.350145, 0.359691, 0.357719, 0.356419, 0.359016, 0.588817,
+    0.647623, 0.530512, 0.516400, 0.502507, 0.279233, 0.348769, 0.335159, 0.341096, 0.350641,
+    0.360843, 0.356421, 0.356358, 0.500758, 0.474947, 0.435799, 0.402879, 0.369152, 0.332065,
+    0.066656, 0.261472, 0.252666, 0.247064, 0.243671, 0.241485, 0.233814, 0.385530, 0.269270,
+    0.348001, 0.336127, 0.327790, 0.319205, 0.253012, 0.115296, 0.297192, 0.305392, 0.313855,
+    0.320299, 0.321937, 0.382434, 0.259166, 0.342530, 0.341670, 0.333333, 0.324748, 0.244486,
+    0.284342, 0.117369, 0.302107, 0.311197, 0.317701, 0.319381, 0.382662, 0.253853, 0.342758,
+    0.339131, 0.342201, 0.333617, 0.238938, 0.291894, 0.288735, 0.119962, 0.313942, 0.320447,
+    0.322696, 0.383436, 0.250470, 0.343532, 0.339905, 0.340208, 0.343081, 0.235471, 0.299678,
+    0.297100, 0.299495, 0.122237, 0.320179, 0.325442, 0.382081, 0.245203, 0.342177, 0.338550,
+    0.338853, 0.338849, 0.233146, 0.305500, 0.302979, 0.305374, 0.304977, 0.121043, 0.325169,
+    0.373573, 0.240792, 0.335407, 0.331780, 0.341382, 0.338922, 0.225929, 0.307263, 0.304782,
+    0.307698, 0.310092, 0.309696, 0.122448, 0.500000, 0.269149, 0.263608, 0.260916, 0.259023,
+    0.250034, 0.578826, 0.639355, 0.310623, 0.555817, 0.564561, 0.572945, 0.579532, 0.470122,
+    0.363935, 0.364257, 0.365836, 0.367555, 0.355848, 0.574356, 0.634889, 0.524478, 0.300394,
+    0.549470, 0.557854, 0.564441, 0.432758, 0.362816, 0.360156, 0.361735, 0.363454, 0.351747,
+    0.587403, 0.650231, 0.532646, 0.518533, 0.293424, 0.543125, 0.549712, 0.392173, 0.354051,
+    0.363487, 0.362084, 0.363803, 0.362007, 0.597550, 0.664468, 0.540475, 0.526363, 0.512440,
+    0.286357, 0.534870, 0.354593, 0.344368, 0.353805, 0.364498, 0.363236, 0.358780, 0.593838,
+    0.678533, 0.546555, 0.532443, 0.518521, 0.504488, 0.276659, 0.319113, 0.331734, 0.341171,
+    0.351864, 0.362776, 0.355197, 0.502821, 0.474947, 0.444943, 0.410382, 0.372584, 0.337515,
+    0.304346, 0.068848, 0.251932, 0.247675, 0.244416, 0.242391, 0.236030, 0.383648, 0.258070,
+    0.346276, 0.345416, 0.337079, 0.327914, 0.316017, 0.243835, 0.118843, 0.297644, 0.307102,
+    0.316275, 0.319562, 0.383775, 0.252518, 0.346403, 0.342776, 0.345846, 0.336681, 0.324784,
+    0.239559, 0.284733, 0.121435, 0.310417, 0.319590, 0.322877, 0.385070, 0.249654, 0.347698,
+    0.344071, 0.344374, 0.346616, 0.334719, 0.236221, 0.293453, 0.296368, 0.124280, 0.322336,
+    0.325623, 0.386506, 0.247560, 0.349134, 0.345507, 0.345810, 0.345285, 0.344846, 0.234042,
+    0.301899, 0.304814, 0.307209, 0.126681, 0.325356, 0.373807, 0.238984, 0.338173, 0.334546,
+    0.344148, 0.341167, 0.337851, 0.227985, 0.304957, 0.307873, 0.310268, 0.309871, 0.122443,
+    0.500000, 0.260494, 0.257549, 0.256225, 0.251245, 0.580981, 0.648765, 0.311928, 0.559126,
+    0.567864, 0.576249, 0.582945, 0.598690, 0.467898, 0.360911, 0.362381, 0.364670, 0.356551,
+    0.580446, 0.648230, 0.527467, 0.307087, 0.557788, 0.563160, 0.569287, 0.585031, 0.440190,
+    0.360880, 0.362350, 0.364639, 0.356520, 0.590720, 0.660401, 0.535636, 0.526319, 0.299851,
+    0.548424, 0.554551, 0.570296, 0.398098, 0.363777, 0.362264, 0.364553, 0.366346, 0.600345,
+    0.674118, 0.543465, 0.531377, 0.517454, 0.292575, 0.539678, 0.555851, 0.357052, 0.354094,
+    0.364679, 0.363985, 0.363118, 0.596735, 0.688422, 0.549646, 0.537037, 0.523115, 0.509053,
+    0.282708, 0.541070, 0.320550, 0.341397, 0.351982, 0.363385, 0.359536, 0.599851, 0.689720,
+    0.564390, 0.551781, 0.537859, 0.524215, 0.510246, 0.279057, 0.296039, 0.333159, 0.343743,
+    0.355146, 0.363331, 0.502821, 0.474947, 0.442912, 0.417212, 0.378033, 0.339752, 0.305632,
+    0.282688, 0.067371, 0.242533, 0.240871, 0.238980, 0.232780, 0.381687, 0.249789, 0.343473,
+    0.343470, 0.346146, 0.336981, 0.325026, 0.317319, 0.234834, 0.118827, 0.300061, 0.309602,
+    0.315986, 0.382881, 0.246686, 0.344667, 0.344664, 0.344573, 0.346815, 0.334860, 0.327153,
+    0.233007, 0.286933, 0.121672, 0.312917, 0.319301, 0.384838, 0.245111, 0.346625, 0.346621,
+    0.346530, 0.346005, 0.345457, 0.337751, 0.230957, 0.295733, 0.298649, 0.124643, 0.322617,
+    0.375452, 0.240227, 0.338976, 0.338972, 0.348181, 0.345200, 0.341884, 0.345526, 0.225047,
+    0.301771, 0.304687, 0.307603, 0.124569, 0.500000, 0.253744, 0.252420, 0.247440, 0.583090,
+    0.657221, 0.309273, 0.557890, 0.571021, 0.576387, 0.582514, 0.598368, 0.597267, 0.467898,
+    0.358992, 0.361281, 0.353162, 0.579333, 0.653065, 0.526355, 0.303555, 0.557598, 0.562964,
+    0.569091, 0.584945, 0.596789, 0.437966, 0.358028, 0.360317, 0.352198, 0.593542, 0.669168,
+    0.538457, 0.526041, 0.301750, 0.553243, 0.556358, 0.571642, 0.583486, 0.405246, 0.362012,
+    0.364301, 0.366093, 0.600394, 0.679713, 0.543514, 0.531099, 0.521973, 0.294250, 0.541478,
+    0.557191, 0.569035, 0.361762, 0.363991, 0.363298, 0.362431, 0.596263, 0.693497, 0.549174,
+    0.536759, 0.524861, 0.510799, 0.284216, 0.542379, 0.554223, 0.322790, 0.351294, 0.362698,
+    0.358848, 0.599480, 0.695034, 0.564019, 0.551604, 0.539185, 0.525541, 0.511543, 0.280438,
+    0.543901, 0.297475, 0.342992, 0.354396, 0.362643, 0.601785, 0.697994, 0.563103, 0.562658,
+    0.550239, 0.536595, 0.522596, 0.512881, 0.272608, 0.273257, 0.327737, 0.339141, 0.347388,
+    0.502813, 0.474947, 0.442904, 0.415172, 0.384594, 0.344074, 0.307659, 0.283966, 0.261314,
+    0.063883, 0.239145, 0.237254, 0.231054, 0.379736, 0.243168, 0.341522, 0.340677, 0.344210,
+    0.346059, 0.334103, 0.326338, 0.312136, 0.231416, 0.117297, 0.309396, 0.315780, 0.381693,
+    0.241593, 0.343480, 0.342634, 0.346167, 0.345249, 0.344700, 0.336935, 0.322733, 0.229367,
+    0.295386, 0.120268, 0.319096, 0.372307, 0.236709, 0.335831, 0.334985, 0.347818, 0.344443,
+    0.341127, 0.344769, 0.330567, 0.223457, 0.301423, 0.304339, 0.120194, 0.500000, 0.248552,
+    0.243572, 0.584133, 0.663229, 0.306586, 0.559025, 0.568638, 0.578396, 0.581504, 0.596789,
+    0.595798, 0.599186, 0.467898, 0.357830, 0.349711, 0.580377, 0.659074, 0.527398, 0.300575,
+    0.555215, 0.564973, 0.568081, 0.583366, 0.595319, 0.595196, 0.437966, 0.356865, 0.348746,
+    0.591364, 0.671556, 0.536280, 0.523864, 0.297935, 0.551906, 0.555014, 0.570298, 0.582251,
+    0.595072, 0.403022, 0.359915, 0.361707, 0.602151, 0.686033, 0.545271, 0.532856, 0.520631,
+    0.295907, 0.545149, 0.557849, 0.569233, 0.582054, 0.368342, 0.362983, 0.362115, 0.595247,
+    0.696645, 0.548158, 0.535743, 0.523518, 0.514253, 0.285692, 0.543031, 0.554415, 0.567236,
+    0.326569, 0.361947, 0.358098, 0.597943, 0.697662, 0.562482, 0.550067, 0.537842, 0.526223,
+    0.512224, 0.281788, 0.544061, 0.556882, 0.299497, 0.353645, 0.361893, 0.600349, 0.700861,
+    0.561667, 0.561222, 0.548997, 0.536856, 0.522858, 0.513113, 0.273874, 0.541868, 0.274694,
+    0.338327, 0.346575, 0.603440, 0.704347, 0.564758, 0.561092, 0.560837, 0.548696, 0.534698,
+    0.524953, 0.510867, 0.272991, 0.262539, 0.339258, 0.347505, 0.502805, 0.474947, 0.442896,
+    0.415164, 0.382555, 0.350115, 0.311120, 0.285790, 0.262591, 0.251094, 0.060805, 0.235528,
+    0.229327, 0.378489, 0.238016, 0.340275, 0.339430, 0.342121, 0.344826, 0.343884, 0.336119,
+    0.321858, 0.322696, 0.227777, 0.115893, 0.315575, 0.369102, 0.233132, 0.332626, 0.331781,
+    0.343772, 0.344021, 0.340311, 0.343954, 0.329693, 0.330531, 0.221867, 0.301076, 0.115819,
+    0.500000, 0.239641, 0.584516, 0.667951, 0.303869, 0.559449, 0.569062, 0.575302, 0.582804,
+    0.595069, 0.593508, 0.596897, 0.600349, 0.467898, 0.346196, 0.580760, 0.663795, 0.527781,
+    0.297562, 0.555639, 0.561880, 0.569381, 0.581646, 0.593030, 0.592907, 0.596358, 0.437966,
+    0.345231, 0.591747, 0.676277, 0.536663, 0.524247, 0.294670, 0.548812, 0.556313, 0.568579,
+    0.579962, 0.592783, 0.592723, 0.403022, 0.358192, 0.599314, 0.687134, 0.542433, 0.530018,
+    0.517793, 0.291850, 0.543102, 0.555796, 0.567179, 0.580000, 0.592885, 0.366118, 0.357667,
+    0.596344, 0.701678, 0.549255, 0.536840, 0.524615, 0.512250, 0.287148, 0.545993, 0.553794,
+    0.566615, 0.579499, 0.332296, 0.357719, 0.596267, 0.699523, 0.560806, 0.548391, 0.536167,
+    0.524220, 0.515018, 0.283106, 0.543434, 0.556255, 0.569139, 0.302629, 0.361079, 0.598152,
+    0.702202, 0.559470, 0.559025, 0.546800, 0.534853, 0.522358, 0.512613, 0.275108, 0.541209,
+    0.554093, 0.276496, 0.345761, 0.601243, 0.705688, 0.562561, 0.558895, 0.558640, 0.546693,
+    0.534198, 0.524453, 0.510337, 0.274183, 0.557545, 0.263976, 0.346692, 0.604398, 0.709237,
+    0.565715, 0.562049, 0.558573, 0.558596, 0.546101, 0.536356, 0.522240, 0.525395, 0.273953,
+    0.254457, 0.347622, 0.502797, 0.474947, 0.442887, 0.415156, 0.382547, 0.348076, 0.316382,
+    0.288652, 0.264214, 0.252372, 0.243293, 0.058136, 0.227601, 0.365839, 0.229496, 0.329363,
+    0.328518, 0.340508, 0.339916, 0.339830, 0.343079, 0.328818, 0.329656, 0.330494, 0.220277,
+    0.111445, 0.500000, 0.596696, 0.688616, 0.306165, 0.569941, 0.579100, 0.587998, 0.591981,
+    0.608639, 0.603490, 0.606879, 0.610331, 0.613846, 0.469113, 0.592940, 0.684460, 0.537853,
+    0.299564, 0.565677, 0.574575, 0.578558, 0.595216, 0.603012, 0.602888, 0.606340, 0.609855,
+    0.439222, 0.601360, 0.681111, 0.546339, 0.533923, 0.304854, 0.551393, 0.555376, 0.572463,
+    0.580258, 0.593079, 0.593019, 0.596534, 0.418551, 0.609696, 0.695350, 0.554675, 0.542261,
+    0.520259, 0.303234, 0.545506, 0.562592, 0.570388, 0.583209, 0.596093, 0.596096, 0.379852,
+    0.603505, 0.706274, 0.558276, 0.545861, 0.523860, 0.514719, 0.297782, 0.549442, 0.557237,
+    0.570059, 0.582943, 0.595890, 0.343806, 0.607363, 0.708051, 0.573762, 0.561347, 0.539758,
+    0.530623, 0.518321, 0.299295, 0.548310, 0.561131, 0.574016, 0.586963, 0.320953, 0.605954,
+    0.707038, 0.569131, 0.568686, 0.547097, 0.537962, 0.525661, 0.517419, 0.291200, 0.546079,
+    0.558964, 0.571911, 0.293000, 0.609045, 0.710524, 0.572223, 0.568557, 0.558937, 0.549802,
+    0.537501, 0.529259, 0.515143, 0.290234, 0.562415, 0.575362, 0.279293, 0.612200, 0.714073,
+    0.575377, 0.571711, 0.558870, 0.561706, 0.549404, 0.541162, 0.527046, 0.530201, 0.290003,
+    0.578877, 0.269627, 0.615417, 0.717685, 0.578595, 0.574929, 0.562088, 0.561702, 0.561370,
+    0.553128, 0.539013, 0.542167, 0.545384, 0.290508, 0.264221, 0.502078, 0.474947, 0.444479,
+    0.416790, 0.396899, 0.360903, 0.327178, 0.305709, 0.279570, 0.266627, 0.257406, 0.252106,
+    0.072247, 0.500000, 0.467472, 0.156756, 0.418901, 0.393087, 0.361454, 0.354661, 0.339425,
+    0.327603, 0.318774, 0.312393, 0.307298, 0.286489, 0.416276, 0.443099, 0.401959, 0.164938,
+    0.398815, 0.388609, 0.386010, 0.384042, 0.383574, 0.380579, 0.379535, 0.379152, 0.364958,
+    0.390671, 0.421608, 0.377547, 0.373923, 0.178178, 0.383527, 0.383108, 0.381501, 0.381594,
+    0.383436, 0.381506, 0.381123, 0.381266, 0.373343, 0.409418, 0.347678, 0.364324, 0.359170,
+    0.186291, 0.375606, 0.375144, 0.378263, 0.380145, 0.383053, 0.381782, 0.379200, 0.351637,
+    0.406479, 0.341064, 0.361880, 0.358786, 0.351453, 0.187631, 0.372692, 0.376493, 0.378534,
+    0.381481, 0.385048, 0.381579, 0.334484, 0.394628, 0.326531, 0.359866, 0.357112, 0.350858,
+    0.348584, 0.185232, 0.370937, 0.373457, 0.376462, 0.380069, 0.381437, 0.325430, 0.392592,
+    0.315242, 0.359495, 0.357267, 0.353876, 0.352245, 0.346925, 0.188500, 0.370702, 0.374287,
+    0.377951, 0.379880, 0.317314, 0.390568, 0.306763, 0.356651, 0.358933, 0.355579, 0.354098,
+    0.349231, 0.346695, 0.188200, 0.375171, 0.378835, 0.380764, 0.311507, 0.389551, 0.300593,
+    0.355634, 0.357096, 0.358251, 0.356808, 0.351996, 0.350008, 0.350824, 0.188136, 0.379719,
+    0.381648, 0.306912, 0.389160, 0.295636, 0.355243, 0.356705, 0.357040, 0.360106, 0.355332,
+    0.353399, 0.354215, 0.355032, 0.188191, 0.382533, 0.288111, 0.377644, 0.276690, 0.341768,
+    0.357216, 0.354886, 0.357133, 0.356868, 0.355461, 0.356277, 0.357094, 0.357910, 0.173956,
+    0.500000, 0.173811, 0.423749, 0.400229, 0.380001, 0.355777, 0.355415, 0.343634, 0.334847,
+    0.328507, 0.323454, 0.302540, 0.502285, 0.417384, 0.406951, 0.169929, 0.409584, 0.401255,
+    0.395223, 0.397938, 0.397470, 0.394474, 0.393431, 0.393048, 0.378854, 0.478472, 0.391809,
+    0.384598, 0.384382, 0.183581, 0.396484, 0.392321, 0.395396, 0.395489, 0.397332, 0.395401,
+    0.395018, 0.395473, 0.464312, 0.374480, 0.365261, 0.376610, 0.371759, 0.194233, 0.384819,
+    0.389351, 0.392470, 0.394352, 0.397259, 0.395989, 0.393406, 0.445552, 0.362098, 0.342081,
+    0.370847, 0.367753, 0.360420, 0.199748, 0.373261, 0.386214, 0.388254, 0.391201, 0.394769,
+    0.391299, 0.439543, 0.346387, 0.341360, 0.373397, 0.370644, 0.364692, 0.349064, 0.201686,
+    0.384068, 0.386589, 0.389593, 0.393200, 0.394568, 0.437506, 0.337333, 0.330110, 0.373026,
+    0.370799, 0.367709, 0.361550, 0.359562, 0.204953, 0.383833, 0.387418, 0.391082, 0.393011,
+    0.435482, 0.329217, 0.321672, 0.370182, 0.372464, 0.369412, 0.363403, 0.361868, 0.359332,
+    0.204653, 0.388302, 0.391966, 0.393895, 0.434465, 0.323410, 0.315543, 0.369165, 0.370627,
+    0.372085, 0.366113, 0.364633, 0.362645, 0.363461, 0.204590, 0.392851, 0.394780, 0.434074,
+    0.318815, 0.310625, 0.368774, 0.370236, 0.370874, 0.369412, 0.367969, 0.366035, 0.366852,
+    0.367668, 0.204645, 0.395664, 0.424608, 0.300013, 0.291582, 0.355299, 0.371050, 0.368720,
+    0.366438, 0.369505, 0.368097, 0.368914, 0.369730, 0.370547, 0.190410, 0.500000, 0.634940,
+    0.647156, 0.657418, 0.667633, 0.677655, 0.674074, 0.676708, 0.679374, 0.682072, 0.669236,
+    0.669575, 0.661882, 0.600016, 0.185235, 0.528797, 0.532311, 0.535434, 0.535831, 0.547983,
+    0.546991, 0.549543, 0.552127, 0.546631, 0.670886, 0.663193, 0.611140, 0.503375, 0.185966,
+    0.519159, 0.522323, 0.522763, 0.534956, 0.545966, 0.545006, 0.547589, 0.553365, 0.670647,
+    0.662953, 0.620483, 0.506816, 0.494580, 0.187175, 0.509465, 0.509947, 0.522183, 0.533234,
+    0.544275, 0.543346, 0.546679, 0.662662, 0.662751, 0.629784, 0.509880, 0.497685, 0.485728,
+    0.188583, 0.496666, 0.509373, 0.520466, 0.531549, 0.542621, 0.542442, 0.656571, 0.654788,
+    0.638923, 0.510330, 0.498175, 0.486258, 0.473878, 0.186880, 0.496083, 0.507218, 0.518343,
+    0.529457, 0.541278, 0.655358, 0.653575, 0.635600, 0.521639, 0.509522, 0.497646, 0.485729,
+    0.473349, 0.190218, 0.495599, 0.506766, 0.517922, 0.529786, 0.657772, 0.655989, 0.637971,
+    0.520702, 0.519656, 0.507819, 0.495942, 0.483601, 0.472907, 0.190187, 0.510275, 0.521431,
+    0.533294, 0.660218, 0.658435, 0.640375, 0.523012, 0.518751, 0.517984, 0.506147, 0.493845,
+    0.483190, 0.486402, 0.190408, 0.524940, 0.536803, 0.662695, 0.660912, 0.642809, 0.525354,
+    0.521093, 0.517111, 0.516343, 0.504082, 0.493466, 0.496677, 0.499889, 0.190756, 0.540312,
+    0.660469, 0.658686, 0.631037, 0.520040, 0.526513, 0.520247, 0.516265, 0.515074, 0.504498,
+    0.507709, 0.510920, 0.514132, 0.186715, 0.500000, 0.376374, 0.356983, 0.346765, 0.340136,
+    0.327610, 0.322697, 0.316441, 0.311552, 0.289937, 0.518448, 0.508146, 0.475006, 0.141456,
+    0.363710, 0.347104, 0.334263, 0.315103, 0.320208, 0.312790, 0.306242, 0.301062, 0.279155,
+    0.470405, 0.456700, 0.359947, 0.350222, 0.149326, 0.369184, 0.368315, 0.367511, 0.373580,
+    0.376517, 0.374455, 0.374030, 0.374287, 0.455411, 0.441706, 0.341291, 0.334129, 0.352450,
+    0.161745, 0.363783, 0.364219, 0.370567, 0.374116, 0.377067, 0.375715, 0.372869, 0.438372,
+    0.435387, 0.331028, 0.321460, 0.351813, 0.347146, 0.173935, 0.356969, 0.364377, 0.371109,
+    0.374102, 0.377763, 0.373990, 0.426757, 0.421247, 0.324077, 0.302884, 0.351041, 0.347520,
+    0.340315, 0.180529, 0.347526, 0.364702, 0.367756, 0.371459, 0.372699, 0.423679, 0.418169,
+    0.312159, 0.307318, 0.356707, 0.353445, 0.347240, 0.331077, 0.183014, 0.360928, 0.364609,
+    0.368373, 0.370224, 0.424786, 0.419276, 0.307319, 0.300137, 0.359504, 0.356803, 0.353542,
+    0.347121, 0.343669, 0.187084, 0.369052, 0.372816, 0.374667, 0.423692, 0.418183, 0.301282,
+    0.293814, 0.357569, 0.359478, 0.356256, 0.349892, 0.347022, 0.351127, 0.187026, 0.373740,
+    0.375592, 0.423261, 0.417752, 0.296534, 0.288780, 0.357138, 0.358205, 0.359593, 0.353269,
+    0.350456, 0.354561, 0.355399, 0.187094, 0.376516, 0.413500, 0.407991, 0.276808, 0.268768,
+    0.358027, 0.356057, 0.356603, 0.354889, 0.352637, 0.356742, 0.357580, 0.358418, 0.171859,
+    0.500000, 0.340065, 0.330163, 0.327033, 0.314513, 0.305215, 0.302898, 0.298009, 0.293000,
+    0.561361, 0.548891, 0.590550, 0.300366, 0.437031, 0.390721, 0.386374, 0.387916, 0.393949,
+    0.387668, 0.390052, 0.389628, 0.379161, 0.519306, 0.508931, 0.475009, 0.415769, 0.119300,
+    0.324376, 0.313958, 0.295691, 0.300957, 0.294849, 0.290027, 0.284847, 0.279549, 0.443707,
+    0.430002, 0.325671, 0.372086, 0.313049, 0.141047, 0.343501, 0.346189, 0.352565, 0.357150,
+    0.361235, 0.359883, 0.359614, 0.427481, 0.423994, 0.315688, 0.368185, 0.302624, 0.328300,
+    0.153806, 0.341952, 0.349387, 0.354143, 0.358840, 0.362501, 0.361305, 0.418652, 0.412749,
+    0.311961, 0.369692, 0.284895, 0.330860, 0.326441, 0.163982, 0.342463, 0.347737, 0.355507,
+    0.359210, 0.363027, 0.415580, 0.409677, 0.300051, 0.375318, 0.289476, 0.336805, 0.333385,
+    0.326484, 0.166479, 0.344539, 0.352936, 0.356700, 0.360559, 0.412496, 0.406593, 0.291046,
+    0.369357, 0.283454, 0.340898, 0.337636, 0.331215, 0.328285, 0.164907, 0.352662, 0.356426,
+    0.360285, 0.414670, 0.408767, 0.288655, 0.371531, 0.278721, 0.344762, 0.342061, 0.338482,
+    0.336139, 0.335743, 0.169231, 0.360869, 0.364728, 0.414238, 0.408335, 0.283907, 0.371099,
+    0.273687, 0.343489, 0.345398, 0.341859, 0.339573, 0.339177, 0.343282, 0.169299, 0.365652,
+    0.407087, 0.399052, 0.279049, 0.361422, 0.268534, 0.343208, 0.344275, 0.345346, 0.343100,
+    0.342703, 0.346808, 0.347646, 0.169356, 0.500000, 0.315088, 0.312273, 0.299753, 0.293896,
+    0.287194, 0.286246, 0.277701, 0.574035, 0.561484, 0.608256, 0.300389, 0.546188, 0.435858,
+    0.375175, 0.377036, 0.383132, 0.380012, 0.378002, 0.381096, 0.370263, 0.587564, 0.575014,
+    0.623944, 0.516154, 0.293923, 0.395472, 0.366473, 0.368334, 0.374430, 0.383484, 0.378351,
+    0.381445, 0.380523, 0.520137, 0.509689, 0.475009, 0.414219, 0.376616, 0.102111, 0.296197,
+    0.279709, 0.285615, 0.279668, 0.276156, 0.272702, 0.263346, 0.421142, 0.415128, 0.301764,
+    0.357464, 0.349186, 0.286107, 0.137756, 0.325247, 0.332403, 0.339827, 0.345561, 0.350356,
+    0.345847, 0.412732, 0.404697, 0.298317, 0.359281, 0.351003, 0.270037, 0.310908, 0.148502,
+    0.328491, 0.336433, 0.342228, 0.347634, 0.348139, 0.409661, 0.401625, 0.286407, 0.364967,
+    0.356689, 0.275226, 0.317593, 0.313477, 0.150999, 0.333236, 0.339657, 0.345124, 0.345671,
+    0.409368, 0.401333, 0.280575, 0.361906, 0.365086, 0.269351, 0.324411, 0.320775, 0.317851,
+    0.153022, 0.339390, 0.347869, 0.348416, 0.407350, 0.399315, 0.274019, 0.359889, 0.360192,
+    0.265777, 0.329571, 0.325992, 0.323649, 0.323252, 0.151703, 0.347596, 0.348143, 0.410186,
+    0.402151, 0.272916, 0.362725, 0.363028, 0.262333, 0.334097, 0.331079, 0.328793, 0.331188,
+    0.330791, 0.156152, 0.352586, 0.398352, 0.390317, 0.264860, 0.352688, 0.362290, 0.253567,
+    0.330061, 0.331653, 0.329407, 0.331801, 0.331405, 0.335510, 0.153175, 0.500000, 0.297130,
+    0.284357, 0.279069, 0.275808, 0.270475, 0.265870, 0.577095, 0.565435, 0.617571, 0.300374,
+    0.552689, 0.561963, 0.434643, 0.365833, 0.371819, 0.369268, 0.370278, 0.368978, 0.361664,
+    0.590726, 0.578673, 0.632978, 0.521810, 0.293656, 0.547265, 0.394216, 0.357067, 0.363054,
+    0.372600, 0.370627, 0.369328, 0.371924, 0.601184, 0.589130, 0.646976, 0.530472, 0.516579,
+    0.287369, 0.356761, 0.348018, 0.354004, 0.363550, 0.373752, 0.369329, 0.369266, 0.511082,
+    0.510408, 0.475008, 0.412628, 0.374984, 0.340038, 0.085295, 0.262562, 0.269888, 0.264327,
+    0.260976, 0.258832, 0.251203, 0.398304, 0.398294, 0.284330, 0.348556, 0.340219, 0.331634,
+    0.254069, 0.132449, 0.311182, 0.319383, 0.327846, 0.334289, 0.335928, 0.395131, 0.395121,
+    0.272180, 0.354140, 0.345803, 0.337219, 0.260601, 0.297368, 0.134947, 0.316185, 0.325275,
+    0.331779, 0.333459, 0.395359, 0.395350, 0.266868, 0.351601, 0.354671, 0.346087, 0.255095,
+    0.304919, 0.301995, 0.137539, 0.328021, 0.334525, 0.336775, 0.396133, 0.396123, 0.263484,
+    0.352375, 0.352678, 0.355551, 0.251668, 0.312703, 0.310360, 0.312755, 0.139814, 0.334257,
+    0.339520, 0.394778, 0.394768, 0.258217, 0.351020, 0.351323, 0.351319, 0.249384, 0.318525,
+    0.316239, 0.318634, 0.318237, 0.138621, 0.339247, 0.386211, 0.386201, 0.253806, 0.344249,
+    0.353852, 0.351392, 0.242208, 0.320288, 0.318042, 0.320958, 0.323352, 0.322956, 0.140026,
+    0.500000, 0.269157, 0.263616, 0.260924, 0.259032, 0.250042, 0.574857, 0.562760, 0.623585,
+    0.300325, 0.553244, 0.562198, 0.571539, 0.433462, 0.360551, 0.358319, 0.359898, 0.361618,
+    0.349910, 0.588031, 0.575934, 0.635758, 0.521989, 0.293354, 0.547469, 0.556809, 0.392920,
+    0.351785, 0.361221, 0.359818, 0.361538, 0.359741, 0.598590, 0.586493, 0.649475, 0.530359,
+    0.516437, 0.286858, 0.542396, 0.355382, 0.342672, 0.352109, 0.362802, 0.361540, 0.357084,
+    0.594884, 0.597018, 0.663540, 0.539087, 0.525166, 0.511551, 0.280895, 0.319949, 0.333627,
+    0.343063, 0.353757, 0.364669, 0.357090, 0.504101, 0.501344, 0.475008, 0.411079, 0.373321,
+    0.338294, 0.305166, 0.068876, 0.253062, 0.248846, 0.245629, 0.243645, 0.237326, 0.384176,
+    0.380632, 0.258131, 0.343354, 0.335016, 0.326373, 0.317788, 0.244934, 0.118892, 0.298132,
+    0.307590, 0.316763, 0.320050, 0.384303, 0.380759, 0.252579, 0.341107, 0.343783, 0.335140,
+    0.326555, 0.240698, 0.285202, 0.121485, 0.310905, 0.320078, 0.323365, 0.385599, 0.382054,
+    0.249715, 0.342402, 0.342311, 0.345075, 0.336491, 0.237401, 0.293922, 0.296838, 0.124330,
+    0.322824, 0.326111, 0.387035, 0.383490, 0.247620, 0.343838, 0.343747, 0.343744, 0.346617,
+    0.235263, 0.302368, 0.305284, 0.307678, 0.126731, 0.325844, 0.374277, 0.370732, 0.239045,
+    0.332877, 0.342086, 0.339626, 0.339622, 0.229247, 0.305427, 0.308343, 0.310737, 0.310340,
+    0.122493, 0.500000, 0.262212, 0.259267, 0.257943, 0.252963, 0.583957, 0.571860, 0.635457,
+    0.309031, 0.564161, 0.569961, 0.578413, 0.585066, 0.442996, 0.362167, 0.363636, 0.365925,
+    0.357806, 0.594231, 0.582134, 0.647630, 0.532319, 0.301796, 0.555225, 0.563677, 0.570330,
+    0.399447, 0.365063, 0.363551, 0.365840, 0.367632, 0.604268, 0.592171, 0.661347, 0.537789,
+    0.523866, 0.295089, 0.549233, 0.555885, 0.358792, 0.355950, 0.366534, 0.365841, 0.364974,
+    0.600664, 0.602386, 0.675651, 0.545685, 0.531763, 0.518119, 0.288389, 0.541111, 0.325773,
+    0.346272, 0.356856, 0.368260, 0.364410, 0.599900, 0.598739, 0.689781, 0.551830, 0.537908,
+    0.524264, 0.510295, 0.279095, 0.296919, 0.333640, 0.344225, 0.355628, 0.363812, 0.506164,
+    0.503408, 0.475008, 0.419963, 0.379445, 0.341523, 0.310616, 0.283548, 0.071762, 0.247646,
+    0.246026, 0.244177, 0.238018, 0.385476, 0.381931, 0.251439, 0.344811, 0.347488, 0.338844,
+    0.329679, 0.317783, 0.239619, 0.123246, 0.304061, 0.313602, 0.319986, 0.386670, 0.383125,
+    0.248336, 0.346005, 0.345914, 0.348678, 0.339513, 0.327617, 0.237832, 0.290663, 0.126091,
+    0.316917, 0.323301, 0.388627, 0.385083, 0.246761, 0.347962, 0.347872, 0.347868, 0.350111,
+    0.338214, 0.235823, 0.299463, 0.302379, 0.129062, 0.326617, 0.379182, 0.375637, 0.241878,
+    0.340313, 0.349522, 0.347062, 0.346538, 0.345989, 0.229954, 0.305501, 0.308417, 0.311332,
+    0.128988, 0.500000, 0.255462, 0.254138, 0.249159, 0.582844, 0.570748, 0.640292, 0.305500,
+    0.563971, 0.569765, 0.578217, 0.584979, 0.600787, 0.440772, 0.359314, 0.361603, 0.353484,
+    0.597052, 0.584956, 0.656396, 0.532041, 0.303694, 0.560044, 0.565483, 0.571676, 0.587484,
+    0.406595, 0.363298, 0.365587, 0.367379, 0.604317, 0.592221, 0.666942, 0.537511, 0.528385,
+    0.296764, 0.551033, 0.557225, 0.573033, 0.363502, 0.365847, 0.365154, 0.364287, 0.600191,
+    0.601914, 0.680726, 0.545407, 0.533509, 0.519865, 0.289896, 0.542419, 0.558656, 0.328013,
+    0.356169, 0.367573, 0.363723, 0.599529, 0.598368, 0.695095, 0.551653, 0.539235, 0.525591,
+    0.511592, 0.280476, 0.543941, 0.298355, 0.343474, 0.354878, 0.363125, 0.602728, 0.601567,
+    0.696473, 0.566480, 0.554061, 0.540417, 0.526836, 0.512930, 0.276599, 0.278118, 0.331737,
+    0.343141, 0.351388, 0.506156, 0.503400, 0.475008, 0.417923, 0.386006, 0.345844, 0.312643,
+    0.284826, 0.265860, 0.068274, 0.244300, 0.242451, 0.236292, 0.383525, 0.379980, 0.244819,
+    0.342018, 0.345551, 0.347921, 0.338757, 0.326801, 0.315866, 0.236242, 0.121716, 0.313396,
+    0.319780, 0.385482, 0.381938, 0.243244, 0.343976, 0.347508, 0.347111, 0.349354, 0.337398,
+    0.326463, 0.234233, 0.299116, 0.124687, 0.323096, 0.376037, 0.372492, 0.238360, 0.336327,
+    0.349159, 0.346306, 0.345781, 0.345233, 0.334297, 0.228364, 0.305153, 0.308069, 0.124613,
+    0.500000, 0.250270, 0.245290, 0.583888, 0.571791, 0.646300, 0.302519, 0.561587, 0.571774,
+    0.577207, 0.583400, 0.599317, 0.599194, 0.440772, 0.358151, 0.350032, 0.594875, 0.582778,
+    0.658784, 0.529864, 0.299879, 0.558707, 0.564140, 0.570332, 0.586250, 0.599071, 0.404370,
+    0.361201, 0.362994, 0.606074, 0.593977, 0.673262, 0.539267, 0.527042, 0.298421, 0.554704,
+    0.557884, 0.573232, 0.586053, 0.370082, 0.364838, 0.363971, 0.599176, 0.600898, 0.683874,
+    0.544391, 0.532167, 0.523319, 0.291372, 0.543071, 0.558848, 0.571669, 0.331792, 0.366822,
+    0.362973, 0.597992, 0.596831, 0.697723, 0.550116, 0.537892, 0.526272, 0.512274, 0.281825,
+    0.544101, 0.556922, 0.300377, 0.354127, 0.362374, 0.601292, 0.600131, 0.699340, 0.565044,
+    0.552819, 0.540678, 0.527097, 0.513162, 0.277864, 0.542842, 0.279555, 0.342327, 0.350575,
+    0.604383, 0.603222, 0.702826, 0.564914, 0.564659, 0.552518, 0.538938, 0.525002, 0.511810,
+    0.276982, 0.267442, 0.343258, 0.351505, 0.506148, 0.503392, 0.475008, 0.417915, 0.383967,
+    0.351886, 0.316104, 0.286651, 0.267138, 0.255681, 0.065196, 0.240725, 0.234566, 0.382278,
+    0.378733, 0.239667, 0.340771, 0.343463, 0.346689, 0.348538, 0.336582, 0.325588, 0.326426,
+    0.232643, 0.120312, 0.319575, 0.372832, 0.369288, 0.234783, 0.333123, 0.345113, 0.345883,
+    0.344965, 0.344417, 0.333422, 0.334261, 0.226774, 0.304806, 0.120239, 0.500000, 0.241359,
+    0.584271, 0.572174, 0.651022, 0.299507, 0.562012, 0.568681, 0.578507, 0.581680, 0.597028,
+    0.596905, 0.600357, 0.440772, 0.346517, 0.595258, 0.583162, 0.663506, 0.530247, 0.296615,
+    0.555613, 0.565439, 0.568613, 0.583961, 0.596782, 0.596721, 0.404370, 0.359479, 0.603236,
+    0.591140, 0.674362, 0.536430, 0.524205, 0.294364, 0.552656, 0.555830, 0.571178, 0.583999,
+    0.596883, 0.367858, 0.359522, 0.600273, 0.601995, 0.688907, 0.545488, 0.533263, 0.521316,
+    0.292828, 0.546033, 0.558227, 0.571048, 0.583932, 0.337519, 0.362594, 0.596317, 0.595155,
+    0.699584, 0.548441, 0.536216, 0.524269, 0.515067, 0.283143, 0.543474, 0.556295, 0.569179,
+    0.303509, 0.361561, 0.599095, 0.597934, 0.700681, 0.562846, 0.550622, 0.538675, 0.526597,
+    0.512662, 0.279098, 0.542184, 0.555068, 0.281357, 0.349761, 0.602186, 0.601025, 0.704167,
+    0.562717, 0.562462, 0.550515, 0.538438, 0.524502, 0.511280, 0.278174, 0.558519, 0.268878,
+    0.350692, 0.605341, 0.604179, 0.707716, 0.565871, 0.562395, 0.562418, 0.550341, 0.536405,
+    0.523183, 0.526337, 0.277943, 0.259401, 0.351622, 0.506140, 0.503383, 0.475008, 0.417907,
+    0.383959, 0.349847, 0.321366, 0.289512, 0.268760, 0.256959, 0.247921, 0.062527, 0.232840,
+    0.369569, 0.366024, 0.231146, 0.329859, 0.341850, 0.341778, 0.344484, 0.343542, 0.332548,
+    0.333386, 0.334224, 0.225184, 0.115864, 0.500000, 0.596450, 0.584354, 0.671687, 0.301509,
+    0.572478, 0.581376, 0.587684, 0.595250, 0.607010, 0.606887, 0.610338, 0.613853, 0.441944,
+    0.605282, 0.593249, 0.668339, 0.540334, 0.307368, 0.558623, 0.564931, 0.572497, 0.584257,
+    0.597078, 0.597017, 0.600532, 0.420468, 0.613619, 0.601585, 0.682579, 0.548672, 0.527082,
+    0.305748, 0.555060, 0.562627, 0.574386, 0.587207, 0.600091, 0.600094, 0.381592, 0.607434,
+    0.609219, 0.693503, 0.554509, 0.532920, 0.523785, 0.303462, 0.549483, 0.561671, 0.574492,
+    0.587376, 0.600323, 0.349029, 0.607413, 0.606315, 0.708112, 0.561396, 0.539807, 0.530672,
+    0.518371, 0.299333, 0.548351, 0.561172, 0.574056, 0.587003, 0.321833, 0.606897, 0.605799,
+    0.705517, 0.572508, 0.550919, 0.541784, 0.529901, 0.517468, 0.295191, 0.547054, 0.559938,
+    0.572885, 0.297861, 0.609988, 0.608890, 0.709003, 0.572379, 0.562759, 0.553624, 0.541741,
+    0.529308, 0.516086, 0.294224, 0.563389, 0.576337, 0.284196, 0.613143, 0.612045, 0.712552,
+    0.575533, 0.562692, 0.565527, 0.553644, 0.541211, 0.527989, 0.531144, 0.293994, 0.579851,
+    0.274571, 0.616360, 0.615262, 0.716164, 0.578750, 0.565910, 0.565524, 0.565610, 0.553177,
+    0.539956, 0.543110, 0.546327, 0.294499, 0.269207, 0.505309, 0.502625, 0.475008, 0.419459,
+    0.398831, 0.362674, 0.332162, 0.306569, 0.284116, 0.271215, 0.262033, 0.256775, 0.076639,
+    0.500000, 0.467476, 0.458227, 0.155170, 0.387428, 0.358881, 0.353175, 0.338093, 0.327591,
+    0.320344, 0.313963, 0.308868, 0.288059, 0.391737, 0.422875, 0.411324, 0.372430, 0.179555,
+    0.380675, 0.379794, 0.380660, 0.382057, 0.384857, 0.382927, 0.382543, 0.382687, 0.374655,
+    0.410973, 0.399423, 0.345393, 0.356640, 0.188189, 0.375059, 0.377070, 0.378727, 0.382087,
+    0.384995, 0.383724, 0.381142, 0.352950, 0.408093, 0.396543, 0.339809, 0.355820, 0.351105,
+    0.189530, 0.374618, 0.376957, 0.380476, 0.383423, 0.386991, 0.383521, 0.338699, 0.398965,
+    0.398627, 0.325419, 0.356556, 0.352919, 0.350645, 0.190449, 0.371407, 0.378172, 0.381176,
+    0.384783, 0.386152, 0.325938, 0.393096, 0.389946, 0.315278, 0.357712, 0.354320, 0.352690,
+    0.347370, 0.188525, 0.371171, 0.374756, 0.378420, 0.380350, 0.321146, 0.394125, 0.390975,
+    0.308250, 0.360407, 0.357541, 0.356060, 0.353810, 0.347140, 0.192275, 0.378861, 0.382525,
+    0.384455, 0.315339, 0.393108, 0.389958, 0.302081, 0.358569, 0.360213, 0.358770, 0.356575,
+    0.350453, 0.354322, 0.192212, 0.383410, 0.385339, 0.310744, 0.392717, 0.389567, 0.297123,
+    0.358178, 0.359002, 0.362069, 0.359911, 0.353843, 0.357713, 0.358530, 0.192267, 0.386223,
+    0.291943, 0.381201, 0.378051, 0.278178, 0.358690, 0.356849, 0.359095, 0.361447, 0.355905,
+    0.359775, 0.360592, 0.361408, 0.178032, 0.500000, 0.468192, 0.172300, 0.394567, 0.377425,
+    0.354296, 0.354080, 0.343619, 0.336414, 0.330074, 0.325020, 0.304107, 0.479794, 0.392813,
+    0.423437, 0.379476, 0.184956, 0.393630, 0.391454, 0.394553, 0.395950, 0.398750, 0.396820,
+    0.396437, 0.396891, 0.465923, 0.375730, 0.411615, 0.362971, 0.369226, 0.196129, 0.386719,
+    0.391274, 0.392931, 0.396292, 0.399199, 0.397929, 0.395346, 0.449885, 0.366308, 0.407894,
+    0.340822, 0.367172, 0.362456, 0.204962, 0.384342, 0.386681, 0.392967, 0.395914, 0.399481,
+    0.396012, 0.443875, 0.350597, 0.410407, 0.340243, 0.370084, 0.366750, 0.359948, 0.206900,
+    0.384536, 0.391301, 0.394305, 0.397912, 0.399281, 0.438007, 0.337836, 0.401726, 0.330143,
+    0.371240, 0.368151, 0.361992, 0.360004, 0.204976, 0.384300, 0.387885, 0.391549, 0.393479,
+    0.439036, 0.333044, 0.402755, 0.323155, 0.373935, 0.371371, 0.367979, 0.366444, 0.359773,
+    0.208727, 0.391990, 0.395654, 0.397583, 0.438019, 0.327237, 0.401738, 0.317026, 0.372097,
+    0.374044, 0.370689, 0.369209, 0.363086, 0.366956, 0.208663, 0.396539, 0.398468, 0.437628,
+    0.322642, 0.401347, 0.312108, 0.371706, 0.372833, 0.373988, 0.372545, 0.366477, 0.370346,
+    0.371163, 0.208718, 0.399352, 0.428162, 0.303841, 0.389831, 0.293065, 0.372520, 0.370679,
+    0.371014, 0.374081, 0.368539, 0.372408, 0.373225, 0.374041, 0.194483, 0.500000, 0.189354,
+    0.399694, 0.384544, 0.372820, 0.355173, 0.359586, 0.352422, 0.346124, 0.341112, 0.320157,
+    0.493597, 0.480269, 0.393920, 0.384712, 0.189946, 0.404335, 0.404036, 0.403702, 0.409782,
+    0.412582, 0.410651, 0.410268, 0.410723, 0.479755, 0.466428, 0.376866, 0.370003, 0.379624,
+    0.201531, 0.399612, 0.400423, 0.406762, 0.410123, 0.413031, 0.411760, 0.409178, 0.463717,
+    0.460737, 0.367444, 0.358386, 0.379398, 0.374985, 0.212904, 0.393491, 0.400824, 0.407109,
+    0.410056, 0.413624, 0.410154, 0.452878, 0.447429, 0.361056, 0.341241, 0.378991, 0.375656,
+    0.368854, 0.219016, 0.385041, 0.400958, 0.403962, 0.407569, 0.408937, 0.450038, 0.444589,
+    0.349737, 0.344952, 0.384711, 0.381622, 0.375765, 0.360423, 0.221429, 0.397367, 0.400952,
+    0.404617, 0.406546, 0.451067, 0.445619, 0.344945, 0.338005, 0.387406, 0.384842, 0.381753,
+    0.375689, 0.372350, 0.225180, 0.405057, 0.408722, 0.410651, 0.450050, 0.444601, 0.339138,
+    0.331916, 0.385569, 0.387515, 0.384463, 0.378454, 0.375663, 0.379532, 0.225116, 0.409606,
+    0.411535, 0.449659, 0.444210, 0.334543, 0.327039, 0.385178, 0.386304, 0.387761, 0.381790,
+    0.379053, 0.382923, 0.383739, 0.225171, 0.412419, 0.440193, 0.434745, 0.315742, 0.307955,
+    0.385991, 0.384150, 0.384788, 0.383326, 0.381115, 0.384985, 0.385801, 0.386618, 0.210936,
+    0.500000, 0.637612, 0.648168, 0.658761, 0.669305, 0.679088, 0.680320, 0.683281, 0.686272,
+    0.673732, 0.679715, 0.672022, 0.664329, 0.602550, 0.186407, 0.531455, 0.535259, 0.538669,
+    0.538781, 0.555298, 0.554591, 0.557427, 0.563455, 0.679751, 0.672058, 0.664365, 0.612171,
+    0.505891, 0.187616, 0.522402, 0.525853, 0.526008, 0.542567, 0.553860, 0.553184, 0.556769,
+    0.672043, 0.672131, 0.664438, 0.621827, 0.509604, 0.497648, 0.189594, 0.513001, 0.513198,
+    0.529799, 0.541134, 0.552459, 0.552532, 0.666240, 0.664457, 0.664546, 0.631439, 0.512939,
+    0.501023, 0.489067, 0.191638, 0.499920, 0.516992, 0.528369, 0.539736, 0.551809, 0.659936,
+    0.658153, 0.656370, 0.640369, 0.513137, 0.501260, 0.489344, 0.476964, 0.190441, 0.499692,
+    0.511111, 0.522520, 0.534635, 0.663179, 0.661396, 0.659613, 0.641455, 0.528450, 0.516612,
+    0.504736, 0.492819, 0.476758, 0.194587, 0.515630, 0.527039, 0.539154, 0.665900, 0.664117,
+    0.662334, 0.644134, 0.527781, 0.527014, 0.515177, 0.503300, 0.487278, 0.491453, 0.194808,
+    0.530547, 0.542663, 0.668653, 0.666870, 0.665087, 0.646845, 0.530359, 0.526377, 0.525610,
+    0.513773, 0.497791, 0.501966, 0.505177, 0.195155, 0.546172, 0.666703, 0.664920, 0.663137,
+    0.635349, 0.536016, 0.529751, 0.525769, 0.525001, 0.509059, 0.513234, 0.516445, 0.519656,
+    0.191114, 0.500000, 0.341269, 0.331399, 0.328277, 0.326740, 0.319091, 0.316774, 0.311886,
+    0.306876, 0.546343, 0.541885, 0.533223, 0.475044, 0.137789, 0.329961, 0.321989, 0.316033,
+    0.302007, 0.312031, 0.307250, 0.302112, 0.296855, 0.458235, 0.444530, 0.430825, 0.327295,
+    0.318821, 0.146354, 0.354906, 0.359546, 0.362278, 0.371846, 0.375931, 0.374579, 0.374310,
+    0.442041, 0.438553, 0.424848, 0.317343, 0.310628, 0.339264, 0.159542, 0.355633, 0.359101,
+    0.368839, 0.373536, 0.377197, 0.376000, 0.433217, 0.427314, 0.424329, 0.313617, 0.304298,
+    0.343703, 0.339601, 0.172368, 0.352183, 0.362762, 0.370532, 0.374235, 0.378052, 0.424970,
+    0.419067, 0.413557, 0.311402, 0.290498, 0.346198, 0.342777, 0.335877, 0.179468, 0.344945,
+    0.363268, 0.367032, 0.370891, 0.425116, 0.419213, 0.413703, 0.304036, 0.299526, 0.355087,
+    0.351826, 0.345722, 0.328671, 0.182473, 0.366665, 0.370429, 0.374288, 0.427289, 0.421386,
+    0.415877, 0.301644, 0.294833, 0.358951, 0.356250, 0.352989, 0.345781, 0.348925, 0.186796,
+    0.374872, 0.378731, 0.426858, 0.420955, 0.415446, 0.296897, 0.289840, 0.357679, 0.359588,
+    0.356365, 0.349215, 0.352359, 0.356464, 0.186864, 0.379655, 0.419706, 0.411671, 0.405768,
+    0.292039, 0.284729, 0.357397, 0.358465, 0.359852, 0.352741, 0.355886, 0.359991, 0.360829,
+    0.186922, 0.500000, 0.316261, 0.313454, 0.315417, 0.307773, 0.301071, 0.300122, 0.291578,
+    0.599850, 0.587299, 0.574892, 0.623238, 0.302047, 0.403719, 0.379647, 0.381597, 0.386785,
+    0.396317, 0.391184, 0.394278, 0.393356, 0.547256, 0.542684, 0.533939, 0.475045, 0.384828,
+    0.120683, 0.304228, 0.300051, 0.286665, 0.296849, 0.293379, 0.289967, 0.280652, 0.435701,
+    0.429688, 0.415983, 0.303360, 0.361855, 0.294111, 0.143492, 0.338928, 0.344758, 0.354523,
+    0.360257, 0.365052, 0.360543, 0.427298, 0.419263, 0.415775, 0.299913, 0.363754, 0.289440,
+    0.324068, 0.156888, 0.340853, 0.351458, 0.357253, 0.362659, 0.363164, 0.421835, 0.413800,
+    0.407897, 0.300922, 0.368628, 0.276248, 0.329533, 0.325417, 0.167571, 0.343568, 0.349990,
+    0.358469, 0.359016, 0.421987, 0.413952, 0.408049, 0.293564, 0.377479, 0.285422, 0.338600,
+    0.335281, 0.327493, 0.170587, 0.353392, 0.361872, 0.362419, 0.419970, 0.411935, 0.406032,
+    0.287008, 0.372584, 0.281890, 0.343760, 0.340498, 0.333291, 0.336435, 0.169268, 0.361599,
+    0.362146, 0.422806, 0.414771, 0.408868, 0.285906, 0.375420, 0.278487, 0.348286, 0.345585,
+    0.341220, 0.344370, 0.343974, 0.173717, 0.366588, 0.410972, 0.402937, 0.397034, 0.277850,
+    0.374682, 0.269762, 0.344250, 0.346159, 0.341833, 0.344984, 0.344587, 0.348692, 0.170740,
+    0.500000, 0.298311, 0.300589, 0.292945, 0.289685, 0.284351, 0.279746, 0.603012, 0.590958,
+    0.578471, 0.632242, 0.301780, 0.561874, 0.402546, 0.370331, 0.375837, 0.385433, 0.383460,
+    0.382161, 0.384757, 0.613532, 0.601479, 0.588991, 0.646305, 0.530633, 0.295494, 0.362090,
+    0.361281, 0.366788, 0.376383, 0.386585, 0.382162, 0.382100, 0.544231, 0.543515, 0.534697,
+    0.475045, 0.383277, 0.345551, 0.103910, 0.282904, 0.270938, 0.281509, 0.278199, 0.276097,
+    0.268509, 0.412869, 0.412859, 0.406846, 0.285926, 0.352970, 0.344385, 0.273481, 0.140835,
+    0.323972, 0.334408, 0.342871, 0.349314, 0.350953, 0.407827, 0.407817, 0.399782, 0.287215,
+    0.358154, 0.349570, 0.261624, 0.309719, 0.152087, 0.329531, 0.338620, 0.345125, 0.347375,
+    0.407979, 0.407969, 0.399934, 0.279857, 0.367064, 0.358479, 0.271167, 0.319426, 0.314422,
+    0.155104, 0.342023, 0.348527, 0.350777, 0.408753, 0.408743, 0.400708, 0.276473, 0.365070,
+    0.367944, 0.267781, 0.327210, 0.322787, 0.325937, 0.157379, 0.348260, 0.353523, 0.407398,
+    0.407388, 0.399353, 0.271207, 0.363715, 0.363712, 0.265537, 0.333031, 0.328666, 0.331816,
+    0.331420, 0.156186, 0.353250, 0.398831, 0.398821, 0.390786, 0.266795, 0.366244, 0.363784,
+    0.258402, 0.334795, 0.330990, 0.334140, 0.336535, 0.336138, 0.157591, 0.500000, 0.285380,
+    0.277484, 0.274792, 0.272900, 0.263910, 0.600317, 0.588220, 0.576623, 0.635022, 0.301479,
+    0.562078, 0.571419, 0.401332, 0.364569, 0.374055, 0.372652, 0.374371, 0.372575, 0.610938,
+    0.598842, 0.586851, 0.648804, 0.530490, 0.294982, 0.557006, 0.360834, 0.355456, 0.364942,
+    0.375635, 0.374373, 0.369917, 0.607233, 0.609367, 0.597376, 0.662869, 0.539219, 0.525605,
+    0.289020, 0.327940, 0.346411, 0.355896, 0.366590, 0.377502, 0.369923, 0.537258, 0.536100,
+    0.535415, 0.475044, 0.381685, 0.343918, 0.313134, 0.087507, 0.254120, 0.266035, 0.262860,
+    0.260918, 0.254640, 0.396872, 0.393327, 0.393317, 0.273166, 0.347368, 0.338724, 0.330140,
+    0.245956, 0.136033, 0.312047, 0.321505, 0.330678, 0.333965, 0.396923, 0.393378, 0.393369,
+    0.265569, 0.356176, 0.347532, 0.338948, 0.256778, 0.298150, 0.139050, 0.324908, 0.334081,
+    0.337368, 0.398218, 0.394674, 0.394664, 0.262705, 0.354704, 0.357468, 0.348883, 0.253522,
+    0.306870, 0.310020, 0.141895, 0.336827, 0.340114, 0.399654, 0.396110, 0.396100, 0.260610,
+    0.356140, 0.356136, 0.359010, 0.251425, 0.315316, 0.318466, 0.320861, 0.144296, 0.339846,
+    0.386896, 0.383352, 0.383342, 0.252034, 0.354478, 0.352018, 0.352015, 0.245450, 0.318375,
+    0.321525, 0.323919, 0.323523, 0.140059, 0.500000, 0.262220, 0.259275, 0.257951, 0.252972,
+    0.594713, 0.582616, 0.570583, 0.636303, 0.301714, 0.558988, 0.568009, 0.577415, 0.400151,
+    0.362722, 0.361638, 0.363928, 0.365720, 0.604878, 0.592781, 0.580748, 0.646849, 0.527305,
+    0.295007, 0.553564, 0.562970, 0.359538, 0.353609, 0.364194, 0.363500, 0.362633, 0.601274,
+    0.603407, 0.591374, 0.660633, 0.535741, 0.522097, 0.288877, 0.548625, 0.326560, 0.344501,
+    0.355085, 0.366489, 0.362639, 0.600928, 0.599767, 0.601964, 0.674763, 0.544535, 0.530891,
+    0.517340, 0.283318, 0.297753, 0.335458, 0.346042, 0.357445, 0.365630, 0.530276, 0.529118,
+    0.526612, 0.475044, 0.380136, 0.342255, 0.311389, 0.284362, 0.071782, 0.248743, 0.247164,
+    0.245357, 0.239240, 0.385927, 0.382382, 0.378838, 0.251476, 0.345348, 0.336704, 0.328061,
+    0.319476, 0.240682, 0.123284, 0.304473, 0.314014, 0.320399, 0.387121, 0.383576, 0.380032,
+    0.248373, 0.344168, 0.346538, 0.337895, 0.329310, 0.238936, 0.291055, 0.126128, 0.317329,
+    0.323714, 0.389078, 0.385534, 0.381989, 0.246798, 0.346125, 0.345728, 0.348492, 0.339908,
+    0.236969, 0.299855, 0.302771, 0.129099, 0.327029, 0.379633, 0.376088, 0.372543, 0.241914,
+    0.347776, 0.344923, 0.344919, 0.347683, 0.231140, 0.305893, 0.308809, 0.311724, 0.129026,
+    0.500000, 0.257180, 0.255856, 0.250877, 0.600545, 0.588448, 0.576415, 0.643599, 0.305627,
+    0.566405, 0.572272, 0.580790, 0.587506, 0.409116, 0.364509, 0.366798, 0.368590, 0.607809,
+    0.595713, 0.583680, 0.654146, 0.534366, 0.298697, 0.557822, 0.566339, 0.573055, 0.364850,
+    0.367058, 0.366365, 0.365498, 0.603684, 0.605818, 0.593784, 0.667930, 0.539903, 0.526259,
+    0.292398, 0.551962, 0.558678, 0.329752, 0.357949, 0.369353, 0.365503, 0.603440, 0.602279,
+    0.604064, 0.682299, 0.547864, 0.534220, 0.520640, 0.286144, 0.543969, 0.303577, 0.348274,
+    0.359677, 0.367924, 0.602759, 0.601598, 0.600500, 0.696509, 0.554092, 0.540448, 0.526867,
+    0.512961, 0.276624, 0.278997, 0.332144, 0.343547, 0.351794, 0.532331, 0.531173, 0.528667,
+    0.475044, 0.388492, 0.347251, 0.314408, 0.289804, 0.266715, 0.072657, 0.249381, 0.247573,
+    0.241456, 0.387236, 0.383692, 0.380147, 0.246445, 0.346815, 0.349185, 0.340542, 0.331377,
+    0.316251, 0.240991, 0.126123, 0.317320, 0.323705, 0.389194, 0.385649, 0.382104, 0.244870,
+    0.348772, 0.348375, 0.351139, 0.341974, 0.326849, 0.239024, 0.302768, 0.129094, 0.327020,
+    0.379748, 0.376203, 0.372659, 0.239986, 0.350423, 0.347570, 0.347566, 0.349809, 0.334683,
+    0.233195, 0.308806, 0.311721, 0.129020, 0.500000, 0.251988, 0.247008, 0.598367, 0.586271,
+    0.574237, 0.645987, 0.301812, 0.565067, 0.570929, 0.579446, 0.586271, 0.603057, 0.406891,
+    0.362412, 0.364204, 0.609566, 0.597470, 0.585436, 0.660466, 0.533024, 0.300353, 0.561493,
+    0.566997, 0.573253, 0.590039, 0.371430, 0.366049, 0.365182, 0.602668, 0.604802, 0.592769,
+    0.671078, 0.538560, 0.529713, 0.293874, 0.552614, 0.558870, 0.575656, 0.333532, 0.368603,
+    0.364753, 0.601903, 0.600742, 0.602527, 0.684927, 0.546521, 0.534902, 0.521321, 0.287493,
+    0.544129, 0.561343, 0.305599, 0.358927, 0.367174, 0.601323, 0.600162, 0.599064, 0.699376,
+    0.552850, 0.540709, 0.527128, 0.513193, 0.277890, 0.542870, 0.280433, 0.342734, 0.350981,
+    0.605308, 0.604147, 0.603049, 0.701280, 0.568463, 0.556322, 0.542741, 0.529223, 0.511840,
+    0.280960, 0.272302, 0.347182, 0.355430, 0.532323, 0.531165, 0.528659, 0.475044, 0.386453,
+    0.353293, 0.317870, 0.291629, 0.267992, 0.260222, 0.069579, 0.245847, 0.239730, 0.385989,
+    0.382445, 0.378900, 0.241293, 0.344726, 0.347953, 0.350323, 0.341159, 0.325974, 0.330079,
+    0.237434, 0.124719, 0.323499, 0.376544, 0.372999, 0.369454, 0.236409, 0.346377, 0.347147,
+    0.346750, 0.348993, 0.333808, 0.337913, 0.231605, 0.308458, 0.124646, 0.500000, 0.243077,
+    0.598751, 0.586654, 0.574621, 0.650709, 0.298547, 0.561973, 0.572228, 0.577726, 0.583982,
+    0.600768, 0.600708, 0.406891, 0.360690, 0.606729, 0.594632, 0.582599, 0.661566, 0.530186,
+    0.296297, 0.559445, 0.564943, 0.571200, 0.587985, 0.600869, 0.369205, 0.360733, 0.603765,
+    0.605899, 0.593865, 0.676111, 0.539657, 0.527710, 0.295330, 0.555575, 0.558249, 0.575035,
+    0.587919, 0.339259, 0.364374, 0.600227, 0.599066, 0.600851, 0.686788, 0.544845, 0.532899,
+    0.524115, 0.288811, 0.543502, 0.560716, 0.573600, 0.308731, 0.366360, 0.599126, 0.597965,
+    0.596867, 0.700717, 0.550653, 0.538706, 0.526628, 0.512693, 0.279123, 0.542211, 0.555096,
+    0.282236, 0.350167, 0.603111, 0.601950, 0.600852, 0.702621, 0.566265, 0.554319, 0.542241,
+    0.528723, 0.511311, 0.282152, 0.559481, 0.273738, 0.354616, 0.606265, 0.605104, 0.604006,
+    0.706170, 0.566199, 0.566222, 0.554144, 0.540626, 0.523214, 0.527262, 0.281921, 0.264303,
+    0.355547, 0.532315, 0.531157, 0.528651, 0.475044, 0.386445, 0.351254, 0.323131, 0.294490,
+    0.269615, 0.261500, 0.252503, 0.066910, 0.238004, 0.373280, 0.369736, 0.366191, 0.232773,
+    0.343114, 0.343042, 0.346269, 0.348118, 0.332933, 0.337038, 0.337876, 0.230015, 0.120271,
+    0.500000, 0.608775, 0.596741, 0.584708, 0.655542, 0.309301, 0.564983, 0.571720, 0.581611,
+    0.584278, 0.601064, 0.601003, 0.604518, 0.422989, 0.617111, 0.605078, 0.593044, 0.669783,
+    0.533063, 0.307681, 0.561849, 0.571740, 0.574408, 0.591193, 0.604077, 0.604080, 0.382939,
+    0.610927, 0.613124, 0.601090, 0.680707, 0.539313, 0.530178, 0.305965, 0.559025, 0.561693,
+    0.578478, 0.591362, 0.604309, 0.350768, 0.611323, 0.610225, 0.612010, 0.695316, 0.548436,
+    0.539302, 0.527418, 0.305001, 0.548379, 0.565593, 0.578477, 0.591424, 0.327055, 0.606928,
+    0.605830, 0.604732, 0.705553, 0.550950, 0.541815, 0.529932, 0.517499, 0.295216, 0.547082,
+    0.559966, 0.572913, 0.298740, 0.610913, 0.609815, 0.608717, 0.707457, 0.566562, 0.557428,
+    0.545544, 0.533530, 0.516117, 0.298203, 0.564351, 0.577298, 0.289056, 0.614067, 0.612969,
+    0.611871, 0.711006, 0.566496, 0.569331, 0.557447, 0.545433, 0.528020, 0.532068, 0.297972,
+    0.580813, 0.279473, 0.617285, 0.616187, 0.615089, 0.714619, 0.569713, 0.569327, 0.569414,
+    0.557399, 0.539986, 0.544034, 0.547252, 0.298477, 0.274150, 0.531402, 0.530358, 0.527935,
+    0.475044, 0.401317, 0.364081, 0.333927, 0.311547, 0.284971, 0.275756, 0.266615, 0.261398,
+    0.081021, 0.500000, 0.466254, 0.456899, 0.431962, 0.134518, 0.337744, 0.334280, 0.320046,
+    0.309699, 0.303771, 0.298972, 0.293877, 0.288670, 0.360330, 0.397920, 0.386310, 0.374760,
+    0.325930, 0.168802, 0.355572, 0.357615, 0.361745, 0.366410, 0.370275, 0.369005, 0.368776,
+    0.338398, 0.394945, 0.383335, 0.371785, 0.322417, 0.332608, 0.170143, 0.355163, 0.359975,
+    0.364799, 0.368703, 0.372271, 0.371155, 0.324621, 0.386305, 0.385862, 0.374311, 0.308831,
+    0.334466, 0.332192, 0.171584, 0.357192, 0.362495, 0.366978, 0.370585, 0.374307, 0.314762,
+    0.383053, 0.379903, 0.379565, 0.298832, 0.338276, 0.336645, 0.333943, 0.172978, 0.356021,
+    0.363852, 0.367516, 0.371277, 0.306263, 0.380250, 0.377100, 0.373950, 0.292954, 0.342497,
+    0.341016, 0.338767, 0.332585, 0.171537, 0.363711, 0.367376, 0.371137, 0.303780, 0.382286,
+    0.379136, 0.375986, 0.288235, 0.346199, 0.344755, 0.343049, 0.340032, 0.339767, 0.175524,
+    0.371481, 0.375242, 0.299185, 0.381895, 0.378745, 0.375595, 0.283277, 0.344988, 0.348054,
+    0.346385, 0.343423, 0.343158, 0.347028, 0.175579, 0.376126, 0.294479, 0.372888, 0.369354,
+    0.366204, 0.278208, 0.344746, 0.346992, 0.349833, 0.346908, 0.346644, 0.350513, 0.351330,
+    0.175633, 0.500000, 0.466933, 0.436554, 0.151687, 0.356288, 0.335401, 0.336033, 0.325727,
+    0.319841, 0.315083, 0.310029, 0.304864, 0.454920, 0.361405, 0.398503, 0.386952, 0.343509,
+    0.176742, 0.367625, 0.371819, 0.375949, 0.380615, 0.384480, 0.383209, 0.382980, 0.439658,
+    0.352230, 0.395070, 0.383520, 0.323430, 0.344343, 0.186097, 0.367654, 0.372466, 0.377290,
+    0.381716, 0.385283, 0.384167, 0.433649, 0.336519, 0.397642, 0.386092, 0.323655, 0.348296,
+    0.344111, 0.188035, 0.370321, 0.375624, 0.380107, 0.383714, 0.387436, 0.430397, 0.326660,
+    0.391683, 0.391345, 0.313697, 0.352106, 0.348565, 0.346576, 0.189429, 0.369150, 0.376981,
+    0.380645, 0.384406, 0.427594, 0.318161, 0.388880, 0.385730, 0.307858, 0.356328, 0.352936,
+    0.351400, 0.345218, 0.187988, 0.376840, 0.380505, 0.384266, 0.429630, 0.315678, 0.390916,
+    0.387766, 0.303180, 0.360029, 0.357163, 0.355682, 0.352666, 0.352401, 0.191975, 0.384610,
+    0.388371, 0.429239, 0.311083, 0.390525, 0.387375, 0.298262, 0.358818, 0.360462, 0.359019,
+    0.356056, 0.355792, 0.359661, 0.192030, 0.389255, 0.422317, 0.306377, 0.381075, 0.377925,
+    0.293234, 0.358576, 0.359400, 0.362467, 0.359542, 0.359277, 0.363147, 0.363963, 0.192085,
+    0.500000, 0.445017, 0.168817, 0.363405, 0.353924, 0.337132, 0.341692, 0.335847, 0.331131,
+    0.326119, 0.320995, 0.468750, 0.455422, 0.362480, 0.399005, 0.350538, 0.182143, 0.380517,
+    0.383417, 0.389779, 0.394445, 0.398310, 0.397039, 0.396810, 0.453489, 0.450020, 0.353305,
+    0.395653, 0.340991, 0.356869, 0.194037, 0.379251, 0.386607, 0.391431, 0.395857, 0.399424,
+    0.398308, 0.445266, 0.439434, 0.349877, 0.397384, 0.324650, 0.359588, 0.355403, 0.203468,
+    0.379982, 0.385285, 0.392535, 0.396142, 0.399864, 0.442426, 0.436594, 0.338558, 0.403067,
+    0.328503, 0.365575, 0.362336, 0.355820, 0.205881, 0.382216, 0.390047, 0.393711, 0.397472,
+    0.439623, 0.433791, 0.330059, 0.397451, 0.322705, 0.369796, 0.366707, 0.360644, 0.357793,
+    0.204440, 0.389906, 0.393571, 0.397332, 0.441659, 0.435827, 0.327576, 0.399487, 0.318067,
+    0.373498, 0.370934, 0.367543, 0.365240, 0.364975, 0.208427, 0.397675, 0.401437, 0.441268,
+    0.435436, 0.322981, 0.399096, 0.313190, 0.372287, 0.374233, 0.370879, 0.368631, 0.368366,
+    0.372236, 0.208482, 0.402321, 0.434287, 0.426370, 0.318275, 0.389646, 0.308202, 0.372045,
+    0.373171, 0.374327, 0.372116, 0.371851, 0.375721, 0.376538, 0.208536, 0.500000, 0.185870,
+    0.368270, 0.361021, 0.355634, 0.342763, 0.351792, 0.347117, 0.342146, 0.337064, 0.482493,
+    0.469165, 0.455837, 0.363586, 0.355529, 0.187133, 0.391158, 0.395935, 0.398865, 0.408213,
+    0.412078, 0.410807, 0.410578, 0.467261, 0.463792, 0.450464, 0.354439, 0.348004, 0.367208,
+    0.199439, 0.392081, 0.395693, 0.405199, 0.409625, 0.413192, 0.412076, 0.459039, 0.453207,
+    0.450226, 0.351012, 0.342196, 0.371754, 0.367872, 0.211409, 0.389068, 0.399364, 0.406614,
+    0.410221, 0.413942, 0.451369, 0.445537, 0.440088, 0.349016, 0.329483, 0.374422, 0.371183,
+    0.364666, 0.217996, 0.382658, 0.399640, 0.403304, 0.407065, 0.451594, 0.445762, 0.440314,
+    0.341959, 0.337496, 0.383208, 0.380118, 0.374357, 0.358152, 0.220892, 0.402910, 0.406574,
+    0.410335, 0.453631, 0.447798, 0.442350, 0.339476, 0.332898, 0.386909, 0.384346, 0.381256,
+    0.374425, 0.377492, 0.224879, 0.410679, 0.414440, 0.453239, 0.447407, 0.441959, 0.334881,
+    0.328061, 0.385698, 0.387644, 0.384592, 0.377816, 0.380882, 0.384752, 0.224934, 0.415324,
+    0.446258, 0.438341, 0.432509, 0.330174, 0.323113, 0.385456, 0.386583, 0.388040, 0.381302,
+    0.384368, 0.388238, 0.389054, 0.224988, 0.500000, 0.640429, 0.651275, 0.662155, 0.672417,
+    0.686971, 0.688488, 0.691732, 0.695007, 0.685961, 0.678268, 0.670574, 0.662881, 0.605211,
+    0.183579, 0.533775, 0.537824, 0.540906, 0.545343, 0.562102, 0.561636, 0.564713, 0.679010,
+    0.678578, 0.670884, 0.663191, 0.615105, 0.508073, 0.185556, 0.524972, 0.528097, 0.532575,
+    0.549376, 0.560911, 0.560476, 0.673444, 0.671140, 0.671229, 0.663536, 0.625032, 0.512017,
+    0.500062, 0.188171, 0.515248, 0.519768, 0.536611, 0.548188, 0.559754, 0.667389, 0.665085,
+    0.663302, 0.663391, 0.634396, 0.515061, 0.503144, 0.491188, 0.190721, 0.502480, 0.519794,
+    0.531413, 0.543021, 0.665502, 0.663197, 0.661414, 0.659631, 0.647697, 0.519224, 0.507347,
+    0.495430, 0.479369, 0.190332, 0.518631, 0.530250, 0.541859, 0.669012, 0.666708, 0.664925,
+    0.663142, 0.649051, 0.534765, 0.522927, 0.511050, 0.495453, 0.494262, 0.194730, 0.534770,
+    0.546378, 0.672001, 0.669697, 0.667914, 0.666131, 0.651998, 0.534325, 0.533558, 0.521720,
+    0.506162, 0.504971, 0.509146, 0.195078, 0.549886, 0.665513, 0.660315, 0.658010, 0.656228,
+    0.654977, 0.537131, 0.533149, 0.532382, 0.516864, 0.515672, 0.519847, 0.523059, 0.195425,
+    0.500000, 0.315463, 0.312688, 0.314659, 0.317998, 0.312945, 0.311996, 0.306995, 0.583281,
+    0.576257, 0.571472, 0.564179, 0.475064, 0.134519, 0.305299, 0.303821, 0.302746, 0.293638,
+    0.306299, 0.302929, 0.297719, 0.450171, 0.443764, 0.430060, 0.416355, 0.303117, 0.295709,
+    0.144124, 0.346453, 0.354235, 0.360356, 0.371072, 0.375867, 0.374677, 0.441799, 0.433371,
+    0.429883, 0.416178, 0.299703, 0.293509, 0.331434, 0.157949, 0.350653, 0.357292, 0.368068,
+    0.373474, 0.377298, 0.436343, 0.427914, 0.422012, 0.419027, 0.300711, 0.291717, 0.338777,
+    0.334978, 0.171282, 0.349408, 0.361134, 0.369614, 0.373479, 0.431319, 0.422891, 0.416988,
+    0.411478, 0.303049, 0.282510, 0.344394, 0.341075, 0.333286, 0.178902, 0.349918, 0.368325,
+    0.372190, 0.432532, 0.424104, 0.418201, 0.412691, 0.298132, 0.294027, 0.354350, 0.351089,
+    0.344198, 0.333222, 0.182158, 0.371721, 0.375586, 0.435368, 0.426939, 0.421036, 0.415527,
+    0.297029, 0.290665, 0.358877, 0.356176, 0.352127, 0.350413, 0.353557, 0.186608, 0.380029,
+    0.435093, 0.424532, 0.418236, 0.412726, 0.292171, 0.285602, 0.357760, 0.359669, 0.355660,
+    0.353946, 0.357090, 0.361195, 0.186675, 0.500000, 0.297482, 0.299768, 0.306607, 0.301559,
+    0.296225, 0.295164, 0.622010, 0.609957, 0.597469, 0.585125, 0.647425, 0.299372, 0.364893,
+    0.370575, 0.376171, 0.384857, 0.395537, 0.391115, 0.394371, 0.577927, 0.577170, 0.572270,
+    0.564895, 0.475065, 0.348738, 0.117830, 0.286674, 0.287018, 0.278298, 0.291119, 0.289059,
+    0.285576, 0.426978, 0.426968, 0.420954, 0.407249, 0.285655, 0.353455, 0.277550, 0.141896,
+    0.333772, 0.342882, 0.353686, 0.360130, 0.365087, 0.421941, 0.421931, 0.413896, 0.410408,
+    0.286945, 0.358722, 0.277092, 0.319281, 0.155798, 0.338011, 0.349765, 0.356269, 0.361838,
+    0.419703, 0.419693, 0.411658, 0.405755, 0.292506, 0.366820, 0.268254, 0.327767, 0.322763,
+    0.167001, 0.348476, 0.354980, 0.363561, 0.420922, 0.420912, 0.412877, 0.406974, 0.287597,
+    0.376737, 0.279918, 0.337800, 0.333694, 0.331980, 0.170270, 0.358383, 0.366964, 0.419567,
+    0.419557, 0.411522, 0.405619, 0.282330, 0.372505, 0.277715, 0.343622, 0.339573, 0.337859,
+    0.341003, 0.169077, 0.366690, 0.420426, 0.420416, 0.411988, 0.406085, 0.281117, 0.375497,
+    0.274243, 0.348304, 0.344816, 0.345887, 0.349038, 0.348641, 0.173526, 0.500000, 0.284559,
+    0.291714, 0.286666, 0.284774, 0.279327, 0.619416, 0.607320, 0.595329, 0.582905, 0.649894,
+    0.298860, 0.570951, 0.363720, 0.364839, 0.373845, 0.384588, 0.383325, 0.382188, 0.616295,
+    0.617908, 0.605917, 0.593493, 0.664023, 0.539013, 0.292898, 0.328821, 0.355794, 0.364799,
+    0.375543, 0.386454, 0.382194, 0.575344, 0.573827, 0.573101, 0.565653, 0.475065, 0.347186,
+    0.314540, 0.101469, 0.270201, 0.262824, 0.275780, 0.273880, 0.271707, 0.410986, 0.407442,
+    0.407432, 0.401418, 0.272896, 0.347876, 0.339292, 0.261433, 0.139744, 0.320957, 0.332650,
+    0.341823, 0.348428, 0.409168, 0.405623, 0.405613, 0.397578, 0.278737, 0.356285, 0.347701,
+    0.253866, 0.306903, 0.151516, 0.334373, 0.343546, 0.350152, 0.410387, 0.406842, 0.406832,
+    0.398797, 0.273828, 0.366261, 0.357677, 0.265659, 0.317777, 0.318848, 0.154786, 0.346949,
+    0.353555, 0.411823, 0.408278, 0.408268, 0.400233, 0.271733, 0.364930, 0.367803, 0.263604,
+    0.326223, 0.327294, 0.330444, 0.157187, 0.353287, 0.408492, 0.404554, 0.404544, 0.396509,
+    0.266356, 0.363731, 0.363727, 0.261290, 0.332201, 0.333272, 0.336422, 0.336026, 0.155993,
+    0.500000, 0.276442, 0.271141, 0.269817, 0.268381, 0.613356, 0.601259, 0.589226, 0.577692,
+    0.647940, 0.298885, 0.567510, 0.576916, 0.362506, 0.362512, 0.373146, 0.372453, 0.374904,
+    0.610336, 0.611948, 0.599915, 0.587988, 0.661787, 0.535505, 0.292755, 0.562570, 0.327565,
+    0.353404, 0.364038, 0.375441, 0.374910, 0.609991, 0.608308, 0.610505, 0.598578, 0.675917,
+    0.544299, 0.530748, 0.287197, 0.301515, 0.344361, 0.354995, 0.366398, 0.377901, 0.569751,
+    0.568234, 0.567066, 0.566372, 0.475064, 0.345594, 0.312906, 0.288425, 0.085760, 0.245540,
+    0.260093, 0.258328, 0.256315, 0.398172, 0.394627, 0.391082, 0.391072, 0.264644, 0.345457,
+    0.336814, 0.328229, 0.237770, 0.135750, 0.314508, 0.324049, 0.333752, 0.399290, 0.395745,
+    0.392200, 0.392190, 0.259496, 0.355332, 0.346688, 0.338104, 0.251081, 0.300403, 0.139019,
+    0.327452, 0.337155, 0.401247, 0.397702, 0.394158, 0.394148, 0.257921, 0.354522, 0.357286,
+    0.348701, 0.249155, 0.309204, 0.312355, 0.141990, 0.340470, 0.401228, 0.397290, 0.393746,
+    0.393736, 0.256236, 0.356635, 0.356632, 0.359396, 0.246989, 0.318161, 0.321311, 0.324227,
+    0.144961, 0.500000, 0.255186, 0.253862, 0.252426, 0.604484, 0.592387, 0.580354, 0.568384,
+    0.644645, 0.294369, 0.560920, 0.570007, 0.579477, 0.361325, 0.360837, 0.360572, 0.363023,
+    0.601008, 0.602620, 0.590587, 0.578616, 0.655258, 0.529052, 0.288070, 0.555630, 0.565099,
+    0.326269, 0.351728, 0.363132, 0.362601, 0.600764, 0.599081, 0.601278, 0.589308, 0.669107,
+    0.537553, 0.523972, 0.282386, 0.550819, 0.300135, 0.342622, 0.354025, 0.365591, 0.600500,
+    0.598818, 0.597720, 0.599980, 0.683317, 0.546429, 0.532848, 0.519360, 0.276602, 0.275602,
+    0.330080, 0.341484, 0.353050, 0.563021, 0.561504, 0.560336, 0.558080, 0.475064, 0.344037,
+    0.311235, 0.286672, 0.263624, 0.068024, 0.246217, 0.244451, 0.242438, 0.387236, 0.383692,
+    0.380147, 0.376602, 0.244615, 0.343447, 0.334803, 0.326160, 0.314346, 0.238120, 0.121486,
+    0.313852, 0.323555, 0.389194, 0.385649, 0.382104, 0.378559, 0.243040, 0.343030, 0.345401,
+    0.336757, 0.324944, 0.236193, 0.299561, 0.124457, 0.326871, 0.389175, 0.385237, 0.381692,
+    0.378148, 0.241355, 0.345143, 0.344747, 0.347510, 0.335697, 0.234027, 0.308518, 0.311433,
+    0.127427, 0.500000, 0.251704, 0.250267, 0.609251, 0.597155, 0.585121, 0.573151, 0.649495,
+    0.298040, 0.567189, 0.573122, 0.581703, 0.589397, 0.369437, 0.363380, 0.365831, 0.602874,
+    0.604487, 0.592454, 0.580483, 0.660108, 0.535049, 0.291560, 0.558739, 0.567320, 0.575014,
+    0.330650, 0.365933, 0.365402, 0.602109, 0.600427, 0.602623, 0.590653, 0.673957, 0.540650,
+    0.527069, 0.285749, 0.553008, 0.560702, 0.303108, 0.356827, 0.368392, 0.601947, 0.600265,
+    0.599167, 0.601015, 0.688406, 0.548693, 0.535113, 0.521595, 0.279312, 0.542235, 0.281426,
+    0.343653, 0.355218, 0.602052, 0.600370, 0.599272, 0.598237, 0.703142, 0.555707, 0.542127,
+    0.528609, 0.511226, 0.276739, 0.268951, 0.343708, 0.355274, 0.565076, 0.563559, 0.562391,
+    0.560135, 0.475064, 0.351613, 0.315371, 0.289489, 0.269066, 0.257172, 0.069309, 0.246667,
+    0.244655, 0.389250, 0.385705, 0.382160, 0.378616, 0.241053, 0.345618, 0.347989, 0.339345,
+    0.326951, 0.326866, 0.238248, 0.124451, 0.326862, 0.389231, 0.385293, 0.381748, 0.378204,
+    0.239368, 0.347731, 0.347334, 0.350098, 0.337705, 0.337619, 0.236082, 0.311431, 0.127422,
+    0.500000, 0.246336, 0.606414, 0.594317, 0.582284, 0.570313, 0.650595, 0.293983, 0.565142,
+    0.571069, 0.579650, 0.587343, 0.604191, 0.367213, 0.361382, 0.603971, 0.605584, 0.593550,
+    0.581580, 0.665140, 0.533046, 0.293017, 0.561701, 0.566699, 0.574393, 0.591241, 0.336377,
+    0.365023, 0.600433, 0.598751, 0.600948, 0.588977, 0.675818, 0.538647, 0.529863, 0.287067,
+    0.552381, 0.560075, 0.576923, 0.306241, 0.367579, 0.599750, 0.598068, 0.596969, 0.598818,
+    0.689747, 0.546690, 0.534613, 0.521095, 0.280545, 0.541576, 0.558853, 0.283229, 0.354405,
+    0.599855, 0.598173, 0.597075, 0.596040, 0.704483, 0.553704, 0.541627, 0.528109, 0.510696,
+    0.277931, 0.558845, 0.270388, 0.354460, 0.603903, 0.602221, 0.601123, 0.600088, 0.706450,
+    0.569380, 0.557302, 0.543785, 0.526790, 0.526648, 0.281654, 0.264934, 0.358909, 0.565068,
+    0.563551, 0.562383, 0.560127, 0.475064, 0.349574, 0.320633, 0.292350, 0.270688, 0.258449,
+    0.253138, 0.066640, 0.242928, 0.385968, 0.382030, 0.378485, 0.374940, 0.235731, 0.343626,
+    0.346853, 0.349223, 0.336830, 0.336744, 0.340849, 0.234492, 0.123047, 0.500000, 0.607539,
+    0.595132, 0.583098, 0.571128, 0.655429, 0.290477, 0.561886, 0.572206, 0.577198, 0.584892,
+    0.601740, 0.601743, 0.367213, 0.601437, 0.603177, 0.591144, 0.579174, 0.666353, 0.530054,
+    0.288761, 0.559491, 0.564483, 0.572177, 0.589025, 0.601972, 0.334153, 0.601833, 0.600279,
+    0.602476, 0.590505, 0.680963, 0.539590, 0.527706, 0.288367, 0.551598, 0.559292, 0.576140,
+    0.589087, 0.310831, 0.597856, 0.596302, 0.595204, 0.597052, 0.691200, 0.544339, 0.532456,
+    0.520441, 0.281748, 0.540786, 0.558064, 0.571011, 0.285999, 0.597961, 0.596407, 0.595309,
+    0.594274, 0.705936, 0.551353, 0.539470, 0.527455, 0.510042, 0.279092, 0.558056, 0.571003,
+    0.271972, 0.602009, 0.600455, 0.599357, 0.598322, 0.707903, 0.567029, 0.555145, 0.543131,
+    0.526136, 0.525994, 0.282814, 0.575452, 0.266370, 0.605227, 0.603672, 0.602574, 0.601539,
+    0.711515, 0.567025, 0.567112, 0.555097, 0.538102, 0.537960, 0.542071, 0.283319, 0.261089,
+    0.553855, 0.549174, 0.547487, 0.545230, 0.475064, 0.349566, 0.318594, 0.296573, 0.273209,
+    0.259870, 0.254416, 0.249239, 0.064382, 0.500000, 0.465179, 0.455714, 0.430699, 0.397277,
+    0.118515, 0.317703, 0.305128, 0.295389, 0.289616, 0.286136, 0.282623, 0.273563, 0.325551,
+    0.384828, 0.373219, 0.361609, 0.353502, 0.307116, 0.155062, 0.338434, 0.343380, 0.350676,
+    0.356406, 0.360932, 0.356658, 0.311547, 0.376477, 0.375650, 0.364041, 0.355933, 0.295074,
+    0.316304, 0.156503, 0.341118, 0.348894, 0.354681, 0.359246, 0.359811, 0.302161, 0.373714,
+    0.370180, 0.369737, 0.361630, 0.285653, 0.320896, 0.318682, 0.158419, 0.345187, 0.351555,
+    0.356698, 0.357302, 0.296564, 0.373528, 0.369994, 0.366844, 0.369949, 0.279916, 0.327677,
+    0.325916, 0.322351, 0.160296, 0.351421, 0.359330, 0.359934, 0.290374, 0.371732, 0.368198,
+    0.365048, 0.365341, 0.276347, 0.332905, 0.331199, 0.328182, 0.327917, 0.159092, 0.359190,
+    0.359794, 0.289103, 0.374394, 0.370860, 0.367710, 0.368003, 0.272840, 0.337233, 0.335564,
+    0.333090, 0.335442, 0.335178, 0.163197, 0.363899, 0.281375, 0.364655, 0.361121, 0.357971,
+    0.367020, 0.264357, 0.333327, 0.336168, 0.333731, 0.336084, 0.335819, 0.339689, 0.160424,
+    0.500000, 0.465817, 0.435400, 0.404221, 0.135684, 0.318825, 0.321115, 0.311417, 0.305686,
+    0.302247, 0.298776, 0.289757, 0.433759, 0.339382, 0.384954, 0.373344, 0.365237, 0.308129,
+    0.171016, 0.351446, 0.356392, 0.363688, 0.369419, 0.373944, 0.369670, 0.427654, 0.323445,
+    0.387431, 0.375821, 0.367714, 0.309899, 0.328712, 0.172954, 0.354246, 0.362023, 0.367810,
+    0.372375, 0.372940, 0.424891, 0.314059, 0.381960, 0.381518, 0.373410, 0.300518, 0.333304,
+    0.331316, 0.174870, 0.358316, 0.364684, 0.369827, 0.370431, 0.424705, 0.308462, 0.381774,
+    0.378624, 0.381729, 0.294821, 0.340085, 0.338549, 0.334984, 0.176747, 0.364550, 0.372459,
+    0.373063, 0.422909, 0.302272, 0.379978, 0.376828, 0.377121, 0.291292, 0.345313, 0.343832,
+    0.340815, 0.340551, 0.175543, 0.372319, 0.372923, 0.425571, 0.301001, 0.382640, 0.379490,
+    0.379783, 0.287825, 0.349641, 0.348197, 0.345723, 0.348076, 0.347811, 0.179648, 0.377028,
+    0.414085, 0.293273, 0.372843, 0.369693, 0.379103, 0.279382, 0.345735, 0.348801, 0.346365,
+    0.348717, 0.348452, 0.352322, 0.176876, 0.500000, 0.443943, 0.409111, 0.152853, 0.337347,
+    0.322214, 0.327382, 0.321692, 0.318295, 0.314865, 0.305888, 0.447589, 0.441651, 0.340457,
+    0.385477, 0.377370, 0.325690, 0.178956, 0.363437, 0.370533, 0.377830, 0.383560, 0.388085,
+    0.383811, 0.439760, 0.431842, 0.337276, 0.387497, 0.379390, 0.310894, 0.340388, 0.188908,
+    0.366674, 0.374451, 0.380238, 0.385324, 0.385889, 0.436920, 0.429003, 0.325957, 0.393239,
+    0.385132, 0.315324, 0.347075, 0.343176, 0.191321, 0.371381, 0.377750, 0.382893, 0.383497,
+    0.436734, 0.428817, 0.320360, 0.390345, 0.393451, 0.309668, 0.353856, 0.350410, 0.347559,
+    0.193199, 0.377615, 0.385525, 0.386129, 0.434938, 0.427020, 0.314170, 0.388549, 0.388842,
+    0.306179, 0.359084, 0.355693, 0.353390, 0.353125, 0.191994, 0.385385, 0.385989, 0.437600,
+    0.429683, 0.312899, 0.391211, 0.391504, 0.302753, 0.363412, 0.360546, 0.358298, 0.360650,
+    0.360385, 0.196100, 0.390094, 0.426055, 0.418137, 0.305171, 0.381414, 0.390824, 0.294350,
+    0.359506, 0.361150, 0.358939, 0.361291, 0.361027, 0.364896, 0.193327, 0.500000, 0.416320,
+    0.169983, 0.344443, 0.340715, 0.328460, 0.337636, 0.334280, 0.330892, 0.321955, 0.461360,
+    0.455422, 0.442095, 0.341533, 0.389364, 0.332701, 0.184357, 0.376265, 0.382067, 0.391597,
+    0.397327, 0.401852, 0.397578, 0.453531, 0.445614, 0.442145, 0.338351, 0.391464, 0.328438,
+    0.352855, 0.196849, 0.378208, 0.388529, 0.394316, 0.399402, 0.399967, 0.448478, 0.440561,
+    0.434729, 0.339316, 0.396365, 0.316302, 0.358308, 0.354409, 0.206754, 0.380980, 0.387348,
+    0.395257, 0.395862, 0.448704, 0.440786, 0.434954, 0.332258, 0.405113, 0.324457, 0.367266,
+    0.364122, 0.356743, 0.209650, 0.390618, 0.398528, 0.399132, 0.446908, 0.438990, 0.433158,
+    0.326068, 0.400504, 0.321008, 0.372494, 0.369405, 0.362574, 0.365640, 0.208446, 0.398387,
+    0.398992, 0.449570, 0.441652, 0.435820, 0.324797, 0.403167, 0.317623, 0.376822, 0.374258,
+    0.370099, 0.373165, 0.372900, 0.212551, 0.403097, 0.438025, 0.430107, 0.424275, 0.317069,
+    0.402486, 0.309260, 0.372916, 0.374862, 0.370740, 0.373807, 0.373542, 0.377412, 0.209779,
+    0.500000, 0.182784, 0.345166, 0.343909, 0.343059, 0.334804, 0.346322, 0.342975, 0.337934,
+    0.475195, 0.468873, 0.455545, 0.442217, 0.340950, 0.333886, 0.185035, 0.383355, 0.391034,
+    0.397131, 0.407543, 0.412069, 0.410952, 0.467395, 0.459094, 0.455625, 0.442297, 0.337797,
+    0.331871, 0.359836, 0.197939, 0.387487, 0.394063, 0.404533, 0.409619, 0.413340, 0.462342,
+    0.454041, 0.448209, 0.445228, 0.338762, 0.330268, 0.367117, 0.363520, 0.210383, 0.386514,
+    0.397876, 0.405785, 0.409546, 0.457738, 0.449436, 0.443604, 0.438156, 0.341028, 0.321858,
+    0.372755, 0.369611, 0.362232, 0.217454, 0.387508, 0.404570, 0.408331, 0.458970, 0.450669,
+    0.444837, 0.439388, 0.336279, 0.332220, 0.382548, 0.379458, 0.372930, 0.362642, 0.220586,
+    0.407840, 0.411601, 0.461632, 0.453331, 0.447499, 0.442050, 0.335009, 0.328875, 0.386875,
+    0.384312, 0.380455, 0.378993, 0.382060, 0.224692, 0.415706, 0.461391, 0.451004, 0.444788,
+    0.439340, 0.330302, 0.323967, 0.385814, 0.387760, 0.383941, 0.382479, 0.385545, 0.389415,
+    0.224746, 0.500000, 0.642997, 0.654087, 0.664644, 0.679635, 0.694431, 0.696190, 0.695754,
+    0.688449, 0.684601, 0.676907, 0.669214, 0.665408, 0.607630, 0.181794, 0.535799, 0.539478,
+    0.546842, 0.551478, 0.568437, 0.564659, 0.683080, 0.677881, 0.677449, 0.669756, 0.665950,
+    0.617755, 0.509974, 0.184408, 0.526629, 0.534035, 0.538713, 0.555714, 0.563936, 0.677222,
+    0.672024, 0.669720, 0.669808, 0.666002, 0.627395, 0.513587, 0.501631, 0.187529, 0.517176,
+    0.521897, 0.538939, 0.547204, 0.675544, 0.670345, 0.668041, 0.666258, 0.670234, 0.641089,
+    0.520555, 0.508639, 0.493002, 0.190887, 0.520746, 0.538218, 0.546482, 0.673885, 0.668686,
+    0.666382, 0.664599, 0.666703, 0.654619, 0.524907, 0.513031, 0.497433, 0.496242, 0.190750,
+    0.537055, 0.545320, 0.677624, 0.672425, 0.670121, 0.668338, 0.670442, 0.656201, 0.540638,
+    0.528800, 0.513243, 0.512475, 0.511284, 0.195274, 0.549839, 0.666996, 0.661797, 0.659493,
+    0.657710, 0.673660, 0.655792, 0.537172, 0.536405, 0.520887, 0.520120, 0.518928, 0.523103,
+    0.195192, 0.500000, 0.296968, 0.299286, 0.306133, 0.312069, 0.308384, 0.306824, 0.614995,
+    0.614196, 0.607163, 0.602570, 0.600259, 0.475075, 0.131946, 0.287750, 0.291047, 0.294637,
+    0.288167, 0.302238, 0.298298, 0.441193, 0.441183, 0.434776, 0.421071, 0.407947, 0.285663,
+    0.279142, 0.142808, 0.341514, 0.352576, 0.359736, 0.371161, 0.375191, 0.436188, 0.436178,
+    0.427750, 0.424262, 0.411138, 0.286984, 0.281395, 0.326840, 0.157139, 0.348028, 0.355815,
+    0.367301, 0.371942, 0.433956, 0.433946, 0.425518, 0.419615, 0.417210, 0.292545, 0.283957,
+    0.337205, 0.332518, 0.170992, 0.354531, 0.366341, 0.373994, 0.429999, 0.429989, 0.421561,
+    0.415658, 0.410729, 0.297332, 0.277240, 0.343787, 0.339682, 0.337967, 0.178864, 0.355125,
+    0.372705, 0.431874, 0.431864, 0.423436, 0.417533, 0.412604, 0.293703, 0.290087, 0.354406,
+    0.350358, 0.348960, 0.337984, 0.182247, 0.376102, 0.429634, 0.429624, 0.421196, 0.415293,
+    0.415596, 0.292041, 0.286206, 0.358247, 0.354759, 0.356147, 0.354433, 0.357577, 0.186555,
+    0.500000, 0.284014, 0.291177, 0.300612, 0.296932, 0.290987, 0.628387, 0.626712, 0.614721,
+    0.602297, 0.593497, 0.664873, 0.297056, 0.330983, 0.365305, 0.374399, 0.384234, 0.395624,
+    0.390435, 0.613565, 0.608843, 0.608076, 0.603368, 0.600975, 0.475075, 0.317130, 0.115669,
+    0.274229, 0.279163, 0.272827, 0.287059, 0.284429, 0.425234, 0.421296, 0.421286, 0.415272,
+    0.402148, 0.272875, 0.348556, 0.265736, 0.141085, 0.330973, 0.341341, 0.352855, 0.358532,
+    0.423421, 0.419483, 0.419473, 0.411438, 0.408531, 0.278717, 0.357047, 0.269569, 0.316658,
+    0.155507, 0.343071, 0.354907, 0.360585, 0.422249, 0.418311, 0.418301, 0.410266, 0.404944,
+    0.286727, 0.366212, 0.262981, 0.326312, 0.327383, 0.166962, 0.353618, 0.359296, 0.424130,
+    0.420192, 0.420182, 0.412147, 0.406825, 0.283106, 0.376791, 0.275975, 0.337008, 0.338395,
+    0.336681, 0.170357, 0.362698, 0.417700, 0.413762, 0.413752, 0.405717, 0.405626, 0.277280,
+    0.371873, 0.273254, 0.342144, 0.343532, 0.341817, 0.344962, 0.169022, 0.500000, 0.275905,
+    0.285655, 0.281976, 0.280041, 0.622428, 0.620752, 0.608719, 0.596791, 0.587911, 0.662608,
+    0.296913, 0.576226, 0.329810, 0.363004, 0.373157, 0.384611, 0.383152, 0.622145, 0.617697,
+    0.619372, 0.607445, 0.598565, 0.676801, 0.543888, 0.291354, 0.302467, 0.353961, 0.364114,
+    0.375567, 0.386142, 0.611184, 0.606462, 0.604935, 0.604199, 0.601733, 0.475074, 0.315579,
+    0.289893, 0.100002, 0.261879, 0.257140, 0.271506, 0.269037, 0.412425, 0.408487, 0.404942,
+    0.404932, 0.399500, 0.264624, 0.346159, 0.337575, 0.253480, 0.139740, 0.323634, 0.335410,
+    0.344185, 0.411673, 0.407735, 0.404190, 0.404180, 0.396726, 0.272914, 0.355635, 0.347051,
+    0.248403, 0.309350, 0.151765, 0.337703, 0.346478, 0.413554, 0.409616, 0.406072, 0.406062,
+    0.398607, 0.269294, 0.366273, 0.357689, 0.261526, 0.320305, 0.321897, 0.155160, 0.349881,
+    0.410436, 0.406498, 0.402953, 0.402943, 0.400721, 0.267160, 0.364778, 0.367541, 0.258952,
+    0.328421, 0.330012, 0.333163, 0.157990, 0.500000, 0.269692, 0.266012, 0.264077, 0.613099,
+    0.611424, 0.599390, 0.587420, 0.579431, 0.656078, 0.292228, 0.569286, 0.578755, 0.328595,
+    0.360848, 0.372301, 0.370842, 0.612918, 0.608469, 0.610145, 0.598175, 0.589792, 0.669991,
+    0.537112, 0.286543, 0.564475, 0.301211, 0.351741, 0.363195, 0.373833, 0.612655, 0.608206,
+    0.606587, 0.608847, 0.600464, 0.684201, 0.545988, 0.532500, 0.280760, 0.279654, 0.339200,
+    0.350653, 0.361291, 0.606703, 0.601980, 0.600453, 0.599275, 0.602444, 0.475074, 0.313978,
+    0.288251, 0.267951, 0.082282, 0.243272, 0.257638, 0.255169, 0.399620, 0.395682, 0.392137,
+    0.388592, 0.389163, 0.258033, 0.343750, 0.335106, 0.323293, 0.235442, 0.134232, 0.324104,
+    0.332879, 0.401501, 0.397563, 0.394018, 0.390474, 0.391044, 0.254413, 0.354388, 0.345744,
+    0.333931, 0.248573, 0.309104, 0.137627, 0.336282, 0.398383, 0.394445, 0.390900, 0.387355,
+    0.393158, 0.252279, 0.352892, 0.355656, 0.343843, 0.245999, 0.317219, 0.320369, 0.140457,
+    0.500000, 0.249994, 0.248059, 0.603162, 0.601487, 0.589454, 0.577483, 0.569058, 0.650337,
+    0.287512, 0.561548, 0.570698, 0.581145, 0.327414, 0.359928, 0.358898, 0.602525, 0.598076,
+    0.599752, 0.587781, 0.579356, 0.661015, 0.529594, 0.281701, 0.556386, 0.566833, 0.299915,
+    0.350822, 0.361460, 0.602363, 0.597914, 0.596295, 0.598555, 0.590129, 0.674944, 0.538177,
+    0.524659, 0.275833, 0.548795, 0.278274, 0.338218, 0.348855, 0.602886, 0.598437, 0.596818,
+    0.595783, 0.601588, 0.689680, 0.547839, 0.534322, 0.517356, 0.276996, 0.265846, 0.341862,
+    0.352500, 0.600493, 0.595770, 0.594243, 0.593065, 0.594931, 0.475074, 0.312422, 0.286581,
+    0.266198, 0.254345, 0.064955, 0.243761, 0.241292, 0.389389, 0.385450, 0.381906, 0.378361,
+    0.375397, 0.239473, 0.342444, 0.333800, 0.321928, 0.325154, 0.235611, 0.120094, 0.322683,
+    0.386270, 0.382332, 0.378787, 0.375243, 0.377511, 0.237338, 0.341341, 0.343712, 0.331839,
+    0.335066, 0.233037, 0.307576, 0.122924, 0.500000, 0.245838, 0.607270, 0.605594, 0.593561,
+    0.581591, 0.573165, 0.653900, 0.290983, 0.567107, 0.572535, 0.582553, 0.590309, 0.334390,
+    0.361642, 0.603732, 0.599283, 0.600958, 0.588988, 0.580562, 0.664577, 0.534931, 0.285033,
+    0.558216, 0.568235, 0.575991, 0.303649, 0.364198, 0.603048, 0.598600, 0.596980, 0.599240,
+    0.590814, 0.678506, 0.540092, 0.526575, 0.279081, 0.550165, 0.557921, 0.281029, 0.351594,
+    0.603572, 0.599123, 0.597504, 0.596469, 0.601861, 0.693243, 0.549343, 0.535825, 0.518830,
+    0.279633, 0.557920, 0.271671, 0.354668, 0.603740, 0.599291, 0.597672, 0.596637, 0.599147,
+    0.708042, 0.556419, 0.542902, 0.525907, 0.525765, 0.277712, 0.261873, 0.354724, 0.602548,
+    0.597826, 0.596299, 0.595121, 0.596987, 0.475073, 0.318958, 0.290116, 0.268813, 0.259787,
+    0.250352, 0.066651, 0.243508, 0.386267, 0.382329, 0.378784, 0.375240, 0.377508, 0.235292,
+    0.343870, 0.346241, 0.334368, 0.337074, 0.336988, 0.235092, 0.122918, 0.500000, 0.607435,
+    0.605760, 0.593726, 0.581756, 0.570173, 0.655586, 0.290667, 0.565825, 0.571246, 0.581264,
+    0.589021, 0.605933, 0.333884, 0.607831, 0.603382, 0.605058, 0.593088, 0.581505, 0.670195,
+    0.533661, 0.290273, 0.558361, 0.568379, 0.576136, 0.593047, 0.309957, 0.603854, 0.599405,
+    0.597786, 0.600046, 0.588463, 0.680433, 0.538823, 0.526808, 0.284224, 0.550303, 0.558060,
+    0.574971, 0.285517, 0.604377, 0.599928, 0.598309, 0.597274, 0.599510, 0.695169, 0.548073,
+    0.536058, 0.519064, 0.284734, 0.558058, 0.575399, 0.274972, 0.604546, 0.600097, 0.598478,
+    0.597443, 0.596795, 0.709968, 0.555150, 0.543135, 0.526141, 0.525998, 0.282814, 0.575454,
+    0.265028, 0.608657, 0.604208, 0.602589, 0.601554, 0.600906, 0.711998, 0.570889, 0.558874,
+    0.541880, 0.542155, 0.542075, 0.287272, 0.263727, 0.589865, 0.585142, 0.583615, 0.582438,
+    0.596979, 0.475073, 0.318509, 0.295928, 0.272924, 0.262798, 0.253220, 0.251729, 0.066530,
+    0.500000, 0.473576, 0.464111, 0.433543, 0.402245, 0.367794, 0.120065, 0.303987, 0.295583,
+    0.290180, 0.286854, 0.284661, 0.277182, 0.310534, 0.380070, 0.379244, 0.367634, 0.358979,
+    0.347469, 0.294054, 0.157863, 0.336842, 0.344853, 0.353113, 0.359504, 0.361026, 0.300922,
+    0.377212, 0.373678, 0.373235, 0.364580, 0.353071, 0.285888, 0.314793, 0.159779, 0.341667,
+    0.350508, 0.356956, 0.358518, 0.295798, 0.377514, 0.373980, 0.370830, 0.373342, 0.361833,
+    0.280503, 0.322260, 0.319184, 0.162178, 0.353141, 0.359588, 0.361671, 0.292511, 0.378335,
+    0.374801, 0.371651, 0.371456, 0.371159, 0.277075, 0.329952, 0.327424, 0.329776, 0.164292,
+    0.359454, 0.364303, 0.287532, 0.377165, 0.373631, 0.370481, 0.370286, 0.367176, 0.274717,
+    0.335807, 0.333333, 0.335685, 0.335421, 0.163206, 0.364163, 0.283128, 0.370480, 0.366946,
+    0.363796, 0.372763, 0.367144, 0.267685, 0.337440, 0.335003, 0.337844, 0.340197, 0.339932,
+    0.164484, 0.500000, 0.464742, 0.434215, 0.402957, 0.368547, 0.120072, 0.305103, 0.296740,
+    0.291378, 0.288094, 0.285943, 0.278505, 0.413777, 0.310534, 0.377251, 0.365642, 0.357475,
+    0.349071, 0.295107, 0.157870, 0.337350, 0.345361, 0.353621, 0.360012, 0.361534, 0.410919,
+    0.300921, 0.372069, 0.371243, 0.363076, 0.354672, 0.286982, 0.315270, 0.159786, 0.342175,
+    0.351016, 0.357464, 0.359026, 0.411222, 0.295798, 0.372372, 0.368838, 0.371838, 0.363434,
+    0.281636, 0.322737, 0.319660, 0.162185, 0.353649, 0.360096, 0.362179, 0.412042, 0.292510,
+    0.373192, 0.369659, 0.369952, 0.372760, 0.278249, 0.330429, 0.327901, 0.330253, 0.164299,
+    0.359962, 0.364811, 0.410872, 0.287532, 0.372022, 0.368489, 0.368782, 0.368778, 0.275931,
+    0.336283, 0.333810, 0.336162, 0.335897, 0.163213, 0.364671, 0.402439, 0.283128, 0.365278,
+    0.361744, 0.371154, 0.368745, 0.268939, 0.337916, 0.335480, 0.338321, 0.340673, 0.340408,
+    0.164491, 0.500000, 0.442828, 0.407956, 0.375502, 0.137242, 0.306203, 0.312705, 0.307384,
+    0.304142, 0.302032, 0.294636, 0.425883, 0.425879, 0.324365, 0.377317, 0.369151, 0.360747,
+    0.296102, 0.173825, 0.350299, 0.358310, 0.366571, 0.372961, 0.374483, 0.422948, 0.422944,
+    0.312819, 0.382964, 0.374797, 0.366394, 0.301788, 0.327618, 0.176238, 0.355241, 0.364082,
+    0.370530, 0.372092, 0.423250, 0.423247, 0.307696, 0.380559, 0.383559, 0.375155, 0.296483,
+    0.335086, 0.332235, 0.178637, 0.366715, 0.373162, 0.375245, 0.424071, 0.424068, 0.304408,
+    0.381380, 0.381673, 0.384482, 0.293136, 0.342778, 0.340475, 0.342827, 0.180750, 0.373028,
+    0.377877, 0.422901, 0.422898, 0.299430, 0.380210, 0.380503, 0.380499, 0.290859, 0.348632,
+    0.346384, 0.348736, 0.348471, 0.179664, 0.377737, 0.414409, 0.414406, 0.295026, 0.373465,
+    0.382875, 0.380467, 0.283907, 0.350265, 0.348054, 0.350895, 0.353247, 0.352983, 0.180942,
+    0.500000, 0.415246, 0.380218, 0.154411, 0.324704, 0.313783, 0.323328, 0.320127, 0.318058,
+    0.310704, 0.439654, 0.439651, 0.433713, 0.325440, 0.381225, 0.372821, 0.313646, 0.181765,
+    0.362226, 0.372388, 0.380649, 0.387039, 0.388561, 0.434995, 0.434991, 0.427073, 0.326651,
+    0.386414, 0.378010, 0.302766, 0.339235, 0.192192, 0.367606, 0.376447, 0.382895, 0.384978,
+    0.435220, 0.435217, 0.427299, 0.319594, 0.395222, 0.386818, 0.311272, 0.348798, 0.344036,
+    0.195088, 0.379717, 0.386165, 0.388248, 0.436041, 0.436038, 0.428120, 0.316306, 0.393335,
+    0.396144, 0.307965, 0.356490, 0.352276, 0.355342, 0.197202, 0.386031, 0.390880, 0.434871,
+    0.434867, 0.426950, 0.311328, 0.392165, 0.392161, 0.305729, 0.362344, 0.358185, 0.361251,
+    0.360987, 0.196116, 0.390740, 0.426379, 0.426375, 0.418458, 0.306924, 0.394538, 0.392129,
+    0.298817, 0.363977, 0.360344, 0.363410, 0.365763, 0.365498, 0.197394, 0.500000, 0.382848,
+    0.167290, 0.327897, 0.328382, 0.320503, 0.332168, 0.330141, 0.326682, 0.453134, 0.453130,
+    0.447192, 0.433864, 0.324828, 0.381458, 0.317079, 0.182854, 0.371504, 0.380372, 0.390865,
+    0.397255, 0.401934, 0.448474, 0.448470, 0.440553, 0.437084, 0.326038, 0.386728, 0.316732,
+    0.348346, 0.195821, 0.375589, 0.386974, 0.393422, 0.398662, 0.446487, 0.446483, 0.438565,
+    0.432733, 0.331265, 0.394694, 0.308672, 0.356674, 0.351912, 0.206209, 0.385765, 0.392212,
+    0.400219, 0.447719, 0.447716, 0.439798, 0.433966, 0.326516, 0.404449, 0.319176, 0.366543,
+    0.362632, 0.361170, 0.209342, 0.395483, 0.403489, 0.446549, 0.446546, 0.438628, 0.432796,
+    0.321538, 0.400467, 0.316980, 0.372397, 0.368541, 0.367079, 0.370145, 0.208256, 0.403349,
+    0.447276, 0.447272, 0.438971, 0.433139, 0.320156, 0.403279, 0.313523, 0.376874, 0.373543,
+    0.374699, 0.377765, 0.377500, 0.212361, 0.500000, 0.180348, 0.328619, 0.331814, 0.335341,
+    0.329574, 0.342420, 0.338530, 0.466716, 0.466713, 0.460391, 0.447063, 0.434311, 0.324481,
+    0.318255, 0.183793, 0.378792, 0.389536, 0.396596, 0.407669, 0.411461, 0.462086, 0.462083,
+    0.453781, 0.450312, 0.437560, 0.325720, 0.320383, 0.355511, 0.197171, 0.385065, 0.392706,
+    0.403836, 0.408188, 0.460099, 0.460095, 0.451794, 0.445962, 0.443557, 0.330947, 0.322857,
+    0.365667, 0.361208, 0.210099, 0.391496, 0.402937, 0.410056, 0.456501, 0.456498, 0.448196,
+    0.442364, 0.437491, 0.335522, 0.316795, 0.372217, 0.368306, 0.366844, 0.217406, 0.392570,
+    0.408841, 0.458360, 0.458356, 0.450055, 0.444223, 0.439350, 0.331985, 0.328410, 0.382636,
+    0.378779, 0.377619, 0.367332, 0.220657, 0.412111, 0.456058, 0.456054, 0.447753, 0.441921,
+    0.442162, 0.330181, 0.324571, 0.386293, 0.382962, 0.384420, 0.382958, 0.386024, 0.224634,
+    0.500000, 0.645471, 0.656195, 0.671440, 0.686631, 0.701627, 0.699664, 0.687019, 0.687012,
+    0.683164, 0.675470, 0.671664, 0.667598, 0.609953, 0.180639, 0.537114, 0.545033, 0.552554,
+    0.557348, 0.570952, 0.681319, 0.681312, 0.676113, 0.675681, 0.671875, 0.667808, 0.619751,
+    0.511219, 0.183760, 0.528174, 0.535738, 0.540573, 0.554219, 0.679799, 0.679791, 0.674593,
+    0.672289, 0.676264, 0.672198, 0.633682, 0.518718, 0.503081, 0.187688, 0.535016, 0.539852,
+    0.553498, 0.678309, 0.678302, 0.673104, 0.670799, 0.672903, 0.676619, 0.647566, 0.525836,
+    0.510239, 0.509472, 0.191299, 0.538702, 0.552777, 0.676839, 0.676832, 0.671634, 0.669329,
+    0.671433, 0.673277, 0.661284, 0.530338, 0.514780, 0.514013, 0.512821, 0.191288, 0.551615,
+    0.666921, 0.666914, 0.661715, 0.659411, 0.675361, 0.673578, 0.659471, 0.543003, 0.527485,
+    0.526718, 0.525951, 0.524759, 0.195383, 0.500000, 0.283501, 0.290695, 0.300138, 0.307442,
+    0.303146, 0.642781, 0.641949, 0.641140, 0.634097, 0.633568, 0.631849, 0.475079, 0.129783,
+    0.275028, 0.283168, 0.289142, 0.284082, 0.297584, 0.435451, 0.435447, 0.435438, 0.429031,
+    0.415907, 0.403044, 0.272876, 0.267067, 0.141994, 0.338649, 0.350968, 0.358838, 0.369498,
+    0.433670, 0.433667, 0.433657, 0.425228, 0.422321, 0.409458, 0.278750, 0.273849, 0.324154,
+    0.156845, 0.353021, 0.360891, 0.371551, 0.432505, 0.432501, 0.432491, 0.424063, 0.418741,
+    0.416597, 0.286760, 0.278661, 0.335686, 0.337074, 0.170949, 0.359608, 0.370591, 0.429210,
+    0.429206, 0.429196, 0.420768, 0.415446, 0.410778, 0.292835, 0.273274, 0.342931, 0.344319,
+    0.342605, 0.178948, 0.359375, 0.426009, 0.426005, 0.425996, 0.417567, 0.417477, 0.411968,
+    0.288647, 0.285603, 0.352864, 0.354252, 0.352855, 0.341879, 0.182190, 0.500000, 0.275360,
+    0.285118, 0.296490, 0.292199, 0.630298, 0.629784, 0.628172, 0.616244, 0.607364, 0.598367,
+    0.677646, 0.295509, 0.303990, 0.363405, 0.373648, 0.384621, 0.395245, 0.641351, 0.640519,
+    0.635787, 0.635010, 0.634367, 0.632564, 0.475078, 0.291890, 0.114200, 0.265884, 0.273455,
+    0.268529, 0.282192, 0.422674, 0.422670, 0.418732, 0.418722, 0.413290, 0.400427, 0.264597,
+    0.346775, 0.257761, 0.141078, 0.333584, 0.344464, 0.355151, 0.421929, 0.421925, 0.417987,
+    0.417977, 0.410523, 0.407876, 0.272888, 0.356333, 0.264084, 0.319041, 0.155752, 0.346763,
+    0.357773, 0.421940, 0.421936, 0.417998, 0.417988, 0.410534, 0.405473, 0.282706, 0.366572,
+    0.258826, 0.329188, 0.330780, 0.167903, 0.360066, 0.418746, 0.418742, 0.414804, 0.414794,
+    0.412572, 0.406669, 0.278527, 0.376465, 0.271301, 0.339141, 0.341050, 0.342642, 0.171157,
+    0.500000, 0.269155, 0.280526, 0.276236, 0.621071, 0.620557, 0.618944, 0.606974, 0.598591,
+    0.589514, 0.670806, 0.290698, 0.578128, 0.302817, 0.361275, 0.372248, 0.382936, 0.620871,
+    0.620357, 0.615971, 0.617709, 0.609326, 0.600249, 0.685079, 0.545635, 0.284915, 0.280394,
+    0.348734, 0.359707, 0.370394, 0.639164, 0.638332, 0.633600, 0.632063, 0.635190, 0.633314,
+    0.475078, 0.290331, 0.269224, 0.096522, 0.259587, 0.254661, 0.268324, 0.409875, 0.409871,
+    0.405933, 0.402389, 0.402960, 0.397788, 0.258007, 0.344388, 0.332575, 0.251130, 0.138219,
+    0.333164, 0.344174, 0.409887, 0.409883, 0.405945, 0.402400, 0.402971, 0.395778, 0.267825,
+    0.354627, 0.342814, 0.245872, 0.317987, 0.150370, 0.346467, 0.406692, 0.406688, 0.402750,
+    0.399206, 0.405009, 0.396973, 0.263646, 0.364580, 0.352767, 0.258348, 0.328257, 0.329848,
+    0.153624, 0.500000, 0.264500, 0.260209, 0.610678, 0.610163, 0.608551, 0.596581, 0.588155,
+    0.579968, 0.661829, 0.285856, 0.570039, 0.580486, 0.301602, 0.359875, 0.370563, 0.610579,
+    0.610064, 0.605679, 0.607417, 0.598992, 0.590411, 0.675822, 0.537795, 0.279988, 0.562448,
+    0.279138, 0.347271, 0.357959, 0.611102, 0.610588, 0.606202, 0.604646, 0.610450, 0.601870,
+    0.690558, 0.547457, 0.530492, 0.281152, 0.269905, 0.350915, 0.361603, 0.635543, 0.634711,
+    0.629978, 0.628441, 0.631126, 0.634025, 0.475078, 0.288731, 0.267582, 0.258679, 0.079212,
+    0.240793, 0.254455, 0.397774, 0.397770, 0.393832, 0.390288, 0.387324, 0.388156, 0.252885,
+    0.342683, 0.330810, 0.334037, 0.232910, 0.132837, 0.332868, 0.394580, 0.394576, 0.390638,
+    0.387093, 0.389361, 0.389351, 0.248706, 0.352636, 0.340763, 0.343990, 0.245394, 0.317055,
+    0.136091, 0.500000, 0.244128, 0.600081, 0.599567, 0.597954, 0.585984, 0.577558, 0.568935,
+    0.654801, 0.280982, 0.561163, 0.571610, 0.582120, 0.300421, 0.358127, 0.599526, 0.599011,
+    0.594625, 0.596364, 0.587938, 0.579315, 0.665038, 0.529223, 0.275030, 0.553540, 0.564050,
+    0.277842, 0.345523, 0.600049, 0.599534, 0.595149, 0.593593, 0.599397, 0.590774, 0.679774,
+    0.538885, 0.521890, 0.276151, 0.564478, 0.268526, 0.349167, 0.600635, 0.600121, 0.595735,
+    0.594179, 0.596688, 0.602296, 0.694574, 0.548610, 0.531615, 0.531891, 0.277966, 0.258776,
+    0.352811, 0.630112, 0.629280, 0.624548, 0.623011, 0.625696, 0.627552, 0.475077, 0.287174,
+    0.265911, 0.256926, 0.247532, 0.062295, 0.240579, 0.382408, 0.382404, 0.378466, 0.374922,
+    0.377190, 0.373645, 0.233705, 0.340632, 0.328760, 0.331986, 0.335213, 0.232432, 0.118558,
+    0.500000, 0.607191, 0.606677, 0.605064, 0.593094, 0.581511, 0.573776, 0.658949, 0.288236,
+    0.563765, 0.574212, 0.584293, 0.592113, 0.307694, 0.603214, 0.602699, 0.598314, 0.600052,
+    0.588469, 0.580734, 0.669186, 0.531871, 0.282187, 0.556136, 0.566217, 0.574036, 0.282932,
+    0.603737, 0.603223, 0.598837, 0.597281, 0.599928, 0.592192, 0.683922, 0.541533, 0.524539,
+    0.283267, 0.566644, 0.574464, 0.272779, 0.604323, 0.603809, 0.599423, 0.597867, 0.597220,
+    0.603303, 0.698722, 0.550847, 0.533852, 0.534128, 0.284512, 0.574526, 0.266318, 0.604555,
+    0.604040, 0.599655, 0.598099, 0.597451, 0.600651, 0.713584, 0.557987, 0.540992, 0.541268,
+    0.541188, 0.283328, 0.260674, 0.619493, 0.618661, 0.613928, 0.612391, 0.627751, 0.628034,
+    0.475077, 0.294001, 0.270697, 0.260929, 0.254565, 0.248950, 0.066539, 0.500000, 0.471702,
+    0.470487, 0.439920, 0.404571, 0.369054, 0.341868, 0.118013, 0.291126, 0.286993, 0.283798,
+    0.281759, 0.275600, 0.298397, 0.374401, 0.374398, 0.373571, 0.364916, 0.353347, 0.345670,
+    0.281819, 0.157088, 0.333999, 0.343176, 0.352096, 0.355484, 0.293047, 0.374608, 0.374605,
+    0.371071, 0.373583, 0.362014, 0.354337, 0.277637, 0.312084, 0.159487, 0.346330, 0.355250,
+    0.358637, 0.290232, 0.375918, 0.375914, 0.372380, 0.372185, 0.371783, 0.364106, 0.274334,
+    0.320654, 0.323495, 0.162122, 0.357882, 0.361270, 0.288156, 0.377365, 0.377361, 0.373827,
+    0.373632, 0.370523, 0.374058, 0.272118, 0.328972, 0.331813, 0.334165, 0.164354, 0.361135,
+    0.280045, 0.366847, 0.366844, 0.363310, 0.372277, 0.366658, 0.367381, 0.266235, 0.332132,
+    0.334973, 0.337325, 0.337060, 0.160441, 0.500000, 0.473139, 0.441110, 0.406089, 0.373514,
+    0.342661, 0.122013, 0.295904, 0.291813, 0.288658, 0.286662, 0.280544, 0.411982, 0.299848,
+    0.375601, 0.374775, 0.366608, 0.357656, 0.346147, 0.286237, 0.161145, 0.337734, 0.346911,
+    0.355832, 0.359219, 0.412189, 0.294498, 0.375808, 0.372274, 0.375275, 0.366323, 0.354814,
+    0.282095, 0.315614, 0.163544, 0.350065, 0.358985, 0.362373, 0.413499, 0.291683, 0.377118,
+    0.373584, 0.373877, 0.376092, 0.364583, 0.278832, 0.324184, 0.327025, 0.166180, 0.361618,
+    0.365005, 0.414946, 0.289608, 0.378565, 0.375031, 0.375324, 0.374832, 0.374535, 0.276657,
+    0.332502, 0.335343, 0.337695, 0.168412, 0.364871, 0.402680, 0.281496, 0.367988, 0.364454,
+    0.373864, 0.370967, 0.367858, 0.270814, 0.335662, 0.338503, 0.340855, 0.340590, 0.164499,
+    0.500000, 0.441753, 0.406772, 0.374237, 0.343425, 0.122020, 0.296997, 0.292948, 0.289835,
+    0.287880, 0.281804, 0.412458, 0.409006, 0.299848, 0.372723, 0.364556, 0.356093, 0.347690,
+    0.287273, 0.161152, 0.338179, 0.347356, 0.356277, 0.359664, 0.412666, 0.409213, 0.294498,
+    0.370606, 0.373223, 0.364760, 0.356356, 0.283171, 0.316032, 0.163551, 0.350510, 0.359430,
+    0.362818, 0.413975, 0.410523, 0.291683, 0.371916, 0.371825, 0.374529, 0.366125, 0.279948,
+    0.324601, 0.327442, 0.166187, 0.362063, 0.365450, 0.415422, 0.411970, 0.289607, 0.373363,
+    0.373272, 0.373269, 0.376077, 0.277813, 0.332919, 0.335760, 0.338113, 0.168419, 0.365316,
+    0.403098, 0.399645, 0.281496, 0.362786, 0.371812, 0.369404, 0.369400, 0.272010, 0.336079,
+    0.338920, 0.341272, 0.341007, 0.164506, 0.500000, 0.414130, 0.379063, 0.350392, 0.139189,
+    0.298076, 0.308892, 0.305820, 0.303906, 0.297871, 0.424505, 0.421053, 0.421049, 0.313679,
+    0.376173, 0.367710, 0.359306, 0.288250, 0.177107, 0.351065, 0.360242, 0.369163, 0.372550,
+    0.424635, 0.421183, 0.421179, 0.306396, 0.384885, 0.376422, 0.368018, 0.297960, 0.328321,
+    0.180003, 0.363513, 0.372433, 0.375820, 0.425945, 0.422493, 0.422489, 0.303581, 0.383487,
+    0.386191, 0.377787, 0.294778, 0.336891, 0.339957, 0.182638, 0.375065, 0.378453, 0.427392,
+    0.423940, 0.423936, 0.301505, 0.384934, 0.384931, 0.387739, 0.292683, 0.345209, 0.348275,
+    0.350628, 0.184870, 0.378318, 0.415068, 0.411615, 0.411612, 0.293394, 0.383475, 0.381066,
+    0.381062, 0.286920, 0.348369, 0.351435, 0.353787, 0.353523, 0.180957, 0.500000, 0.381774,
+    0.351372, 0.152107, 0.312674, 0.306067, 0.317861, 0.315989, 0.313849, 0.437985, 0.434532,
+    0.434528, 0.428590, 0.313067, 0.376428, 0.368024, 0.302216, 0.180736, 0.359441, 0.370769,
+    0.379690, 0.386234, 0.436390, 0.432938, 0.432934, 0.425017, 0.318540, 0.384682, 0.376279,
+    0.295360, 0.336582, 0.191646, 0.372327, 0.381247, 0.387791, 0.437623, 0.434171, 0.434167,
+    0.426250, 0.313791, 0.394497, 0.386093, 0.305989, 0.347246, 0.348402, 0.194778, 0.384517,
+    0.391062, 0.439070, 0.435618, 0.435614, 0.427696, 0.311716, 0.393236, 0.396045, 0.303934,
+    0.355565, 0.356720, 0.359786, 0.197011, 0.390927, 0.435964, 0.432128, 0.432124, 0.424207,
+    0.306626, 0.392216, 0.392212, 0.301626, 0.361568, 0.362724, 0.365790, 0.365525, 0.195924,
+    0.500000, 0.353458, 0.165244, 0.316106, 0.320904, 0.315275, 0.328268, 0.325697, 0.451596,
+    0.447760, 0.447757, 0.441819, 0.429067, 0.312691, 0.376846, 0.305867, 0.182086, 0.368917,
+    0.378950, 0.390104, 0.395760, 0.450002, 0.446166, 0.446163, 0.438245, 0.435352, 0.318163,
+    0.385181, 0.309544, 0.345877, 0.195535, 0.380507, 0.391972, 0.397629, 0.449022, 0.445186,
+    0.445182, 0.437265, 0.432008, 0.325699, 0.394154, 0.303607, 0.355308, 0.356463, 0.206161,
+    0.390762, 0.396419, 0.450881, 0.447045, 0.447041, 0.439123, 0.433867, 0.322162, 0.404535,
+    0.315364, 0.365803, 0.367260, 0.365799, 0.209411, 0.399689, 0.444746, 0.440910, 0.440906,
+    0.432989, 0.432846, 0.316651, 0.399883, 0.312674, 0.370987, 0.372444, 0.370983, 0.374049,
+    0.208197, 0.500000, 0.178302, 0.316566, 0.324314, 0.330090, 0.325652, 0.337954, 0.461287,
+    0.461284, 0.461280, 0.454958, 0.442206, 0.429698, 0.312343, 0.306799, 0.183024, 0.376141,
+    0.388052, 0.395772, 0.406111, 0.459723, 0.459719, 0.459715, 0.451414, 0.448521, 0.436013,
+    0.317845, 0.313178, 0.352983, 0.196885, 0.389920, 0.397640, 0.407979, 0.458743, 0.458739,
+    0.458735, 0.450434, 0.445177, 0.443017, 0.325380, 0.317774, 0.364242, 0.365699, 0.210050,
+    0.396431, 0.407081, 0.455771, 0.455767, 0.455764, 0.447462, 0.442206, 0.437577, 0.331167,
+    0.312964, 0.371417, 0.372875, 0.371413, 0.217475, 0.396714, 0.452665, 0.452662, 0.452658,
+    0.444357, 0.444214, 0.438766, 0.327098, 0.324086, 0.381166, 0.382623, 0.381464, 0.371176,
+    0.220598, 0.500000, 0.647158, 0.662528, 0.677930, 0.693279, 0.704511, 0.688501, 0.684868,
+    0.684861, 0.681013, 0.677206, 0.673140, 0.669073, 0.611549, 0.179988, 0.538235, 0.546270,
+    0.553907, 0.555304, 0.687099, 0.683466, 0.683459, 0.678260, 0.681714, 0.677648, 0.673581,
+    0.625599, 0.512271, 0.183917, 0.545548, 0.553186, 0.554583, 0.685729, 0.682095, 0.682088,
+    0.676889, 0.678472, 0.682187, 0.678121, 0.639680, 0.519880, 0.519113, 0.188097, 0.552465,
+    0.553862, 0.684389, 0.680756, 0.680748, 0.675550, 0.677133, 0.678976, 0.682692, 0.653714,
+    0.527109, 0.526341, 0.525574, 0.191834, 0.552712, 0.669223, 0.665589, 0.665582, 0.660383,
+    0.675812, 0.674029, 0.675873, 0.663997, 0.528506, 0.527739, 0.526971, 0.525780, 0.191394,
+    0.500000, 0.274846, 0.284636, 0.296016, 0.306713, 0.668119, 0.663633, 0.662791, 0.661973,
+    0.658792, 0.658196, 0.657066, 0.475079, 0.128313, 0.266407, 0.277437, 0.284822, 0.279192,
+    0.433672, 0.432827, 0.432823, 0.432813, 0.426987, 0.414124, 0.401261, 0.264596, 0.258832,
+    0.141986, 0.341196, 0.354027, 0.364140, 0.432958, 0.432113, 0.432109, 0.432099, 0.424251,
+    0.421605, 0.408742, 0.272918, 0.268343, 0.326476, 0.157089, 0.356649, 0.366762, 0.432976,
+    0.432130, 0.432127, 0.432117, 0.424269, 0.419208, 0.416955, 0.282736, 0.274485, 0.338501,
+    0.340410, 0.171889, 0.369062, 0.427911, 0.427066, 0.427062, 0.427053, 0.424437, 0.418534,
+    0.413866, 0.291936, 0.268580, 0.347963, 0.349872, 0.351464, 0.183898, 0.500000, 0.268610,
+    0.279989, 0.290750, 0.632236, 0.628507, 0.628056, 0.626507, 0.618124, 0.609046, 0.600113,
+    0.685921, 0.289068, 0.281564, 0.358114, 0.369176, 0.379383, 0.666681, 0.662195, 0.661354,
+    0.656611, 0.659697, 0.658986, 0.657774, 0.475079, 0.270890, 0.110719, 0.263569, 0.270954,
+    0.265324, 0.420905, 0.420059, 0.420056, 0.416118, 0.416688, 0.411517, 0.398654, 0.257977,
+    0.341714, 0.255390, 0.139556, 0.343049, 0.353163, 0.420922, 0.420077, 0.420073, 0.416135,
+    0.416706, 0.409513, 0.406867, 0.267796, 0.352035, 0.261532, 0.327617, 0.154356, 0.355462,
+    0.415858, 0.415013, 0.415009, 0.411071, 0.416874, 0.408839, 0.403778, 0.277055, 0.361588,
+    0.255626, 0.337078, 0.338670, 0.166365, 0.500000, 0.263963, 0.274723, 0.621944, 0.618215,
+    0.617764, 0.616215, 0.607789, 0.599208, 0.590194, 0.676634, 0.284142, 0.576099, 0.280391,
+    0.356740, 0.366948, 0.622531, 0.618802, 0.618350, 0.614028, 0.619311, 0.610730, 0.601716,
+    0.691433, 0.543625, 0.285305, 0.270436, 0.360385, 0.370592, 0.664696, 0.660210, 0.659369,
+    0.654626, 0.656952, 0.659809, 0.658524, 0.475079, 0.269330, 0.259759, 0.093451, 0.257085,
+    0.251456, 0.408810, 0.407965, 0.407961, 0.404023, 0.401059, 0.401891, 0.396719, 0.252856,
+    0.340031, 0.343258, 0.248578, 0.136823, 0.341863, 0.403746, 0.402900, 0.402897, 0.398959,
+    0.401227, 0.401217, 0.394023, 0.262115, 0.349585, 0.352812, 0.242673, 0.325876, 0.148832,
+    0.500000, 0.258634, 0.610891, 0.607162, 0.606710, 0.605161, 0.596735, 0.588112, 0.579595,
+    0.665850, 0.279183, 0.567191, 0.577701, 0.279176, 0.354512, 0.611477, 0.607748, 0.607297,
+    0.602974, 0.608257, 0.599634, 0.591117, 0.680649, 0.535024, 0.280305, 0.578129, 0.269180,
+    0.358156, 0.612064, 0.608335, 0.607883, 0.603561, 0.605549, 0.611156, 0.602639, 0.695449,
+    0.544749, 0.545024, 0.282120, 0.262843, 0.361800, 0.661675, 0.657189, 0.656347, 0.651605,
+    0.653930, 0.656345, 0.659234, 0.475079, 0.267730, 0.258118, 0.251876, 0.076551, 0.237587,
+    0.391574, 0.390729, 0.390725, 0.386787, 0.389055, 0.385510, 0.386342, 0.247115, 0.337581,
+    0.340808, 0.344035, 0.229711, 0.131299, 0.500000, 0.602903, 0.599174, 0.598723, 0.597174,
+    0.585591, 0.577855, 0.569295, 0.655715, 0.278135, 0.559081, 0.569591, 0.580164, 0.279713,
+    0.603427, 0.599698, 0.599246, 0.594924, 0.597050, 0.589314, 0.580754, 0.670451, 0.527185,
+    0.279214, 0.570018, 0.580591, 0.269602, 0.604013, 0.600284, 0.599833, 0.595510, 0.594342,
+    0.600836, 0.592276, 0.685251, 0.536910, 0.537185, 0.281029, 0.581082, 0.263182, 0.604662,
+    0.600933, 0.600482, 0.596159, 0.594991, 0.598191, 0.603861, 0.700113, 0.546698, 0.546974,
+    0.547312, 0.283580, 0.257585, 0.644609, 0.640123, 0.639281, 0.634539, 0.649540, 0.650381,
+    0.652487, 0.475078, 0.267763, 0.258037, 0.251713, 0.246140, 0.062182, 0.500000, 0.471702,
+    0.468614, 0.446297, 0.409683, 0.371195, 0.343127, 0.318179, 0.116605, 0.282084, 0.280398,
+    0.278490, 0.272486, 0.290463, 0.372558, 0.371735, 0.371731, 0.373859, 0.362290, 0.354554,
+    0.343824, 0.273156, 0.157060, 0.336342, 0.345598, 0.351852, 0.287422, 0.373772, 0.372949,
+    0.372945, 0.372366, 0.371964, 0.364228, 0.353497, 0.271282, 0.314250, 0.159696, 0.348752,
+    0.355006, 0.285819, 0.375708, 0.374884, 0.374880, 0.374301, 0.371192, 0.374623, 0.363892,
+    0.269191, 0.322897, 0.325738, 0.162450, 0.358159, 0.281084, 0.368296, 0.367472, 0.367469,
+    0.376052, 0.370433, 0.371156, 0.371533, 0.263450, 0.328850, 0.331691, 0.334532, 0.162376,
+    0.500000, 0.471266, 0.447487, 0.411200, 0.375655, 0.343920, 0.322336, 0.120605, 0.286903,
+    0.285259, 0.283392, 0.277429, 0.410139, 0.291914, 0.372938, 0.372934, 0.375551, 0.366599,
+    0.355031, 0.347353, 0.277614, 0.161118, 0.340077, 0.349334, 0.355587, 0.411353, 0.288873,
+    0.374152, 0.374148, 0.374058, 0.376273, 0.364704, 0.357027, 0.275781, 0.317780, 0.163753,
+    0.352487, 0.358741, 0.413288, 0.287271, 0.376088, 0.376084, 0.375993, 0.375501, 0.375099,
+    0.367422, 0.273730, 0.326427, 0.329268, 0.166507, 0.361895, 0.404129, 0.282535, 0.368617,
+    0.368613, 0.377639, 0.374742, 0.371633, 0.375063, 0.268029, 0.332380, 0.335221, 0.338062,
+    0.166434, 0.500000, 0.449913, 0.412401, 0.377183, 0.348391, 0.323141, 0.124605, 0.291659,
+    0.290057, 0.288231, 0.282310, 0.413669, 0.410216, 0.293366, 0.374079, 0.376695, 0.368232,
+    0.359281, 0.347771, 0.282014, 0.165175, 0.343749, 0.353006, 0.359260, 0.414883, 0.411431,
+    0.290324, 0.375293, 0.375202, 0.377906, 0.368954, 0.357445, 0.280221, 0.321251, 0.167811,
+    0.356159, 0.362413, 0.416818, 0.413366, 0.288722, 0.377228, 0.377137, 0.377134, 0.379349,
+    0.367840, 0.278211, 0.329898, 0.332739, 0.170565, 0.365567, 0.407600, 0.404147, 0.283986,
+    0.369757, 0.378783, 0.376375, 0.375883, 0.375481, 0.272550, 0.335851, 0.338692, 0.341533,
+    0.170491, 0.500000, 0.413055, 0.377878, 0.349126, 0.323916, 0.124612, 0.292732, 0.291171,
+    0.289386, 0.283507, 0.414086, 0.410634, 0.407181, 0.293365, 0.374584, 0.366121, 0.357658,
+    0.349254, 0.283032, 0.165182, 0.344131, 0.353388, 0.359641, 0.415300, 0.411848, 0.408396,
+    0.290324, 0.373475, 0.375795, 0.367332, 0.358928, 0.281279, 0.321609, 0.167818, 0.356541,
+    0.362795, 0.417236, 0.413783, 0.410331, 0.288722, 0.375410, 0.375023, 0.377727, 0.369323,
+    0.279309, 0.330256, 0.333097, 0.170572, 0.365949, 0.408017, 0.404565, 0.401112, 0.283986,
+    0.377056, 0.374264, 0.374260, 0.376964, 0.273689, 0.336209, 0.339050, 0.341891, 0.170498,
+    0.500000, 0.380658, 0.350217, 0.327334, 0.137530, 0.289907, 0.303212, 0.301469, 0.299485,
+    0.425841, 0.422389, 0.418936, 0.418933, 0.305509, 0.374382, 0.365919, 0.357515, 0.280431,
+    0.176825, 0.353466, 0.362723, 0.372134, 0.426978, 0.423526, 0.420074, 0.420070, 0.300534,
+    0.384101, 0.375638, 0.367234, 0.292490, 0.330542, 0.179958, 0.365993, 0.375404, 0.428914,
+    0.425462, 0.422009, 0.422006, 0.298932, 0.383329, 0.386033, 0.377629, 0.290560, 0.339190,
+    0.342256, 0.182712, 0.378557, 0.428914, 0.425078, 0.421625, 0.421622, 0.297218, 0.385414,
+    0.385410, 0.388114, 0.288394, 0.347986, 0.351053, 0.353893, 0.185465, 0.500000, 0.352383,
+    0.328376, 0.150707, 0.304744, 0.300626, 0.313748, 0.311332, 0.439453, 0.435617, 0.432165,
+    0.432161, 0.426798, 0.305133, 0.374821, 0.366417, 0.294616, 0.180715, 0.362040, 0.373448,
+    0.381971, 0.438866, 0.435030, 0.431578, 0.431574, 0.424232, 0.312915, 0.384083, 0.375679,
+    0.290109, 0.338987, 0.191862, 0.375526, 0.384049, 0.440725, 0.436889, 0.433436, 0.433433,
+    0.426091, 0.309378, 0.394523, 0.386119, 0.301990, 0.349730, 0.351374, 0.195113, 0.387320,
+    0.437695, 0.433860, 0.430407, 0.430403, 0.428176, 0.307243, 0.393081, 0.395785, 0.299442,
+    0.357707, 0.359351, 0.362417, 0.197738, 0.500000, 0.329917, 0.163844, 0.308154, 0.315441,
+    0.311140, 0.323590, 0.449173, 0.449170, 0.445334, 0.445330, 0.439968, 0.427460, 0.304757,
+    0.375180, 0.298249, 0.182065, 0.371453, 0.381959, 0.392322, 0.448586, 0.448583, 0.444747,
+    0.444743, 0.437401, 0.434752, 0.312539, 0.384522, 0.304275, 0.348224, 0.195751, 0.384037,
+    0.394711, 0.448721, 0.448717, 0.444881, 0.444877, 0.437535, 0.432523, 0.321759, 0.394505,
+    0.299590, 0.358116, 0.359760, 0.207016, 0.396789, 0.445615, 0.445611, 0.441775, 0.441771,
+    0.439543, 0.433711, 0.317689, 0.404216, 0.310855, 0.367886, 0.369832, 0.371476, 0.210139,
+    0.500000, 0.176902, 0.308354, 0.318829, 0.325934, 0.320960, 0.459625, 0.458802, 0.458798,
+    0.458794, 0.453048, 0.440540, 0.428033, 0.304410, 0.298938, 0.183004, 0.378614, 0.390997,
+    0.400770, 0.459068, 0.458244, 0.458240, 0.458237, 0.450511, 0.447862, 0.435354, 0.312220,
+    0.307892, 0.355271, 0.197102, 0.393387, 0.403159, 0.459202, 0.458378, 0.458375, 0.458371,
+    0.450645, 0.445633, 0.443368, 0.321441, 0.313740, 0.366991, 0.368937, 0.210906, 0.405237,
+    0.454371, 0.453548, 0.453544, 0.453540, 0.450929, 0.445097, 0.440468, 0.330070, 0.308438,
+    0.376213, 0.378159, 0.379803, 0.222042, 0.500000, 0.648781, 0.664267, 0.679784, 0.691328,
+    0.694491, 0.690857, 0.687223, 0.687216, 0.687255, 0.683189, 0.679122, 0.675055, 0.613079,
+    0.180144, 0.557559, 0.565625, 0.570210, 0.693199, 0.689565, 0.685932, 0.685924, 0.684613,
+    0.687807, 0.683740, 0.679674, 0.627239, 0.530067, 0.184325, 0.564904, 0.569489, 0.691938,
+    0.688305, 0.684671, 0.684664, 0.683353, 0.684675, 0.688390, 0.684324, 0.641430, 0.537707,
+    0.536940, 0.188632, 0.568768, 0.676863, 0.673230, 0.669596, 0.669589, 0.682124, 0.679820,
+    0.681663, 0.685378, 0.651989, 0.542164, 0.541397, 0.540630, 0.192509, 0.500000, 0.268257,
+    0.279669, 0.290437, 0.689537, 0.685010, 0.680514, 0.679662, 0.682706, 0.679255, 0.678852,
+    0.678053, 0.475079, 0.129745, 0.264445, 0.275857, 0.286542, 0.435525, 0.434680, 0.433835,
+    0.433831, 0.434402, 0.428837, 0.415974, 0.403111, 0.258259, 0.256931, 0.142301, 0.353773,
+    0.366161, 0.435575, 0.434729, 0.433884, 0.433881, 0.434451, 0.426865, 0.424218, 0.411355,
+    0.268109, 0.266781, 0.338048, 0.157530, 0.368784, 0.430517, 0.429672, 0.428826, 0.428823,
+    0.434625, 0.426197, 0.421136, 0.418883, 0.277368, 0.275959, 0.349699, 0.351609, 0.172618,
+    0.500000, 0.263579, 0.274348, 0.637267, 0.633538, 0.629872, 0.629484, 0.631479, 0.622899,
+    0.613884, 0.605014, 0.692247, 0.291725, 0.272209, 0.373200, 0.383497, 0.688099, 0.683572,
+    0.679076, 0.678225, 0.677345, 0.680161, 0.679642, 0.678760, 0.475079, 0.262127, 0.112561,
+    0.261989, 0.272674, 0.423462, 0.422617, 0.421772, 0.421768, 0.418411, 0.419243, 0.414071,
+    0.401208, 0.253109, 0.355705, 0.253827, 0.139997, 0.355184, 0.418404, 0.417559, 0.416714,
+    0.416710, 0.418585, 0.418575, 0.411382, 0.408735, 0.262368, 0.365341, 0.263005, 0.338815,
+    0.155085, 0.500000, 0.258258, 0.626214, 0.622485, 0.618819, 0.618430, 0.620426, 0.611802,
+    0.603285, 0.594334, 0.681434, 0.286724, 0.595602, 0.271036, 0.371061, 0.626863, 0.623134,
+    0.619468, 0.619080, 0.618302, 0.623387, 0.614870, 0.605919, 0.696296, 0.561836, 0.288540,
+    0.263759, 0.374705, 0.686316, 0.681789, 0.677293, 0.676442, 0.675562, 0.677618, 0.680465,
+    0.679510, 0.475079, 0.260567, 0.253455, 0.095704, 0.258806, 0.406233, 0.405387, 0.404542,
+    0.404538, 0.406413, 0.402869, 0.403700, 0.398528, 0.247368, 0.353337, 0.356564, 0.250052,
+    0.137552, 0.500000, 0.618163, 0.614434, 0.610768, 0.610380, 0.609218, 0.601482, 0.592922,
+    0.584468, 0.671236, 0.285634, 0.587491, 0.598064, 0.271540, 0.618813, 0.615083, 0.611417,
+    0.611029, 0.607094, 0.613067, 0.604507, 0.596053, 0.686098, 0.553997, 0.287449, 0.598555,
+    0.264221, 0.619462, 0.615733, 0.612067, 0.611679, 0.607744, 0.610422, 0.616092, 0.607638,
+    0.700961, 0.563785, 0.564124, 0.290000, 0.262606, 0.670960, 0.666433, 0.661937, 0.661086,
+    0.672881, 0.673363, 0.675768, 0.678647, 0.475079, 0.260557, 0.253404, 0.251503, 0.081352,
+    0.500000, 0.471702, 0.468614, 0.444424, 0.415823, 0.375277, 0.345082, 0.319438, 0.298126,
+    0.113357, 0.278816, 0.276908, 0.270904, 0.284222, 0.370841, 0.370017, 0.369194, 0.372144,
+    0.371359, 0.363623, 0.352833, 0.338948, 0.269831, 0.155652, 0.345528, 0.351782, 0.282619,
+    0.372776, 0.371953, 0.371129, 0.374080, 0.370587, 0.374018, 0.363228, 0.349343, 0.267740,
+    0.322688, 0.158406, 0.354936, 0.277883, 0.365364, 0.364541, 0.363717, 0.375831, 0.369828,
+    0.370551, 0.370928, 0.357043, 0.261998, 0.328641, 0.331482, 0.158332, 0.500000, 0.471266,
+    0.445613, 0.417340, 0.379737, 0.345875, 0.323595, 0.302324, 0.117357, 0.283677, 0.281810,
+    0.275847, 0.408421, 0.285673, 0.371221, 0.370397, 0.373836, 0.375668, 0.364099, 0.356363,
+    0.342478, 0.274329, 0.159710, 0.349264, 0.355517, 0.410357, 0.284070, 0.373156, 0.372332,
+    0.375772, 0.374896, 0.374494, 0.366758, 0.352873, 0.272278, 0.326218, 0.162464, 0.358671,
+    0.401197, 0.279335, 0.365685, 0.364861, 0.377418, 0.374137, 0.371028, 0.374458, 0.360573,
+    0.266577, 0.332171, 0.335012, 0.162390, 0.500000, 0.448040, 0.418541, 0.381266, 0.350347,
+    0.324400, 0.306493, 0.121357, 0.288475, 0.286649, 0.280728, 0.411951, 0.408499, 0.287124,
+    0.371541, 0.374981, 0.377301, 0.368349, 0.356781, 0.345948, 0.278770, 0.163767, 0.352936,
+    0.359190, 0.413887, 0.410434, 0.285522, 0.373477, 0.376916, 0.376529, 0.378744, 0.367176,
+    0.356343, 0.276759, 0.329689, 0.166521, 0.362343, 0.404668, 0.401216, 0.280786, 0.366006,
+    0.378562, 0.375770, 0.375278, 0.374876, 0.364044, 0.271098, 0.335642, 0.338483, 0.166448,
+    0.500000, 0.420742, 0.382478, 0.351887, 0.328883, 0.307309, 0.125357, 0.293210, 0.291425,
+    0.285546, 0.415422, 0.411970, 0.408517, 0.288576, 0.376066, 0.378386, 0.369923, 0.360971,
+    0.346307, 0.283153, 0.167825, 0.356545, 0.362799, 0.417358, 0.413905, 0.410453, 0.286973,
+    0.378001, 0.377614, 0.380318, 0.371366, 0.356702, 0.281182, 0.333101, 0.170579, 0.365952,
+    0.408139, 0.404687, 0.401234, 0.282237, 0.379647, 0.376855, 0.376851, 0.379067, 0.364402,
+    0.275562, 0.339053, 0.341894, 0.170505, 0.500000, 0.379583, 0.349032, 0.326068, 0.304535,
+    0.121113, 0.290380, 0.288637, 0.286652, 0.415548, 0.412095, 0.408643, 0.405191, 0.286887,
+    0.372919, 0.364456, 0.355993, 0.344434, 0.280592, 0.163521, 0.353376, 0.362787, 0.417483,
+    0.414031, 0.410579, 0.407126, 0.285285, 0.372530, 0.374851, 0.366388, 0.354829, 0.278662,
+    0.330103, 0.166275, 0.365940, 0.417483, 0.413647, 0.410195, 0.406742, 0.283571, 0.374615,
+    0.374228, 0.376932, 0.365373, 0.276496, 0.338899, 0.341740, 0.169028, 0.500000, 0.351267,
+    0.327221, 0.308200, 0.134290, 0.287793, 0.300916, 0.298500, 0.427435, 0.423599, 0.420147,
+    0.416695, 0.417266, 0.299268, 0.372901, 0.364438, 0.352879, 0.278211, 0.175424, 0.362909,
+    0.371432, 0.429294, 0.425458, 0.422006, 0.418553, 0.419125, 0.295732, 0.383341, 0.374878,
+    0.363319, 0.290092, 0.339221, 0.178675, 0.374702, 0.426265, 0.422429, 0.418977, 0.415524,
+    0.421210, 0.293596, 0.381899, 0.384603, 0.373044, 0.287544, 0.347198, 0.350264, 0.181301,
+    0.500000, 0.328842, 0.309069, 0.147466, 0.302609, 0.298308, 0.310758, 0.437156, 0.437152,
+    0.433316, 0.429864, 0.430436, 0.425318, 0.298892, 0.373281, 0.361722, 0.292377, 0.179314,
+    0.371420, 0.382094, 0.437290, 0.437286, 0.433450, 0.429998, 0.430570, 0.423472, 0.308112,
+    0.383264, 0.371705, 0.287692, 0.347607, 0.190579, 0.384172, 0.434184, 0.434180, 0.430344,
+    0.426892, 0.432578, 0.424661, 0.304043, 0.393034, 0.381475, 0.298957, 0.357679, 0.359323,
+    0.193701, 0.500000, 0.310302, 0.160603, 0.305997, 0.313102, 0.308128, 0.447637, 0.446813,
+    0.446810, 0.442974, 0.443546, 0.438427, 0.425920, 0.298516, 0.370426, 0.295994, 0.180664,
+    0.380769, 0.390542, 0.447771, 0.446948, 0.446944, 0.443108, 0.443680, 0.436582, 0.433933,
+    0.307736, 0.380489, 0.301842, 0.356784, 0.194469, 0.392620, 0.442941, 0.442117, 0.442114,
+    0.438278, 0.443964, 0.436046, 0.431034, 0.316423, 0.389802, 0.296540, 0.366006, 0.367650,
+    0.205605, 0.500000, 0.178170, 0.306399, 0.317212, 0.327327, 0.461487, 0.460663, 0.459840,
+    0.459836, 0.460408, 0.454906, 0.442398, 0.429891, 0.298315, 0.296996, 0.183301, 0.390789,
+    0.402750, 0.461650, 0.460827, 0.460003, 0.460000, 0.460571, 0.453090, 0.450441, 0.437934,
+    0.307564, 0.306245, 0.366624, 0.197517, 0.405139, 0.456820, 0.455996, 0.455173, 0.455169,
+    0.460855, 0.452554, 0.447542, 0.445277, 0.316251, 0.314852, 0.377976, 0.379922, 0.211605,
+    0.500000, 0.667348, 0.682866, 0.694493, 0.694032, 0.690399, 0.686765, 0.683131, 0.687011,
+    0.686790, 0.682723, 0.678657, 0.672480, 0.630107, 0.180335, 0.564644, 0.569230, 0.692811,
+    0.689178, 0.685544, 0.681911, 0.685790, 0.684218, 0.687412, 0.683346, 0.677169, 0.644298,
+    0.536698, 0.184642, 0.568509, 0.677776, 0.674142, 0.670508, 0.666875, 0.684601, 0.679402,
+    0.680725, 0.684440, 0.678264, 0.654936, 0.541155, 0.540388, 0.188519, 0.500000, 0.261861,
+    0.272630, 0.699601, 0.695033, 0.690495, 0.685990, 0.689000, 0.691775, 0.688314, 0.688102,
+    0.686867, 0.475079, 0.108205, 0.258633, 0.269401, 0.420258, 0.419413, 0.418567, 0.417722,
+    0.418299, 0.419131, 0.413566, 0.400703, 0.384296, 0.251519, 0.250763, 0.135622, 0.351663,
+    0.415200, 0.414355, 0.413510, 0.412664, 0.418473, 0.418463, 0.410877, 0.408230, 0.391824,
+    0.260778, 0.260023, 0.335552, 0.150710, 0.500000, 0.256540, 0.625973, 0.622244, 0.618578,
+    0.614975, 0.618131, 0.619929, 0.611412, 0.602460, 0.590420, 0.697878, 0.284594, 0.260403,
+    0.371184, 0.698164, 0.693595, 0.689057, 0.684552, 0.687562, 0.686413, 0.689219, 0.688893,
+    0.687575, 0.475079, 0.250391, 0.091347, 0.255533, 0.403028, 0.402183, 0.401338, 0.400493,
+    0.406302, 0.402364, 0.403195, 0.398024, 0.381617, 0.245778, 0.353301, 0.247069, 0.133177,
+    0.500000, 0.617922, 0.614193, 0.610527, 0.606924, 0.606924, 0.609609, 0.601049, 0.592595,
+    0.580474, 0.687680, 0.283503, 0.597618, 0.260948, 0.618572, 0.614843, 0.611177, 0.607574,
+    0.607573, 0.607485, 0.612634, 0.604180, 0.592059, 0.702542, 0.563233, 0.286054, 0.259334,
+    0.683908, 0.679339, 0.674802, 0.670296, 0.685982, 0.683259, 0.685305, 0.688142, 0.686751,
+    0.475079, 0.250421, 0.248520, 0.076995, 0.500000, 0.471702, 0.468614, 0.444424, 0.413950,
+    0.380944, 0.348372, 0.321207, 0.299386, 0.288523, 0.110496, 0.275326, 0.269322, 0.279361,
+    0.369785, 0.368962, 0.368138, 0.370269, 0.370306, 0.373353, 0.362564, 0.348619, 0.349436,
+    0.266289, 0.154362, 0.351712, 0.274626, 0.362373, 0.361550, 0.360726, 0.372020, 0.369547,
+    0.369887, 0.370264, 0.356320, 0.357136, 0.260547, 0.328432, 0.154289, 0.500000, 0.471266,
+    0.445613, 0.415467, 0.385404, 0.349165, 0.325365, 0.303583, 0.292761, 0.114496, 0.280228,
+    0.274266, 0.407366, 0.280813, 0.370165, 0.369342, 0.371961, 0.374616, 0.373830, 0.366094,
+    0.352149, 0.352966, 0.270827, 0.158420, 0.355447, 0.398206, 0.276077, 0.362694, 0.361871,
+    0.373607, 0.373856, 0.370363, 0.373794, 0.359850, 0.360666, 0.265126, 0.331962, 0.158346,
+    0.500000, 0.448040, 0.416668, 0.386933, 0.353636, 0.326169, 0.307752, 0.296971, 0.118496,
+    0.285067, 0.279146, 0.410896, 0.407444, 0.282264, 0.370486, 0.373105, 0.376248, 0.378080,
+    0.366511, 0.355620, 0.356437, 0.275308, 0.162478, 0.359119, 0.401677, 0.398225, 0.277528,
+    0.363015, 0.374751, 0.375489, 0.374613, 0.374212, 0.363320, 0.364137, 0.269647, 0.335433,
+    0.162404, 0.500000, 0.418869, 0.388145, 0.355176, 0.330652, 0.308568, 0.301151, 0.122495,
+    0.289843, 0.283964, 0.414367, 0.410914, 0.407462, 0.283715, 0.374191, 0.377333, 0.379654,
+    0.370702, 0.355979, 0.359848, 0.279731, 0.166535, 0.362729, 0.405148, 0.401696, 0.398243,
+    0.278979, 0.375837, 0.376574, 0.376187, 0.378402, 0.363679, 0.367549, 0.274110, 0.338844,
+    0.166462, 0.500000, 0.386560, 0.352840, 0.328643, 0.309502, 0.298418, 0.122244, 0.290676,
+    0.288692, 0.417546, 0.414094, 0.410641, 0.407189, 0.283479, 0.375062, 0.377382, 0.368919,
+    0.356813, 0.356850, 0.280535, 0.166282, 0.365944, 0.417546, 0.413710, 0.410257, 0.406805,
+    0.281765, 0.377147, 0.376760, 0.379464, 0.367357, 0.367395, 0.278369, 0.341744, 0.169035,
+    0.500000, 0.350192, 0.326036, 0.306935, 0.295891, 0.118258, 0.288084, 0.285668, 0.417804,
+    0.413968, 0.410516, 0.407064, 0.404187, 0.282027, 0.372100, 0.363637, 0.352019, 0.355162,
+    0.278194, 0.162238, 0.362085, 0.414775, 0.410939, 0.407487, 0.404034, 0.406272, 0.279891,
+    0.371041, 0.373361, 0.361743, 0.364887, 0.275646, 0.338110, 0.164863, 0.500000, 0.327727,
+    0.307913, 0.299568, 0.131435, 0.285476, 0.297925, 0.425800, 0.425796, 0.421960, 0.418508,
+    0.415631, 0.416448, 0.294407, 0.372023, 0.360405, 0.363548, 0.275794, 0.174142, 0.371555,
+    0.422694, 0.422691, 0.418855, 0.415402, 0.417640, 0.417636, 0.290338, 0.381793, 0.370175,
+    0.373318, 0.287059, 0.347169, 0.177264, 0.500000, 0.309226, 0.300262, 0.144611, 0.300270,
+    0.295296, 0.436281, 0.435458, 0.435454, 0.431618, 0.428741, 0.429558, 0.424440, 0.294031,
+    0.369189, 0.372332, 0.289944, 0.178031, 0.380003, 0.431451, 0.430627, 0.430624, 0.426788,
+    0.429025, 0.429021, 0.421924, 0.302718, 0.378502, 0.381645, 0.284642, 0.355496, 0.189168,
+    0.500000, 0.302023, 0.162258, 0.304380, 0.314495, 0.450161, 0.449337, 0.448514, 0.448510,
+    0.445249, 0.446066, 0.440948, 0.428440, 0.293802, 0.384132, 0.294347, 0.181079, 0.392522,
+    0.445330, 0.444507, 0.443683, 0.443679, 0.445533, 0.445529, 0.438432, 0.435783, 0.302489,
+    0.393525, 0.302954, 0.367769, 0.195167, 0.500000, 0.158272, 0.301348, 0.311547, 0.447170,
+    0.446346, 0.445523, 0.444699, 0.445271, 0.446087, 0.440586, 0.428078, 0.412113, 0.292351,
+    0.291596, 0.177036, 0.389298, 0.442339, 0.441516, 0.440692, 0.439869, 0.445555, 0.445551,
+    0.438070, 0.435421, 0.419456, 0.301038, 0.300283, 0.364719, 0.191124, 0.500000, 0.685358,
+    0.696986, 0.693030, 0.689396, 0.685763, 0.682129, 0.682382, 0.686002, 0.685780, 0.681714,
+    0.675537, 0.679157, 0.646614, 0.180652, 0.567577, 0.677994, 0.674360, 0.670727, 0.667093,
+    0.681193, 0.681186, 0.679614, 0.682808, 0.676632, 0.680251, 0.657251, 0.539515, 0.184529,
+    0.500000, 0.254822, 0.707249, 0.702639, 0.698060, 0.693513, 0.692869, 0.695611, 0.698375,
+    0.694904, 0.694459, 0.697482, 0.475079, 0.086990, 0.252261, 0.399765, 0.398920, 0.398074,
+    0.397229, 0.402197, 0.402193, 0.403025, 0.397460, 0.381053, 0.381891, 0.244188, 0.244087,
+    0.128802, 0.500000, 0.617681, 0.613952, 0.610286, 0.606683, 0.603468, 0.607314, 0.609176,
+    0.600721, 0.588600, 0.591818, 0.704124, 0.282108, 0.256061, 0.693136, 0.688526, 0.683947,
+    0.679400, 0.691432, 0.692599, 0.691440, 0.694236, 0.693676, 0.696616, 0.475079, 0.245537,
+    0.072638, 0.500000, 0.471702, 0.468614, 0.444424, 0.413950, 0.379071, 0.353329, 0.323941,
+    0.300969, 0.289782, 0.281123, 0.108021, 0.267740, 0.271310, 0.359323, 0.358500, 0.357676,
+    0.368970, 0.365678, 0.369547, 0.369541, 0.355596, 0.356413, 0.357229, 0.259096, 0.150245,
+    0.500000, 0.471266, 0.445613, 0.415467, 0.383531, 0.354123, 0.328099, 0.305167, 0.294021,
+    0.285401, 0.112020, 0.272684, 0.395156, 0.272761, 0.359644, 0.358821, 0.370557, 0.369987,
+    0.370024, 0.373071, 0.359126, 0.359943, 0.360759, 0.263675, 0.154303, 0.500000, 0.448040,
+    0.416668, 0.385060, 0.358594, 0.328903, 0.309336, 0.298230, 0.289651, 0.116020, 0.277564,
+    0.398627, 0.395175, 0.274212, 0.359965, 0.371702, 0.371619, 0.374274, 0.373488, 0.362597,
+    0.363413, 0.364230, 0.268196, 0.158360, 0.500000, 0.418869, 0.386272, 0.360134, 0.333386,
+    0.310152, 0.302410, 0.293871, 0.120020, 0.282382, 0.402098, 0.398646, 0.395194, 0.275664,
+    0.372787, 0.372705, 0.375847, 0.377679, 0.362955, 0.366825, 0.367642, 0.272659, 0.162418,
+    0.500000, 0.384687, 0.357797, 0.331377, 0.311086, 0.299677, 0.294502, 0.119769, 0.287110,
+    0.414496, 0.410660, 0.407207, 0.403755, 0.278449, 0.373277, 0.376420, 0.378740, 0.366634,
+    0.366671, 0.370541, 0.276918, 0.164991, 0.500000, 0.356224, 0.329287, 0.309324, 0.300858,
+    0.292016, 0.119776, 0.287707, 0.414779, 0.410943, 0.407490, 0.404038, 0.406275, 0.278027,
+    0.373514, 0.375834, 0.364216, 0.366871, 0.366908, 0.277520, 0.164870, 0.500000, 0.326651,
+    0.306728, 0.298302, 0.289501, 0.115790, 0.285093, 0.411145, 0.411142, 0.407306, 0.403853,
+    0.406091, 0.402638, 0.276576, 0.370492, 0.358874, 0.362017, 0.365161, 0.275161, 0.160827,
+    0.500000, 0.308111, 0.299106, 0.293189, 0.128967, 0.282464, 0.419902, 0.419079, 0.419075,
+    0.415239, 0.417476, 0.414024, 0.414840, 0.288956, 0.367201, 0.370344, 0.373487, 0.272744,
+    0.172730, 0.500000, 0.300948, 0.294227, 0.146652, 0.301663, 0.433781, 0.432958, 0.432134,
+    0.432131, 0.433984, 0.430532, 0.431348, 0.426230, 0.288726, 0.382224, 0.385367, 0.291056,
+    0.178730, 0.500000, 0.291475, 0.142667, 0.298714, 0.430791, 0.429967, 0.429143, 0.428320,
+    0.434006, 0.430170, 0.430986, 0.425868, 0.409903, 0.287275, 0.382317, 0.288385, 0.174686,
+    0.500000, 0.138681, 0.295766, 0.427741, 0.426917, 0.426094, 0.425270, 0.430136, 0.430133,
+    0.430949, 0.425447, 0.409482, 0.410299, 0.285824, 0.285714, 0.170643, 0.500000, 0.698805,
+    0.677519, 0.673885, 0.670251, 0.666618, 0.680718, 0.677084, 0.680703, 0.680482, 0.674306,
+    0.677925, 0.681545, 0.658936, 0.180539, 0.500000, 0.698444, 0.693792, 0.689172, 0.684583,
+    0.696574, 0.694087, 0.696818, 0.699573, 0.695348, 0.698289, 0.701230, 0.475079, 0.068281,
+    0.500000, 0.472778, 0.469799, 0.445689, 0.424295, 0.390858, 0.363244, 0.339740, 0.315171,
+    0.302968, 0.294169, 0.288961, 0.121070, 0.500000, 0.472381, 0.446769, 0.428715, 0.395319,
+    0.364037, 0.343898, 0.319369, 0.307206, 0.298448, 0.293280, 0.125069, 0.500000, 0.449115,
+    0.430389, 0.396847, 0.368508, 0.344702, 0.323538, 0.311415, 0.302697, 0.297570, 0.129069,
+    0.500000, 0.432590, 0.398060, 0.370048, 0.349185, 0.324354, 0.315596, 0.306918, 0.301830,
+    0.133069, 0.500000, 0.384687, 0.355924, 0.335388, 0.313500, 0.301074, 0.295761, 0.290714,
+    0.117679, 0.500000, 0.355802, 0.334750, 0.313189, 0.303707, 0.294727, 0.293044, 0.119656,
+    0.500000, 0.332951, 0.311112, 0.301957, 0.295919, 0.290570, 0.119663, 0.500000, 0.308487,
+    0.299373, 0.293375, 0.288066, 0.115677, 0.500000, 0.301284, 0.294522, 0.292570, 0.133363,
+    0.500000, 0.291851, 0.289899, 0.129377, 0.500000, 0.287228, 0.125391, 0.500000, 0.121405,
+    0.500000,
+];
@@ -0,0 +1,84 @@
+use crate::{Card, Deck, PokerError};
+
+// A shuffled deck with a dead card set that stays out of every deal, including after a
+// reset, so simulations need not pass the dead cards around
+#[derive(Clone, Debug)]
+pub struct Simulation {
+    deck: Deck,
+    dead: Vec<Card>,
+}
+
+impl Simulation {
+    pub fn new(dead: &[Card]) -> Result<Simulation, PokerError> {
+        let mut deck = Deck::new();
+        for card in dead {
+            deck.remove(*card)?;
+        }
+
+        // Forget the dead cards entirely so a reset never brings them back
+        deck.dealt.clear();
+        deck.shuffle();
+
+        Ok(Simulation {
+            deck,
+            dead: dead.to_vec(),
+        })
+    }
+
+    pub fn dead(&self) -> &[Card] {
+        &self.dead
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.deck.remaining()
+    }
+
+    pub fn deal(&mut self, n: usize) -> Result<Vec<Card>, PokerError> {
+        if n > self.deck.remaining() {
+            return Err(PokerError::DeckEmpty);
+        }
+
+        Ok(self.deck.draw(n))
+    }
+
+    // Gather every dealt card and shuffle, ready for the next trial
+    pub fn reset(&mut self) {
+        self.deck.reset();
+        self.deck.shuffle();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rank, Suit};
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn aces_stay_dead() {
+        let aces: Vec<Card> = Suit::iter()
+            .map(|suit| Card::new(Rank::Ace, suit))
+            .collect();
+        let mut sim = Simulation::new(&aces).unwrap();
+        assert_eq!(sim.remaining(), 48);
+
+        for _ in 0..200 {
+            let cards = sim.deal(9).unwrap();
+            assert!(cards.iter().all(|card| card.rank != Rank::Ace));
+            sim.reset();
+        }
+
+        assert_eq!(sim.deal(48).unwrap().len(), 48);
+        assert_eq!(sim.deal(1), Err(PokerError::DeckEmpty));
+        assert_eq!(sim.dead(), &aces[..]);
+    }
+
+    #[test]
+    fn duplicate_dead_card() {
+        let ace = Card::new(Rank::Ace, Suit::Spades);
+        assert_eq!(
+            Simulation::new(&[ace, ace]).unwrap_err(),
+            PokerError::DuplicateCard(ace)
+        );
+    }
+}
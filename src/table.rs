@@ -0,0 +1,423 @@
+use crate::{Card, Deck, Hand, PokerError};
+
+#[derive(Debug)]
+pub struct Table {
+    deck: Deck,
+    hole_size: usize,
+    board_size: usize,
+    holes: Vec<Vec<Card>>,
+    board: Vec<Card>,
+}
+
+impl Table {
+    // A hold'em table: two hole cards per player and a five card board
+    pub fn new(players: usize) -> Result<Table, PokerError> {
+        Table::with_config(players, 5, 2)
+    }
+
+    pub fn with_config(
+        players: usize,
+        board_size: usize,
+        hole_size: usize,
+    ) -> Result<Table, PokerError> {
+        // Evaluation needs at least five cards per player
+        if hole_size + board_size < 5 {
+            return Err(PokerError::TooFewCards(hole_size + board_size));
+        }
+
+        let needed = players * hole_size + board_size + Table::streets(board_size);
+        if needed > 52 {
+            return Err(PokerError::TooManyCards(needed));
+        }
+
+        Ok(Table {
+            deck: Deck::new_shuffled(),
+            hole_size,
+            board_size,
+            holes: vec![Vec::with_capacity(hole_size); players],
+            board: Vec::with_capacity(board_size),
+        })
+    }
+
+    // The flop brings up to three cards and every later street one more, each after a burn
+    fn streets(board_size: usize) -> usize {
+        match board_size {
+            0 => 0,
+            _ => 1 + board_size.saturating_sub(3),
+        }
+    }
+
+    pub fn players(&self) -> usize {
+        self.holes.len()
+    }
+
+    pub fn hole(&self, seat: usize) -> &[Card] {
+        &self.holes[seat]
+    }
+
+    pub fn board(&self) -> &[Card] {
+        &self.board
+    }
+
+    // Seat specific hole cards, taking them out of the deck
+    pub fn set_hole(&mut self, seat: usize, cards: [Card; 2]) -> Result<(), PokerError> {
+        if seat >= self.players() {
+            return Err(PokerError::InvalidAction(format!(
+                "no seat {} at a table of {}",
+                seat,
+                self.players()
+            )));
+        }
+        if self.hole_size != 2 {
+            return Err(PokerError::InvalidAction(format!(
+                "two hole cards at a table dealing {}",
+                self.hole_size
+            )));
+        }
+        if !self.holes[seat].is_empty() {
+            return Err(PokerError::InvalidAction(format!(
+                "seat {} already has hole cards",
+                seat
+            )));
+        }
+        if cards[0] == cards[1] {
+            return Err(PokerError::DuplicateCard(cards[0]));
+        }
+
+        // Check both before touching the deck so a failure leaves it as it was
+        for card in cards {
+            if !self.deck.cards.contains(&card) {
+                return Err(PokerError::DuplicateCard(card));
+            }
+        }
+        for card in cards {
+            self.deck.remove(card)?;
+        }
+
+        self.holes[seat].extend(cards);
+
+        Ok(())
+    }
+
+    // Stack the deck so that normal dealing gives every seat the hole cards listed for it
+    // and the board starts with the given cards. Burn cards come from the rest of the deck.
+    pub fn rig(&mut self, hole: Vec<[Card; 2]>, board: Vec<Card>) -> Result<(), PokerError> {
+        if hole.len() != self.players() {
+            return Err(PokerError::InvalidAction(format!(
+                "hole cards for {} players at a table of {}",
+                hole.len(),
+                self.players()
+            )));
+        }
+        if self.hole_size != 2 {
+            return Err(PokerError::InvalidAction(format!(
+                "two hole cards at a table dealing {}",
+                self.hole_size
+            )));
+        }
+        if board.len() > self.board_size {
+            return Err(PokerError::TooManyCards(board.len()));
+        }
+        if self.holes.iter().any(|hole| !hole.is_empty()) || !self.board.is_empty() {
+            return Err(PokerError::InvalidAction(String::from(
+                "cannot rig a table that has started dealing",
+            )));
+        }
+
+        let rigged: Vec<Card> = hole.iter().flatten().chain(&board).copied().collect();
+        let mut burns = self
+            .deck
+            .cards
+            .iter()
+            .rev()
+            .filter(|card| !rigged.contains(card));
+
+        // Hole cards go round the table one at a time
+        let mut order: Vec<Card> = (0..2)
+            .flat_map(|i| hole.iter().map(move |h| h[i]))
+            .collect();
+
+        // Then a burn before the flop and before each later street
+        for (i, card) in board.iter().enumerate() {
+            if i == 0 || i >= 3 {
+                order.push(*burns.next().ok_or(PokerError::DeckEmpty)?);
+            }
+            order.push(*card);
+        }
+
+        self.deck.stack(&order)
+    }
+
+    // Deal hole cards one at a time around the table, skipping seats already holding cards
+    pub fn deal_hole_cards(&mut self) {
+        let empty: Vec<usize> = (0..self.players())
+            .filter(|seat| self.holes[*seat].is_empty())
+            .collect();
+
+        for _ in 0..self.hole_size {
+            for seat in &empty {
+                self.holes[*seat].extend(self.deck.draw(1));
+            }
+        }
+    }
+
+    // Deal whatever set_hole left open: the other seats and the board
+    pub fn deal_remaining(&mut self) {
+        self.deal();
+    }
+
+    // Burn and deal the next street, the flop and then one card at a time. Returns false
+    // without dealing once the board is complete.
+    pub fn deal_street(&mut self) -> bool {
+        if self.board.len() >= self.board_size {
+            return false;
+        }
+
+        let street = if self.board.is_empty() { 3 } else { 1 };
+        let count = street.min(self.board_size - self.board.len());
+
+        self.deck.draw(1);
+        self.board.extend(self.deck.draw(count));
+
+        true
+    }
+
+    // Deal the whole board, burning a card before each street
+    pub fn deal_board(&mut self) {
+        while self.deal_street() {}
+    }
+
+    pub fn deal(&mut self) {
+        self.deal_hole_cards();
+        self.deal_board();
+        debug_assert_eq!(self.validate(), Ok(()));
+    }
+
+    // Check no card sits in two hands or in a hand and on the board
+    pub fn validate(&self) -> Result<(), PokerError> {
+        let mut seen: Vec<Card> = Vec::with_capacity(52);
+        for card in self.holes.iter().flatten().chain(&self.board) {
+            if seen.contains(card) {
+                return Err(PokerError::DuplicateCard(*card));
+            }
+            seen.push(*card);
+        }
+
+        Ok(())
+    }
+
+    // The seat's hole cards together with the board, best five picked on evaluation
+    pub fn hand(&self, seat: usize) -> Hand {
+        Hand::new(&self.holes[seat], &self.board)
+    }
+
+    // One line per seat and one for the board, e.g. "Seat 0: As Kd", adding each seat's hand
+    // once the board is complete
+    pub fn display(&self) -> String {
+        let cards = |cards: &[Card]| {
+            cards
+                .iter()
+                .map(Card::to_string)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let showdown = self.board.len() == self.board_size
+            && self.holes.iter().all(|hole| hole.len() == self.hole_size);
+
+        let mut lines: Vec<String> = (0..self.players())
+            .map(|seat| {
+                let mut line = format!("Seat {}: {}", seat, cards(&self.holes[seat]));
+                if showdown {
+                    line += &format!(" ({})", self.hand(seat).best());
+                }
+                line
+            })
+            .collect();
+        lines.push(format!("Board: {}", cards(&self.board)));
+
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Rank, Suit};
+
+    #[test]
+    fn holdem_table() {
+        let mut table = Table::new(6).unwrap();
+        table.deal();
+
+        assert_eq!(table.players(), 6);
+        assert_eq!(table.board().len(), 5);
+        assert!((0..6).all(|seat| table.hole(seat).len() == 2));
+        assert_eq!(table.deck.remaining(), 52 - 12 - 5 - 3);
+    }
+
+    #[test]
+    fn custom_table() {
+        let mut table = Table::with_config(4, 4, 3).unwrap();
+        table.deal();
+
+        assert_eq!(table.board().len(), 4);
+        assert_eq!(table.hole(2).len(), 3);
+        assert_eq!(table.deck.remaining(), 52 - 12 - 4 - 2);
+
+        // The best five of the seven cards in the pool
+        let hand = table.hand(2);
+        let cards = hand.cards();
+        let mut best = None;
+        for skip1 in 0..7 {
+            for skip2 in skip1 + 1..7 {
+                let five: Vec<Card> = (0..7)
+                    .filter(|i| *i != skip1 && *i != skip2)
+                    .map(|i| cards[i])
+                    .collect();
+                let ranking = crate::eval5(&[five[0], five[1], five[2], five[3], five[4]]);
+                best = best.max(Some(ranking));
+            }
+        }
+        assert_eq!(Some(hand.best()), best);
+    }
+
+    #[test]
+    fn seat_aces() {
+        let aces = [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Ace, Suit::Hearts),
+        ];
+
+        let mut table = Table::new(3).unwrap();
+        table.set_hole(0, aces).unwrap();
+        table.deal_remaining();
+
+        assert_eq!(table.hole(0), &aces[..]);
+        assert!((1..3).all(|seat| table.hole(seat).len() == 2));
+        assert!((1..3).all(|seat| !table.hole(seat).iter().any(|c| aces.contains(c))));
+        assert_eq!(table.board().len(), 5);
+        assert_eq!(table.deck.assert_integrity(), Ok(()));
+
+        let mut table = Table::new(3).unwrap();
+        table.set_hole(0, aces).unwrap();
+        let kings = [Card::new(Rank::King, Suit::Spades), aces[1]];
+        assert_eq!(
+            table.set_hole(1, kings),
+            Err(PokerError::DuplicateCard(aces[1]))
+        );
+        assert!(table.hole(1).is_empty());
+        assert_eq!(table.deck.remaining(), 50);
+
+        let tens = [
+            Card::new(Rank::Ten, Suit::Spades),
+            Card::new(Rank::Ten, Suit::Hearts),
+        ];
+        let refused = |err| matches!(err, Err(PokerError::InvalidAction(_)));
+        assert!(refused(table.set_hole(0, tens)));
+        assert!(refused(table.set_hole(3, tens)));
+        assert!(refused(
+            Table::with_config(3, 5, 4).unwrap().set_hole(0, tens)
+        ));
+        assert_eq!(table.deck.remaining(), 50);
+    }
+
+    #[test]
+    fn rigged_table() {
+        let hole = vec![
+            [
+                Card::new(Rank::Ace, Suit::Spades),
+                Card::new(Rank::Ace, Suit::Hearts),
+            ],
+            [
+                Card::new(Rank::Seven, Suit::Clubs),
+                Card::new(Rank::Two, Suit::Diamonds),
+            ],
+        ];
+        let flop = vec![
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Spades),
+        ];
+
+        let mut table = Table::new(2).unwrap();
+        table.rig(hole.clone(), flop.clone()).unwrap();
+        table.deal();
+
+        assert_eq!(table.hole(0), &hole[0][..]);
+        assert_eq!(table.hole(1), &hole[1][..]);
+        assert_eq!(&table.board()[..3], &flop[..]);
+        assert_eq!(table.board().len(), 5);
+        assert_eq!(table.deck.assert_integrity(), Ok(()));
+
+        let refused = |err| matches!(err, Err(PokerError::InvalidAction(_)));
+        assert!(refused(table.rig(hole.clone(), vec![])));
+        let mut table = Table::new(3).unwrap();
+        assert!(refused(table.rig(hole.clone(), vec![])));
+        let mut table = Table::with_config(2, 5, 3).unwrap();
+        assert!(refused(table.rig(hole, vec![])));
+    }
+
+    #[test]
+    fn validated_table() {
+        let mut table = Table::new(4).unwrap();
+        table.deal();
+        assert_eq!(table.validate(), Ok(()));
+
+        let shared = table.hole(1)[0];
+        table.holes[3][1] = shared;
+        assert_eq!(table.validate(), Err(PokerError::DuplicateCard(shared)));
+
+        let mut table = Table::new(2).unwrap();
+        table.deal();
+        let shared = table.board()[4];
+        table.holes[0][0] = shared;
+        assert_eq!(table.validate(), Err(PokerError::DuplicateCard(shared)));
+    }
+
+    #[test]
+    fn displayed_table() {
+        let hole = vec![
+            [
+                Card::new(Rank::Ace, Suit::Spades),
+                Card::new(Rank::Ace, Suit::Hearts),
+            ],
+            [
+                Card::new(Rank::Seven, Suit::Clubs),
+                Card::new(Rank::Two, Suit::Diamonds),
+            ],
+        ];
+        let flop = vec![
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Two, Suit::Spades),
+        ];
+
+        let mut table = Table::new(2).unwrap();
+        table.rig(hole, flop).unwrap();
+        table.deal_hole_cards();
+        table.deal_street();
+
+        let shown = table.display();
+        assert!(shown.contains("Seat 0: As Ah\n"));
+        assert!(shown.contains("Seat 1: 7c 2d\n"));
+        assert!(shown.ends_with("Board: Ac 7h 2s"));
+        assert!(!shown.contains('('));
+
+        table.deal_board();
+        let shown = table.display();
+        let ranking = table.hand(1).best();
+        assert!(shown.contains(&format!("Seat 1: 7c 2d ({})\n", ranking)));
+        assert!(ranking.to_string().contains(", "));
+        assert!(shown.contains("Board: Ac 7h 2s "));
+        assert_eq!(shown.lines().count(), 3);
+    }
+
+    #[test]
+    fn invalid_table() {
+        assert_eq!(
+            Table::with_config(2, 2, 2).unwrap_err(),
+            PokerError::TooFewCards(4)
+        );
+        assert_eq!(Table::new(23).unwrap_err(), PokerError::TooManyCards(54));
+    }
+}
@@ -0,0 +1,52 @@
+use holdemrmx::{Action, Card, GameState, Rank, Street, Suit, Table, Transition};
+
+#[test]
+fn scripted_hand_to_showdown() {
+    let aces = [
+        Card::new(Rank::Ace, Suit::Spades),
+        Card::new(Rank::Ace, Suit::Hearts),
+    ];
+    let kings = [
+        Card::new(Rank::King, Suit::Spades),
+        Card::new(Rank::King, Suit::Hearts),
+    ];
+    let board = vec![
+        Card::new(Rank::Nine, Suit::Clubs),
+        Card::new(Rank::Seven, Suit::Diamonds),
+        Card::new(Rank::Two, Suit::Spades),
+        Card::new(Rank::Jack, Suit::Clubs),
+        Card::new(Rank::Four, Suit::Hearts),
+    ];
+
+    let mut table = Table::new(2).unwrap();
+    table.rig(vec![aces, kings], board.clone()).unwrap();
+    let mut game = GameState::new(table, vec![1000, 1000], 10).unwrap();
+
+    let script = [
+        (Action::Call, Transition::NextToAct(1)),
+        (Action::Check, Transition::NewStreet(Street::Flop, 1)),
+        (Action::Check, Transition::NextToAct(0)),
+        (Action::Raise(20), Transition::NextToAct(1)),
+        (Action::Call, Transition::NewStreet(Street::Turn, 1)),
+        (Action::Check, Transition::NextToAct(0)),
+        (Action::Check, Transition::NewStreet(Street::River, 1)),
+        (Action::Raise(50), Transition::NextToAct(0)),
+        (
+            Action::Call,
+            Transition::Showdown {
+                winners: vec![0],
+                pot: 160,
+            },
+        ),
+    ];
+
+    for (action, expected) in script {
+        assert_eq!(game.step(action), Ok(expected));
+    }
+
+    assert_eq!(game.table().hole(0), &aces[..]);
+    assert_eq!(game.table().board(), &board[..]);
+    assert_eq!(game.stack(0), 1080);
+    assert_eq!(game.stack(1), 920);
+    assert_eq!(game.to_act(), None);
+}
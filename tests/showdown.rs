@@ -0,0 +1,138 @@
+use holdemrmx::{
+    resolve_showdown, showdown_with_cards, Card, HandRanking, PokerError, Rank, Showdown, Suit,
+};
+
+#[test]
+fn flush_wins_showdown() {
+    let holes = [
+        [
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Four, Suit::Hearts),
+        ],
+        [
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::King, Suit::Spades),
+        ],
+    ];
+    let board = [
+        Card::new(Rank::King, Suit::Hearts),
+        Card::new(Rank::Nine, Suit::Hearts),
+        Card::new(Rank::Two, Suit::Diamonds),
+        Card::new(Rank::Seven, Suit::Hearts),
+        Card::new(Rank::Three, Suit::Clubs),
+    ];
+
+    let (winners, ranking, cards) = showdown_with_cards(&holes, &board);
+
+    assert_eq!(winners, vec![0]);
+    assert_eq!(ranking, HandRanking::Flush(0b11_0001_0100_1000));
+    assert_eq!(
+        cards,
+        [holes[0][0], board[0], board[1], board[3], holes[0][1]]
+    );
+}
+
+#[test]
+fn chopped_showdown() {
+    let holes = [
+        [
+            Card::new(Rank::Two, Suit::Hearts),
+            Card::new(Rank::Three, Suit::Hearts),
+        ],
+        [
+            Card::new(Rank::Two, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Spades),
+        ],
+    ];
+    let board = [
+        Card::new(Rank::Ace, Suit::Spades),
+        Card::new(Rank::King, Suit::Diamonds),
+        Card::new(Rank::Queen, Suit::Clubs),
+        Card::new(Rank::Jack, Suit::Hearts),
+        Card::new(Rank::Ten, Suit::Clubs),
+    ];
+
+    let (winners, ranking, cards) = showdown_with_cards(&holes, &board);
+
+    assert_eq!(winners, vec![0, 1]);
+    assert_eq!(ranking, HandRanking::Straight(Rank::Ace));
+    assert_eq!(cards, board);
+}
+
+#[test]
+fn four_way_showdown_with_chop() {
+    let holes = [
+        [
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Diamonds),
+        ],
+        [
+            Card::new(Rank::Ace, Suit::Spades),
+            Card::new(Rank::Three, Suit::Hearts),
+        ],
+        [
+            Card::new(Rank::King, Suit::Clubs),
+            Card::new(Rank::King, Suit::Diamonds),
+        ],
+        [
+            Card::new(Rank::Seven, Suit::Hearts),
+            Card::new(Rank::Eight, Suit::Hearts),
+        ],
+    ];
+    let board = [
+        Card::new(Rank::Ace, Suit::Hearts),
+        Card::new(Rank::Queen, Suit::Spades),
+        Card::new(Rank::Jack, Suit::Diamonds),
+        Card::new(Rank::Nine, Suit::Clubs),
+        Card::new(Rank::Four, Suit::Spades),
+    ];
+
+    // Both aces play the board's kickers and split
+    let showdown = resolve_showdown(&holes, &board).unwrap();
+    assert_eq!(showdown.winners, vec![0, 1]);
+    assert_eq!(showdown.rankings.len(), 4);
+    assert_eq!(showdown.rankings[0], showdown.rankings[1]);
+    assert_eq!(
+        showdown.rankings[2],
+        HandRanking::OnePair(Rank::King, 0b10_1100_0000_0000)
+    );
+
+    // On the flop the Three still plays as a kicker
+    let flop = resolve_showdown(&holes, &board[..3]).unwrap();
+    assert_eq!(
+        flop,
+        Showdown {
+            winners: vec![1],
+            rankings: flop.rankings.clone(),
+        }
+    );
+    assert!(flop.rankings[1] > flop.rankings[0]);
+}
+
+#[test]
+fn invalid_showdowns() {
+    let holes = [
+        [
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Two, Suit::Diamonds),
+        ],
+        [
+            Card::new(Rank::Ace, Suit::Clubs),
+            Card::new(Rank::Three, Suit::Hearts),
+        ],
+    ];
+    let board = [
+        Card::new(Rank::Ten, Suit::Hearts),
+        Card::new(Rank::Queen, Suit::Spades),
+        Card::new(Rank::Jack, Suit::Diamonds),
+    ];
+
+    assert_eq!(
+        resolve_showdown(&holes, &board),
+        Err(PokerError::DuplicateCard(Card::new(Rank::Ace, Suit::Clubs)))
+    );
+    assert_eq!(
+        resolve_showdown(&holes[..1], &board[..2]),
+        Err(PokerError::TooFewCards(2))
+    );
+}